@@ -0,0 +1,350 @@
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#ifndef MAMES_PP_H
+#define MAMES_PP_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Return code of every `mames_pp_*` function.
+//
+// Codes `10..30` mirror `ParseBeatmapError` and are only returned by
+// `mames_pp_beatmap_from_bytes_strict`. Codes `30..40` mirror
+// `ConvertError`.
+typedef enum MamesPpError {
+  // No error.
+  MAMES_PP_ERROR_OK = 0,
+  // A required pointer argument was null.
+  MAMES_PP_ERROR_NULL_POINTER = 1,
+  // An argument was out of range or not valid UTF-8.
+  MAMES_PP_ERROR_INVALID_ARGUMENT = 2,
+  // Reading the beatmap failed.
+  MAMES_PP_ERROR_IO = 3,
+  // The output buffer is too small. The required length has been written.
+  MAMES_PP_ERROR_BUFFER_TOO_SMALL = 4,
+  // The gradual calculation has already processed all objects.
+  MAMES_PP_ERROR_FINISHED = 5,
+  MAMES_PP_ERROR_PARSE_EFFECT_FLAGS = 10,
+  MAMES_PP_ERROR_PARSE_EVENT_TYPE = 11,
+  MAMES_PP_ERROR_PARSE_HIT_OBJECT_TYPE = 12,
+  MAMES_PP_ERROR_PARSE_HIT_SOUND_TYPE = 13,
+  MAMES_PP_ERROR_INVALID_EVENT_LINE = 14,
+  MAMES_PP_ERROR_INVALID_REPEAT_COUNT = 15,
+  MAMES_PP_ERROR_INVALID_TIMING_POINT_LINE = 16,
+  MAMES_PP_ERROR_INVALID_HIT_OBJECT_LINE = 17,
+  MAMES_PP_ERROR_PARSE_MODE = 18,
+  MAMES_PP_ERROR_PARSE_NUMBER = 19,
+  MAMES_PP_ERROR_TIME_SIGNATURE = 20,
+  MAMES_PP_ERROR_TIMING_CONTROL_POINT_NA_N = 21,
+  MAMES_PP_ERROR_UNKNOWN_HIT_OBJECT_TYPE = 22,
+  // Cannot convert an already converted map.
+  MAMES_PP_ERROR_ALREADY_CONVERTED = 30,
+  // Cannot convert the map to the requested mode.
+  MAMES_PP_ERROR_INCOMPATIBLE_MODE = 31,
+  // A panic was caught before it could cross the FFI boundary.
+  MAMES_PP_ERROR_PANIC = 99,
+} MamesPpError;
+
+// Opaque handle of a `Beatmap`.
+typedef struct MamesPpBeatmap MamesPpBeatmap;
+
+// Opaque handle of a `Difficulty`.
+typedef struct MamesPpDifficulty MamesPpDifficulty;
+
+// Opaque handle of `DifficultyAttributes`.
+typedef struct MamesPpDifficultyAttributes MamesPpDifficultyAttributes;
+
+// Opaque handle of a `GradualPerformance`.
+typedef struct MamesPpGradualPerformance MamesPpGradualPerformance;
+
+// C counterpart of `ScoreState`.
+typedef struct MamesPpScoreState {
+  uint32_t max_combo;
+  uint32_t osu_large_tick_hits;
+  uint32_t osu_small_tick_hits;
+  uint32_t slider_end_hits;
+  uint32_t taiko_drum_roll_tick_hits;
+  uint32_t taiko_swells_completed;
+  uint32_t n_geki;
+  uint32_t n_katu;
+  uint32_t n300;
+  uint32_t n100;
+  uint32_t n50;
+  uint32_t misses;
+} MamesPpScoreState;
+
+// The result of a performance calculation.
+//
+// Values that don't exist for the mode are zero.
+typedef struct MamesPpPerformanceAttributes {
+  // 0 = osu!standard, 1 = osu!taiko, 2 = osu!catch, 3 = osu!mania
+  uint8_t mode;
+  double pp;
+  double stars;
+  uint32_t max_combo;
+  double pp_acc;
+  double pp_aim;
+  double pp_speed;
+  double pp_flashlight;
+  double pp_reading;
+  double pp_difficulty;
+  double effective_miss_count;
+} MamesPpPerformanceAttributes;
+
+// A single `(attribute_id, value)` pair.
+//
+// See `database_attributes`.
+typedef struct MamesPpDatabaseAttribute {
+  uint16_t id;
+  double value;
+} MamesPpDatabaseAttribute;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Parse a beatmap from the content of a `.osu` file.
+//
+// Lines that cannot be decoded are skipped; see
+// [`mames_pp_beatmap_from_bytes_strict`] to reject them instead.
+//
+// # Safety
+//
+// `data` must point to `len` readable bytes and `out` must be valid for
+// writes.
+MamesPpError mames_pp_beatmap_from_bytes(const uint8_t *data, size_t len, MamesPpBeatmap **out);
+
+// Parse a beatmap from the content of a `.osu` file and fail with the
+// error of the first line that cannot be decoded.
+//
+// # Safety
+//
+// `data` must point to `len` readable bytes and `out` must be valid for
+// writes.
+MamesPpError mames_pp_beatmap_from_bytes_strict(const uint8_t *data,
+                                                size_t len,
+                                                MamesPpBeatmap **out);
+
+// Convert the beatmap to the given mode in-place.
+//
+// # Safety
+//
+// `map` must be a valid beatmap handle and `difficulty` either null or a
+// valid difficulty handle whose mods will be considered.
+MamesPpError mames_pp_beatmap_convert(MamesPpBeatmap *map,
+                                      uint8_t mode,
+                                      const MamesPpDifficulty *difficulty);
+
+// Free a beatmap handle. Passing null is a no-op.
+//
+// # Safety
+//
+// `map` must be null or a handle that has not been freed yet.
+void mames_pp_beatmap_free(MamesPpBeatmap *map);
+
+// Create a new difficulty configuration without mods.
+//
+// # Safety
+//
+// `out` must be valid for writes.
+MamesPpError mames_pp_difficulty_new(MamesPpDifficulty **out);
+
+// Specify mods through their legacy bits.
+//
+// # Safety
+//
+// `difficulty` must be a valid difficulty handle.
+MamesPpError mames_pp_difficulty_mods_bits(MamesPpDifficulty *difficulty, uint32_t bits);
+
+// Specify mods through a nul-terminated string of acronyms, e.g. `"HDDT"`.
+//
+// # Safety
+//
+// `difficulty` must be a valid difficulty handle and `acronyms` a valid
+// nul-terminated string.
+MamesPpError mames_pp_difficulty_mods_acronyms(MamesPpDifficulty *difficulty,
+                                               const char *acronyms);
+
+// Adjust the clock rate.
+//
+// # Safety
+//
+// `difficulty` must be a valid difficulty handle.
+MamesPpError mames_pp_difficulty_clock_rate(MamesPpDifficulty *difficulty, double clock_rate);
+
+// Override the map's approach rate.
+//
+// If `with_mods` is true, the value already considers mods.
+//
+// # Safety
+//
+// `difficulty` must be a valid difficulty handle.
+MamesPpError mames_pp_difficulty_ar(MamesPpDifficulty *difficulty, float value, bool with_mods);
+
+// Override the map's circle size.
+//
+// If `with_mods` is true, the value already considers mods.
+//
+// # Safety
+//
+// `difficulty` must be a valid difficulty handle.
+MamesPpError mames_pp_difficulty_cs(MamesPpDifficulty *difficulty, float value, bool with_mods);
+
+// Override the map's drain rate.
+//
+// If `with_mods` is true, the value already considers mods.
+//
+// # Safety
+//
+// `difficulty` must be a valid difficulty handle.
+MamesPpError mames_pp_difficulty_hp(MamesPpDifficulty *difficulty, float value, bool with_mods);
+
+// Override the map's overall difficulty.
+//
+// If `with_mods` is true, the value already considers mods.
+//
+// # Safety
+//
+// `difficulty` must be a valid difficulty handle.
+MamesPpError mames_pp_difficulty_od(MamesPpDifficulty *difficulty, float value, bool with_mods);
+
+// Amount of passed objects for partial plays, e.g. a fail.
+//
+// # Safety
+//
+// `difficulty` must be a valid difficulty handle.
+MamesPpError mames_pp_difficulty_passed_objects(MamesPpDifficulty *difficulty,
+                                                uint32_t passed_objects);
+
+// Whether the calculated attributes belong to an osu!lazer or osu!stable
+// score.
+//
+// # Safety
+//
+// `difficulty` must be a valid difficulty handle.
+MamesPpError mames_pp_difficulty_lazer(MamesPpDifficulty *difficulty, bool lazer);
+
+// Specify the algorithm version.
+//
+// `0` is osu!lazer 2026 and `1` is Mamestagram.
+//
+// # Safety
+//
+// `difficulty` must be a valid difficulty handle.
+MamesPpError mames_pp_difficulty_algorithm(MamesPpDifficulty *difficulty, uint8_t algorithm);
+
+// Calculate the difficulty attributes of a beatmap.
+//
+// # Safety
+//
+// `difficulty` and `map` must be valid handles and `out` must be valid for
+// writes.
+MamesPpError mames_pp_difficulty_calculate(const MamesPpDifficulty *difficulty,
+                                           const MamesPpBeatmap *map,
+                                           MamesPpDifficultyAttributes **out);
+
+// Free a difficulty handle. Passing null is a no-op.
+//
+// # Safety
+//
+// `difficulty` must be null or a handle that has not been freed yet.
+void mames_pp_difficulty_free(MamesPpDifficulty *difficulty);
+
+// The star rating of difficulty attributes.
+//
+// # Safety
+//
+// `attrs` must be a valid handle and `out` must be valid for writes.
+MamesPpError mames_pp_difficulty_attributes_stars(const MamesPpDifficultyAttributes *attrs,
+                                                  double *out);
+
+// The maximum combo of difficulty attributes.
+//
+// # Safety
+//
+// `attrs` must be a valid handle and `out` must be valid for writes.
+MamesPpError mames_pp_difficulty_attributes_max_combo(const MamesPpDifficultyAttributes *attrs,
+                                                      uint32_t *out);
+
+// Write all difficulty attributes as `(attribute_id, value)` pairs.
+//
+// The amount of pairs is written to `len`. If `capacity` is too small,
+// nothing is written to `out` and `MamesPpError::BufferTooSmall` is
+// returned so that the call can be repeated with a larger buffer.
+//
+// # Safety
+//
+// `attrs` must be a valid handle, `out` must be valid for `capacity` writes
+// or null if `capacity` is zero, and `len` must be valid for writes.
+MamesPpError mames_pp_difficulty_attributes_database(const MamesPpDifficultyAttributes *attrs,
+                                                     MamesPpDatabaseAttribute *out,
+                                                     size_t capacity,
+                                                     size_t *len);
+
+// Free a difficulty attributes handle. Passing null is a no-op.
+//
+// # Safety
+//
+// `attrs` must be null or a handle that has not been freed yet.
+void mames_pp_difficulty_attributes_free(MamesPpDifficultyAttributes *attrs);
+
+// Calculate the performance of a score.
+//
+// The attributes must have been calculated with the same `difficulty`. If
+// `state` is null, the performance of a perfect play is calculated.
+//
+// # Safety
+//
+// `attrs` and `difficulty` must be valid handles, `state` must be null or
+// valid for reads, and `out` must be valid for writes.
+MamesPpError mames_pp_performance_calculate(const MamesPpDifficultyAttributes *attrs,
+                                            const MamesPpDifficulty *difficulty,
+                                            const MamesPpScoreState *state,
+                                            MamesPpPerformanceAttributes *out);
+
+// Prepare a gradual performance calculation.
+//
+// # Safety
+//
+// `difficulty` and `map` must be valid handles and `out` must be valid for
+// writes.
+MamesPpError mames_pp_gradual_performance_new(const MamesPpDifficulty *difficulty,
+                                              const MamesPpBeatmap *map,
+                                              MamesPpGradualPerformance **out);
+
+// Process the next `n + 1` objects with the score state after them.
+//
+// Returns `MamesPpError::Finished` if all objects were already processed.
+//
+// # Safety
+//
+// `gradual` must be a valid handle, `state` must be valid for reads, and
+// `out` must be valid for writes.
+MamesPpError mames_pp_gradual_performance_nth(MamesPpGradualPerformance *gradual,
+                                              const MamesPpScoreState *state,
+                                              size_t n,
+                                              MamesPpPerformanceAttributes *out);
+
+// The amount of remaining objects.
+//
+// # Safety
+//
+// `gradual` must be a valid handle and `out` must be valid for writes.
+MamesPpError mames_pp_gradual_performance_len(const MamesPpGradualPerformance *gradual,
+                                              size_t *out);
+
+// Free a gradual performance handle. Passing null is a no-op.
+//
+// # Safety
+//
+// `gradual` must be null or a handle that has not been freed yet.
+void mames_pp_gradual_performance_free(MamesPpGradualPerformance *gradual);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* MAMES_PP_H */
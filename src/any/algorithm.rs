@@ -0,0 +1,30 @@
+/// Version of the difficulty and performance algorithms.
+///
+/// Allows comparing the upstream osu!lazer values with this fork's values
+/// side by side without pinning different crate versions.
+///
+/// Specified through [`Difficulty::algorithm`] or the `algorithm` method of
+/// the performance calculators.
+///
+/// [`Difficulty::algorithm`]: crate::Difficulty::algorithm
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlgorithmVersion {
+    /// The upstream osu!lazer calculation of 2026 without any of this fork's
+    /// adjustments.
+    Lazer2026,
+    /// [`Lazer2026`] plus this fork's adjustments, i.e. the relax
+    /// performance calculation, the single tap speed evaluation, and the
+    /// osu!taiko reading adjustments for HD, FL, and Constant Speed.
+    ///
+    /// [`Lazer2026`]: AlgorithmVersion::Lazer2026
+    #[default]
+    Mamestagram,
+}
+
+impl AlgorithmVersion {
+    /// Whether this fork's own adjustments apply.
+    pub(crate) const fn fork_adjustments(self) -> bool {
+        matches!(self, Self::Mamestagram)
+    }
+}
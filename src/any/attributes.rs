@@ -9,6 +9,11 @@ use super::performance::{into::IntoPerformance, Performance};
 
 /// The result of a difficulty calculation based on the mode.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum DifficultyAttributes {
     /// osu!standard difficulty calculation result.
     Osu(OsuDifficultyAttributes),
@@ -49,6 +54,11 @@ impl DifficultyAttributes {
 
 /// The result of a performance calculation based on the mode.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum PerformanceAttributes {
     /// osu!standard performance calculation result.
     Osu(OsuPerformanceAttributes),
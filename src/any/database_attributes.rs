@@ -0,0 +1,424 @@
+//! Conversion between difficulty attributes and the `(attribute_id, value)`
+//! rows of osu-web's `osu_beatmap_difficulty_attribs` table.
+//!
+//! IDs below [`EXTENSION_START`] are the ones used by osu!lazer's
+//! `DifficultyAttributes.ToDatabaseAttributes`. Everything that osu-web does
+//! not store, e.g. the reading skill of this fork or object counts that
+//! osu-web takes from the beatmap itself, uses IDs of the extension range so
+//! that attributes can be stored and restored without loss.
+
+use rosu_map::section::general::GameMode;
+
+use crate::{
+    catch::CatchDifficultyAttributes, mania::ManiaDifficultyAttributes,
+    model::beatmap::BeatmapAttributesBuilder, osu::OsuDifficultyAttributes,
+    taiko::TaikoDifficultyAttributes,
+};
+
+use super::DifficultyAttributes;
+
+pub const AIM: u16 = 1;
+pub const SPEED: u16 = 3;
+pub const OVERALL_DIFFICULTY: u16 = 5;
+pub const APPROACH_RATE: u16 = 7;
+pub const MAX_COMBO: u16 = 9;
+pub const DIFFICULTY: u16 = 11;
+pub const GREAT_HIT_WINDOW: u16 = 13;
+pub const SCORE_MULTIPLIER: u16 = 15;
+pub const FLASHLIGHT: u16 = 17;
+pub const SLIDER_FACTOR: u16 = 19;
+pub const SPEED_NOTE_COUNT: u16 = 21;
+pub const SPEED_DIFFICULT_STRAIN_COUNT: u16 = 23;
+pub const AIM_DIFFICULT_STRAIN_COUNT: u16 = 25;
+pub const OK_HIT_WINDOW: u16 = 27;
+pub const MONO_STAMINA_FACTOR: u16 = 29;
+pub const AIM_DIFFICULT_SLIDER_COUNT: u16 = 31;
+pub const AIM_TOP_WEIGHTED_SLIDER_FACTOR: u16 = 33;
+pub const SPEED_TOP_WEIGHTED_SLIDER_FACTOR: u16 = 35;
+pub const NESTED_SCORE_PER_OBJECT: u16 = 37;
+pub const LEGACY_SCORE_BASE_MULTIPLIER: u16 = 39;
+pub const MAXIMUM_LEGACY_COMBO_SCORE: u16 = 41;
+
+/// The first ID of the extension range.
+///
+/// osu-web will never assign these IDs so fork 独自 attributes are stored
+/// here. Like upstream, only odd IDs are used.
+pub const EXTENSION_START: u16 = 1000;
+
+pub const READING: u16 = 1001;
+pub const READING_DIFFICULT_NOTE_COUNT: u16 = 1003;
+pub const STAMINA: u16 = 1005;
+pub const RHYTHM: u16 = 1007;
+pub const COLOR: u16 = 1009;
+pub const MECHANICAL_DIFFICULTY: u16 = 1011;
+pub const STAMINA_TOP_STRAINS: u16 = 1013;
+pub const CONSISTENCY_FACTOR: u16 = 1015;
+pub const MEH_HIT_WINDOW: u16 = 1017;
+pub const DRAIN_RATE: u16 = 1019;
+pub const N_CIRCLES: u16 = 1021;
+pub const N_SLIDERS: u16 = 1023;
+pub const N_LARGE_TICKS: u16 = 1025;
+pub const N_SPINNERS: u16 = 1027;
+pub const N_DRUM_ROLLS: u16 = 1029;
+pub const N_DRUM_ROLL_TICKS: u16 = 1031;
+pub const N_SWELLS: u16 = 1033;
+pub const N_SWELL_HITS: u16 = 1035;
+pub const N_FRUITS: u16 = 1037;
+pub const N_DROPLETS: u16 = 1039;
+pub const N_TINY_DROPLETS: u16 = 1041;
+pub const N_OBJECTS: u16 = 1043;
+pub const N_HOLD_NOTES: u16 = 1045;
+pub const IS_CONVERT: u16 = 1047;
+
+const fn from_count(value: f64) -> u32 {
+    value as u32
+}
+
+fn from_bool(value: f64) -> bool {
+    value > 0.0
+}
+
+fn to_bool(value: bool) -> f64 {
+    f64::from(u8::from(value))
+}
+
+impl DifficultyAttributes {
+    /// Convert the attributes into `(attribute_id, value)` pairs.
+    ///
+    /// See [`database_attributes`](crate::any::database_attributes) for the
+    /// meaning of the IDs.
+    pub fn to_database_attributes(&self) -> Vec<(u16, f64)> {
+        match self {
+            Self::Osu(attrs) => attrs.to_database_attributes(),
+            Self::Taiko(attrs) => attrs.to_database_attributes(),
+            Self::Catch(attrs) => attrs.to_database_attributes(),
+            Self::Mania(attrs) => attrs.to_database_attributes(),
+        }
+    }
+
+    /// Create attributes of the given mode from `(attribute_id, value)` pairs.
+    ///
+    /// Unknown IDs are ignored and missing ones are set to zero.
+    pub fn from_database_attributes(
+        mode: GameMode,
+        values: impl IntoIterator<Item = (u16, f64)>,
+    ) -> Self {
+        match mode {
+            GameMode::Osu => Self::Osu(OsuDifficultyAttributes::from_database_attributes(values)),
+            GameMode::Taiko => {
+                Self::Taiko(TaikoDifficultyAttributes::from_database_attributes(values))
+            }
+            GameMode::Catch => {
+                Self::Catch(CatchDifficultyAttributes::from_database_attributes(values))
+            }
+            GameMode::Mania => {
+                Self::Mania(ManiaDifficultyAttributes::from_database_attributes(values))
+            }
+        }
+    }
+}
+
+impl OsuDifficultyAttributes {
+    /// Convert the attributes into `(attribute_id, value)` pairs.
+    pub fn to_database_attributes(&self) -> Vec<(u16, f64)> {
+        vec![
+            (AIM, self.aim),
+            (SPEED, self.speed),
+            (OVERALL_DIFFICULTY, self.od()),
+            (APPROACH_RATE, self.ar),
+            (MAX_COMBO, f64::from(self.max_combo)),
+            (DIFFICULTY, self.stars),
+            (GREAT_HIT_WINDOW, self.great_hit_window),
+            (FLASHLIGHT, self.flashlight),
+            (SLIDER_FACTOR, self.slider_factor),
+            (SPEED_NOTE_COUNT, self.speed_note_count),
+            (
+                SPEED_DIFFICULT_STRAIN_COUNT,
+                self.speed_difficult_strain_count,
+            ),
+            (AIM_DIFFICULT_STRAIN_COUNT, self.aim_difficult_strain_count),
+            (OK_HIT_WINDOW, self.ok_hit_window),
+            (AIM_DIFFICULT_SLIDER_COUNT, self.aim_difficult_slider_count),
+            (
+                AIM_TOP_WEIGHTED_SLIDER_FACTOR,
+                self.aim_top_weighted_slider_factor,
+            ),
+            (
+                SPEED_TOP_WEIGHTED_SLIDER_FACTOR,
+                self.speed_top_weighted_slider_factor,
+            ),
+            (NESTED_SCORE_PER_OBJECT, self.nested_score_per_object),
+            (
+                LEGACY_SCORE_BASE_MULTIPLIER,
+                self.legacy_score_base_multiplier,
+            ),
+            (MAXIMUM_LEGACY_COMBO_SCORE, self.maximum_legacy_combo_score),
+            (READING, self.reading),
+            (
+                READING_DIFFICULT_NOTE_COUNT,
+                self.reading_difficult_note_count,
+            ),
+            (MEH_HIT_WINDOW, self.meh_hit_window),
+            (DRAIN_RATE, self.hp),
+            (N_CIRCLES, f64::from(self.n_circles)),
+            (N_SLIDERS, f64::from(self.n_sliders)),
+            (N_LARGE_TICKS, f64::from(self.n_large_ticks)),
+            (N_SPINNERS, f64::from(self.n_spinners)),
+        ]
+    }
+
+    /// Create attributes from `(attribute_id, value)` pairs.
+    ///
+    /// Unknown IDs are ignored and missing ones are set to zero. If the great
+    /// hit window is missing, as is the case for rows of osu-web, it is
+    /// derived from the overall difficulty.
+    pub fn from_database_attributes(values: impl IntoIterator<Item = (u16, f64)>) -> Self {
+        let mut attrs = Self::default();
+        let mut great_hit_window = None;
+        let mut od = None;
+
+        for (id, value) in values {
+            match id {
+                AIM => attrs.aim = value,
+                SPEED => attrs.speed = value,
+                OVERALL_DIFFICULTY => od = Some(value),
+                APPROACH_RATE => attrs.ar = value,
+                MAX_COMBO => attrs.max_combo = from_count(value),
+                DIFFICULTY => attrs.stars = value,
+                GREAT_HIT_WINDOW => great_hit_window = Some(value),
+                FLASHLIGHT => attrs.flashlight = value,
+                SLIDER_FACTOR => attrs.slider_factor = value,
+                SPEED_NOTE_COUNT => attrs.speed_note_count = value,
+                SPEED_DIFFICULT_STRAIN_COUNT => attrs.speed_difficult_strain_count = value,
+                AIM_DIFFICULT_STRAIN_COUNT => attrs.aim_difficult_strain_count = value,
+                OK_HIT_WINDOW => attrs.ok_hit_window = value,
+                AIM_DIFFICULT_SLIDER_COUNT => attrs.aim_difficult_slider_count = value,
+                AIM_TOP_WEIGHTED_SLIDER_FACTOR => attrs.aim_top_weighted_slider_factor = value,
+                SPEED_TOP_WEIGHTED_SLIDER_FACTOR => attrs.speed_top_weighted_slider_factor = value,
+                NESTED_SCORE_PER_OBJECT => attrs.nested_score_per_object = value,
+                LEGACY_SCORE_BASE_MULTIPLIER => attrs.legacy_score_base_multiplier = value,
+                MAXIMUM_LEGACY_COMBO_SCORE => attrs.maximum_legacy_combo_score = value,
+                READING => attrs.reading = value,
+                READING_DIFFICULT_NOTE_COUNT => attrs.reading_difficult_note_count = value,
+                MEH_HIT_WINDOW => attrs.meh_hit_window = value,
+                DRAIN_RATE => attrs.hp = value,
+                N_CIRCLES => attrs.n_circles = from_count(value),
+                N_SLIDERS => attrs.n_sliders = from_count(value),
+                N_LARGE_TICKS => attrs.n_large_ticks = from_count(value),
+                N_SPINNERS => attrs.n_spinners = from_count(value),
+                _ => {}
+            }
+        }
+
+        attrs.great_hit_window = great_hit_window
+            .or_else(|| od.map(BeatmapAttributesBuilder::osu_od_to_great_hit_window))
+            .unwrap_or(0.0);
+
+        attrs
+    }
+}
+
+impl TaikoDifficultyAttributes {
+    /// Convert the attributes into `(attribute_id, value)` pairs.
+    pub fn to_database_attributes(&self) -> Vec<(u16, f64)> {
+        vec![
+            (MAX_COMBO, f64::from(self.max_combo)),
+            (DIFFICULTY, self.stars),
+            (GREAT_HIT_WINDOW, self.great_hit_window),
+            (OK_HIT_WINDOW, self.ok_hit_window),
+            (MONO_STAMINA_FACTOR, self.mono_stamina_factor),
+            (READING, self.reading),
+            (STAMINA, self.stamina),
+            (RHYTHM, self.rhythm),
+            (COLOR, self.color),
+            (MECHANICAL_DIFFICULTY, self.mechanical_difficulty),
+            (STAMINA_TOP_STRAINS, self.stamina_top_strains),
+            (CONSISTENCY_FACTOR, self.consistency_factor),
+            (N_DRUM_ROLLS, f64::from(self.n_drum_rolls)),
+            (N_DRUM_ROLL_TICKS, f64::from(self.n_drum_roll_ticks)),
+            (N_SWELLS, f64::from(self.n_swells)),
+            (N_SWELL_HITS, f64::from(self.n_swell_hits)),
+            (IS_CONVERT, to_bool(self.is_convert)),
+        ]
+    }
+
+    /// Create attributes from `(attribute_id, value)` pairs.
+    ///
+    /// Unknown IDs are ignored and missing ones are set to zero.
+    pub fn from_database_attributes(values: impl IntoIterator<Item = (u16, f64)>) -> Self {
+        let mut attrs = Self::default();
+
+        for (id, value) in values {
+            match id {
+                MAX_COMBO => attrs.max_combo = from_count(value),
+                DIFFICULTY => attrs.stars = value,
+                GREAT_HIT_WINDOW => attrs.great_hit_window = value,
+                OK_HIT_WINDOW => attrs.ok_hit_window = value,
+                MONO_STAMINA_FACTOR => attrs.mono_stamina_factor = value,
+                READING => attrs.reading = value,
+                STAMINA => attrs.stamina = value,
+                RHYTHM => attrs.rhythm = value,
+                COLOR => attrs.color = value,
+                MECHANICAL_DIFFICULTY => attrs.mechanical_difficulty = value,
+                STAMINA_TOP_STRAINS => attrs.stamina_top_strains = value,
+                CONSISTENCY_FACTOR => attrs.consistency_factor = value,
+                N_DRUM_ROLLS => attrs.n_drum_rolls = from_count(value),
+                N_DRUM_ROLL_TICKS => attrs.n_drum_roll_ticks = from_count(value),
+                N_SWELLS => attrs.n_swells = from_count(value),
+                N_SWELL_HITS => attrs.n_swell_hits = from_count(value),
+                IS_CONVERT => attrs.is_convert = from_bool(value),
+                _ => {}
+            }
+        }
+
+        attrs
+    }
+}
+
+impl CatchDifficultyAttributes {
+    /// Convert the attributes into `(attribute_id, value)` pairs.
+    pub fn to_database_attributes(&self) -> Vec<(u16, f64)> {
+        vec![
+            (APPROACH_RATE, self.ar),
+            (MAX_COMBO, f64::from(self.max_combo())),
+            (DIFFICULTY, self.stars),
+            (N_FRUITS, f64::from(self.n_fruits)),
+            (N_DROPLETS, f64::from(self.n_droplets)),
+            (N_TINY_DROPLETS, f64::from(self.n_tiny_droplets)),
+            (IS_CONVERT, to_bool(self.is_convert)),
+        ]
+    }
+
+    /// Create attributes from `(attribute_id, value)` pairs.
+    ///
+    /// Unknown IDs are ignored and missing ones are set to zero. The maximum
+    /// combo is derived from the fruit and droplet counts. If those are
+    /// missing, e.g. for rows of osu-web, all of [`MAX_COMBO`] is attributed
+    /// to fruits.
+    pub fn from_database_attributes(values: impl IntoIterator<Item = (u16, f64)>) -> Self {
+        let mut attrs = Self::default();
+        let mut max_combo = 0;
+
+        for (id, value) in values {
+            match id {
+                APPROACH_RATE => attrs.ar = value,
+                MAX_COMBO => max_combo = from_count(value),
+                DIFFICULTY => attrs.stars = value,
+                N_FRUITS => attrs.n_fruits = from_count(value),
+                N_DROPLETS => attrs.n_droplets = from_count(value),
+                N_TINY_DROPLETS => attrs.n_tiny_droplets = from_count(value),
+                IS_CONVERT => attrs.is_convert = from_bool(value),
+                _ => {}
+            }
+        }
+
+        if attrs.max_combo() == 0 {
+            attrs.n_fruits = max_combo;
+        }
+
+        attrs
+    }
+}
+
+impl ManiaDifficultyAttributes {
+    /// Convert the attributes into `(attribute_id, value)` pairs.
+    pub fn to_database_attributes(&self) -> Vec<(u16, f64)> {
+        vec![
+            (MAX_COMBO, f64::from(self.max_combo)),
+            (DIFFICULTY, self.stars),
+            (N_OBJECTS, f64::from(self.n_objects)),
+            (N_HOLD_NOTES, f64::from(self.n_hold_notes)),
+            (IS_CONVERT, to_bool(self.is_convert)),
+        ]
+    }
+
+    /// Create attributes from `(attribute_id, value)` pairs.
+    ///
+    /// Unknown IDs are ignored and missing ones are set to zero.
+    pub fn from_database_attributes(values: impl IntoIterator<Item = (u16, f64)>) -> Self {
+        let mut attrs = Self::default();
+
+        for (id, value) in values {
+            match id {
+                MAX_COMBO => attrs.max_combo = from_count(value),
+                DIFFICULTY => attrs.stars = value,
+                N_OBJECTS => attrs.n_objects = from_count(value),
+                N_HOLD_NOTES => attrs.n_hold_notes = from_count(value),
+                IS_CONVERT => attrs.is_convert = from_bool(value),
+                _ => {}
+            }
+        }
+
+        attrs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Beatmap, Difficulty};
+
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for (path, mode) in [
+            ("./resources/2785319.osu", GameMode::Osu),
+            ("./resources/2785319.osu", GameMode::Taiko),
+            ("./resources/2785319.osu", GameMode::Catch),
+            ("./resources/1638954.osu", GameMode::Mania),
+        ] {
+            let map = Beatmap::from_path(path).unwrap();
+            let map = map.convert(mode, &0.into()).unwrap();
+            let attrs = Difficulty::new().mods(8 | 64).calculate(&map);
+            let values = attrs.to_database_attributes();
+
+            assert!(values.iter().all(|(id, _)| id % 2 == 1), "{mode:?}");
+            assert_eq!(
+                DifficultyAttributes::from_database_attributes(mode, values),
+                attrs,
+                "{mode:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn osu_web_rows() {
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+
+        let DifficultyAttributes::Osu(attrs) = Difficulty::new().calculate(&map) else {
+            unreachable!()
+        };
+
+        // osu-web neither stores the hit windows nor the fork's extension range
+        let rows = attrs
+            .to_database_attributes()
+            .into_iter()
+            .filter(|(id, _)| *id < EXTENSION_START && *id != GREAT_HIT_WINDOW);
+
+        let restored = OsuDifficultyAttributes::from_database_attributes(rows);
+
+        assert!((restored.great_hit_window - attrs.great_hit_window).abs() < 1e-9);
+        assert!((restored.od() - attrs.od()).abs() < 1e-9);
+        assert!((restored.stars - attrs.stars).abs() < f64::EPSILON);
+        assert!(restored.reading.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn catch_osu_web_rows() {
+        let map = Beatmap::from_path("./resources/2118524.osu").unwrap();
+
+        let DifficultyAttributes::Catch(attrs) = Difficulty::new().calculate(&map) else {
+            unreachable!()
+        };
+
+        let rows = attrs
+            .to_database_attributes()
+            .into_iter()
+            .filter(|(id, _)| *id < EXTENSION_START);
+
+        let restored = CatchDifficultyAttributes::from_database_attributes(rows);
+
+        assert_eq!(restored.max_combo(), attrs.max_combo());
+        assert!(restored.max_combo() > 0);
+    }
+}
@@ -0,0 +1,338 @@
+use std::f64::consts::PI;
+
+use rosu_map::section::general::GameMode;
+
+use crate::{
+    model::beatmap::HitWindows,
+    util::special_functions::{erf, erf_inv},
+};
+
+use super::ScoreState;
+
+/// 99% critical value for the normal distribution (one-tailed).
+#[allow(clippy::unreadable_literal)]
+const Z: f64 = 2.32634787404;
+
+// DiffUtils.SQRT2 is deliberately one ULP below std::f64::consts::SQRT_2.
+// Retain that exact value because this feeds into lazer's speed deviation.
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
+const LAZER_SQRT_2: f64 = 1.414_213_562_373_095_0;
+
+/// Judgement counts used to estimate a player's hit deviation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct HitJudgements {
+    /// Amount of hits within the great hit window.
+    pub great: u32,
+    /// Amount of hits within the ok hit window.
+    pub ok: u32,
+    /// Amount of hits within the meh hit window.
+    pub meh: u32,
+    /// Amount of misses.
+    pub misses: u32,
+}
+
+impl HitJudgements {
+    /// Extract the judgements of a [`ScoreState`] for the given mode.
+    ///
+    /// - osu!standard: 300s, 100s, 50s, and misses
+    /// - osu!taiko: 300s, 100s, and misses
+    /// - osu!mania: 320s and 300s are greats, 200s and 100s are oks.
+    ///
+    /// Returns `None` for osu!catch. Fruits, droplets, and tiny droplets are
+    /// judged by the catcher's position when the object reaches it, not by
+    /// the timing of a key press; lazer's `CatchHitWindows` accordingly has
+    /// no timing ranges. Their counts therefore carry no information about
+    /// hit deviation and an unstable rate cannot be estimated for osu!catch
+    /// scores.
+    pub const fn new(state: &ScoreState, mode: GameMode) -> Option<Self> {
+        let judgements = match mode {
+            GameMode::Osu => Self {
+                great: state.n300,
+                ok: state.n100,
+                meh: state.n50,
+                misses: state.misses,
+            },
+            GameMode::Taiko => Self {
+                great: state.n300,
+                ok: state.n100,
+                meh: 0,
+                misses: state.misses,
+            },
+            GameMode::Catch => return None,
+            GameMode::Mania => Self {
+                great: state.n_geki + state.n300,
+                ok: state.n_katu + state.n100,
+                meh: state.n50,
+                misses: state.misses,
+            },
+        };
+
+        Some(judgements)
+    }
+
+    /// Return the total amount of judgements.
+    pub const fn total(&self) -> u32 {
+        self.great + self.ok + self.meh + self.misses
+    }
+}
+
+/// An estimation of a player's unstable rate based on their judgements.
+///
+/// The bounds each hold with 99% confidence.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeviationEstimate {
+    /// The most likely unstable rate.
+    pub unstable_rate: f64,
+    /// The lower bound of the unstable rate.
+    pub unstable_rate_lower: f64,
+    /// The upper bound of the unstable rate.
+    ///
+    /// This is the value that osu!lazer uses for performance calculation.
+    pub unstable_rate_upper: f64,
+}
+
+impl DeviationEstimate {
+    /// Estimate the unstable rate for the given [`HitWindows`] and
+    /// [`HitJudgements`].
+    ///
+    /// If the hit windows contain great, ok, and meh windows, e.g. for
+    /// osu!standard, the variance of oks and mehs is taken into account.
+    /// Misses are ignored in that case, just like in lazer's
+    /// `OsuPerformanceCalculator.calculateDeviation`, because they hold no
+    /// timing information. Otherwise, only the ratio of greats to all other
+    /// judgements, misses included, is considered.
+    ///
+    /// Returns `None` if there are no judgements to estimate from or if the
+    /// great hit window is not positive.
+    ///
+    /// osu!catch is not supported, see [`HitJudgements::new`].
+    ///
+    /// [`HitWindows`] can be obtained through [`BeatmapAttributesBuilder`].
+    ///
+    /// [`BeatmapAttributesBuilder`]: crate::model::beatmap::BeatmapAttributesBuilder
+    pub fn new(hit_windows: &HitWindows, judgements: HitJudgements) -> Option<Self> {
+        let HitWindows {
+            od_great,
+            od_ok,
+            od_meh,
+            ar: _,
+        } = *hit_windows;
+
+        if od_great <= 0.0 {
+            return None;
+        }
+
+        let HitJudgements {
+            great,
+            ok,
+            meh,
+            misses: _,
+        } = judgements;
+
+        let (great, ok, meh) = (f64::from(great), f64::from(ok), f64::from(meh));
+
+        let estimate = |p_bound: fn(f64, f64) -> f64| match (od_ok, od_meh) {
+            (Some(ok_hit_window), Some(meh_hit_window)) => {
+                let n = f64::max(1.0, great + ok);
+                let p = great / n;
+
+                deviation_with_tails(
+                    p_bound(n, p),
+                    great,
+                    ok,
+                    meh,
+                    od_great,
+                    ok_hit_window,
+                    meh_hit_window,
+                )
+            }
+            _ => {
+                if judgements.great == 0 {
+                    return None;
+                }
+
+                let n = f64::from(judgements.total());
+                let p = great / n;
+
+                Some(od_great / (f64::sqrt(2.0) * erf_inv(p_bound(n, p))))
+            }
+        };
+
+        let deviation = estimate(|_, p| p)?;
+        let deviation_lower = estimate(|n, p| p.max(wilson_upper_bound(n, p)).min(1.0))?;
+        let deviation_upper = estimate(|n, p| p.min(wilson_lower_bound(n, p)))?;
+
+        Some(Self {
+            unstable_rate: deviation * 10.0,
+            unstable_rate_lower: deviation_lower * 10.0,
+            unstable_rate_upper: deviation_upper * 10.0,
+        })
+    }
+}
+
+/// The lower bound of the Wilson score interval.
+///
+/// We can be 99% confident that the population proportion is at least this
+/// value.
+pub(crate) fn wilson_lower_bound(n: f64, p: f64) -> f64 {
+    (n * p + Z * Z / 2.0) / (n + Z * Z)
+        - Z / (n + Z * Z) * f64::sqrt(n * p * (1.0 - p) + Z * Z / 4.0)
+}
+
+/// The upper bound of the Wilson score interval.
+fn wilson_upper_bound(n: f64, p: f64) -> f64 {
+    (n * p + Z * Z / 2.0) / (n + Z * Z)
+        + Z / (n + Z * Z) * f64::sqrt(n * p * (1.0 - p) + Z * Z / 4.0)
+}
+
+/// Upper bound on the deviation of `n` hits where a proportion of `p` are
+/// within the great hit window.
+///
+/// upstream: `TaikoPerformanceCalculator.computeDeviationUpperBound`
+pub(crate) fn deviation_upper_bound(great_hit_window: f64, n: f64, p: f64) -> f64 {
+    great_hit_window / (f64::sqrt(2.0) * erf_inv(wilson_lower_bound(n, p)))
+}
+
+/// Deviation of greats and oks with `p` being the proportion of greats,
+/// combined with the variance of mehs.
+///
+/// upstream: `OsuPerformanceCalculator.calculateDeviation`
+pub(crate) fn deviation_with_tails(
+    p: f64,
+    count_great: f64,
+    count_ok: f64,
+    count_meh: f64,
+    great_hit_window: f64,
+    ok_hit_window: f64,
+    meh_hit_window: f64,
+) -> Option<f64> {
+    if count_great + count_ok + count_meh <= 0.0 {
+        return None;
+    }
+
+    // * Tested max precision for the deviation calculation.
+    let deviation = if p >= 1.0 {
+        // All greats and oks are assumed to be perfectly centered
+        0.0
+    } else if p > 0.01 {
+        // * Compute deviation assuming greats and oks are normally distributed.
+        let mut deviation = great_hit_window / (LAZER_SQRT_2 * erf_inv(p));
+
+        // * Subtract the variance provided by tails outside the ok window.
+        let ratio = ok_hit_window / deviation;
+        let ok_hit_window_tail_amount =
+            f64::sqrt(2.0 / PI) * ok_hit_window * f64::exp(-0.5 * ratio * ratio)
+                / (deviation * erf(ok_hit_window / (LAZER_SQRT_2 * deviation)));
+
+        deviation *= f64::sqrt(1.0 - ok_hit_window_tail_amount);
+        deviation
+    } else {
+        // * Tested limit for a score containing only oks.
+        ok_hit_window / f64::sqrt(3.0)
+    };
+
+    // * Then compute the variance for mehs.
+    let meh_variance = (meh_hit_window * meh_hit_window
+        + ok_hit_window * meh_hit_window
+        + ok_hit_window * ok_hit_window)
+        / 3.0;
+
+    // * Find the total deviation.
+    let deviation = f64::sqrt(
+        ((count_great + count_ok) * deviation * deviation + count_meh * meh_variance)
+            / (count_great + count_ok + count_meh),
+    );
+
+    Some(deviation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OSU_WINDOWS: HitWindows = HitWindows {
+        ar: 600.0,
+        od_great: 32.0,
+        od_ok: Some(74.0),
+        od_meh: Some(116.0),
+    };
+
+    const TAIKO_WINDOWS: HitWindows = HitWindows {
+        ar: 0.0,
+        od_great: 34.5,
+        od_ok: Some(84.5),
+        od_meh: None,
+    };
+
+    #[test]
+    fn no_judgements() {
+        let judgements = HitJudgements::default();
+
+        assert!(DeviationEstimate::new(&OSU_WINDOWS, judgements).is_none());
+        assert!(DeviationEstimate::new(&TAIKO_WINDOWS, judgements).is_none());
+    }
+
+    #[test]
+    fn bounds_are_ordered() {
+        let judgements = HitJudgements {
+            great: 900,
+            ok: 80,
+            meh: 10,
+            misses: 10,
+        };
+
+        for windows in [OSU_WINDOWS, TAIKO_WINDOWS] {
+            let estimate = DeviationEstimate::new(&windows, judgements).unwrap();
+
+            assert!(estimate.unstable_rate_lower <= estimate.unstable_rate);
+            assert!(estimate.unstable_rate <= estimate.unstable_rate_upper);
+        }
+    }
+
+    #[test]
+    fn catch_has_no_judgements() {
+        let state = ScoreState {
+            n300: 500,
+            misses: 2,
+            ..ScoreState::new()
+        };
+
+        assert!(HitJudgements::new(&state, GameMode::Catch).is_none());
+        assert!(HitJudgements::new(&state, GameMode::Osu).is_some());
+    }
+
+    #[test]
+    fn osu_ignores_misses() {
+        let judgements = HitJudgements {
+            great: 900,
+            ok: 80,
+            meh: 10,
+            misses: 0,
+        };
+
+        let with_misses = HitJudgements {
+            misses: 50,
+            ..judgements
+        };
+
+        assert_eq!(
+            DeviationEstimate::new(&OSU_WINDOWS, judgements),
+            DeviationEstimate::new(&OSU_WINDOWS, with_misses),
+        );
+    }
+
+    #[test]
+    fn taiko_upper_bound() {
+        let judgements = HitJudgements {
+            great: 950,
+            ok: 45,
+            meh: 0,
+            misses: 5,
+        };
+
+        let estimate = DeviationEstimate::new(&TAIKO_WINDOWS, judgements).unwrap();
+        let expected = deviation_upper_bound(TAIKO_WINDOWS.od_great, 1000.0, 0.95) * 10.0;
+
+        assert!((estimate.unstable_rate_upper - expected).abs() < f64::EPSILON);
+    }
+}
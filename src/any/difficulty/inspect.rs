@@ -1,9 +1,14 @@
-use crate::{model::mods::GameMods, Difficulty};
+use crate::{any::AlgorithmVersion, model::mods::GameMods, osu::OsuParameters, Difficulty};
 
 use super::ModsDependent;
 
 /// [`Difficulty`] but all fields are public for inspection.
+///
+/// With the `serde` feature, [`Difficulty`] itself is (de)serialized through
+/// this type so a deserialized [`InspectDifficulty`] can be turned back into
+/// a [`Difficulty`] with [`InspectDifficulty::into_difficulty`].
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InspectDifficulty {
     /// Specify mods.
     pub mods: GameMods,
@@ -32,6 +37,14 @@ pub struct InspectDifficulty {
     ///
     /// Defaults to `true`.
     pub lazer: Option<bool>,
+    /// Version of the difficulty and performance algorithms.
+    ///
+    /// Defaults to [`AlgorithmVersion::Mamestagram`].
+    pub algorithm: Option<AlgorithmVersion>,
+    /// Override the constants of the osu!standard calculation.
+    ///
+    /// Defaults to [`OsuParameters::DEFAULT`].
+    pub osu_parameters: Option<OsuParameters>,
 }
 
 impl InspectDifficulty {
@@ -47,6 +60,8 @@ impl InspectDifficulty {
             od,
             hardrock_offsets,
             lazer,
+            algorithm,
+            osu_parameters,
         } = self;
 
         let mut difficulty = Difficulty::new().mods(mods);
@@ -83,6 +98,14 @@ impl InspectDifficulty {
             difficulty = difficulty.lazer(lazer);
         }
 
+        if let Some(algorithm) = algorithm {
+            difficulty = difficulty.algorithm(algorithm);
+        }
+
+        if let Some(osu_parameters) = osu_parameters {
+            difficulty = difficulty.osu_parameters(osu_parameters);
+        }
+
         difficulty
     }
 }
@@ -98,3 +121,37 @@ impl From<Difficulty> for InspectDifficulty {
         difficulty.inspect()
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::{any::DifficultyAttributes, model::mods::reexports::GameModsIntermode, Beatmap};
+
+    use super::*;
+
+    #[test]
+    fn serde_roundtrip() {
+        let difficulty = Difficulty::new()
+            .mods(GameModsIntermode::from_acronyms("HDDT"))
+            .clock_rate(1.3)
+            .ar(9.5, true)
+            .passed_objects(100);
+
+        let json = serde_json::to_string(&difficulty).unwrap();
+        let inspect: InspectDifficulty = serde_json::from_str(&json).unwrap();
+
+        assert!(inspect.mods.hd() && inspect.mods.dt());
+        assert_eq!(inspect.passed_objects, Some(100));
+
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+        let attrs = difficulty.calculate(&map);
+
+        assert_eq!(inspect.into_difficulty().calculate(&map), attrs);
+
+        let json = serde_json::to_string(&attrs).unwrap();
+        let deserialized: DifficultyAttributes = serde_json::from_str(&json).unwrap();
+
+        // serde_json may be off by one ulp when parsing floats
+        assert_eq!(deserialized.max_combo(), attrs.max_combo());
+        assert!((deserialized.stars() - attrs.stars()).abs() < 1e-12);
+    }
+}
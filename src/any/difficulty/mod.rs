@@ -8,17 +8,20 @@ use rosu_map::section::general::GameMode;
 use crate::{
     catch::Catch,
     mania::Mania,
-    model::{beatmap::Beatmap, mode::ConvertError, mods::GameMods},
-    osu::Osu,
+    model::{beatmap::Beatmap, hit_object::HitObject, mode::ConvertError, mods::GameMods},
+    osu::{Osu, OsuParameters},
     taiko::Taiko,
     GradualDifficulty, GradualPerformance,
 };
 
-use super::{attributes::DifficultyAttributes, InspectDifficulty, Strains};
+use super::{attributes::DifficultyAttributes, AlgorithmVersion, InspectDifficulty, Strains};
+
+use self::section::{DifficultySection, SectionWindow};
 
 pub mod gradual;
 pub mod inspect;
 pub mod object;
+pub mod section;
 pub mod skills;
 
 use crate::model::mode::IGameMode;
@@ -37,6 +40,11 @@ use crate::model::mode::IGameMode;
 ///     .calculate(&map);
 /// ```
 #[derive(Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "InspectDifficulty", into = "InspectDifficulty")
+)]
 #[must_use]
 pub struct Difficulty {
     mods: GameMods,
@@ -54,10 +62,14 @@ pub struct Difficulty {
     od: Option<ModsDependent>,
     hardrock_offsets: Option<bool>,
     lazer: Option<bool>,
+    algorithm: Option<AlgorithmVersion>,
+    osu_parameters: Option<Box<OsuParameters>>,
+    section: Option<DifficultySection>,
 }
 
 /// Wrapper for beatmap attributes in [`Difficulty`].
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModsDependent {
     /// Value of the beatmap attribute.
     pub value: f32,
@@ -90,6 +102,9 @@ impl Difficulty {
             od: None,
             hardrock_offsets: None,
             lazer: None,
+            algorithm: None,
+            osu_parameters: None,
+            section: None,
         }
     }
 
@@ -106,6 +121,9 @@ impl Difficulty {
             od,
             hardrock_offsets,
             lazer,
+            algorithm,
+            osu_parameters,
+            section: _,
         } = self;
 
         InspectDifficulty {
@@ -118,6 +136,8 @@ impl Difficulty {
             od,
             hardrock_offsets,
             lazer,
+            algorithm,
+            osu_parameters: osu_parameters.map(|params| *params),
         }
     }
 
@@ -131,6 +151,12 @@ impl Difficulty {
     /// - [`&rosu_mods::GameModsIntermode`](rosu_mods::GameModsIntermode)
     ///
     /// See <https://github.com/ppy/osu-api/wiki#mods>
+    ///
+    /// The osu!taiko Random mod shuffles note colours based on its `seed`
+    /// setting which is only available through [`rosu_mods::GameMods`]. If
+    /// no seed is given, e.g. for legacy mods, the seed `0` is used so that
+    /// results stay deterministic; they will generally not match the
+    /// colours of the actual play.
     pub fn mods(self, mods: impl Into<GameMods>) -> Self {
         Self {
             mods: mods.into(),
@@ -265,6 +291,24 @@ impl Difficulty {
         self
     }
 
+    /// Specify the [`AlgorithmVersion`] of the calculation.
+    ///
+    /// Defaults to [`AlgorithmVersion::Mamestagram`].
+    pub const fn algorithm(mut self, algorithm: AlgorithmVersion) -> Self {
+        self.algorithm = Some(algorithm);
+
+        self
+    }
+
+    /// Override the constants of the osu!standard calculation.
+    ///
+    /// Defaults to [`OsuParameters::DEFAULT`].
+    pub fn osu_parameters(mut self, params: OsuParameters) -> Self {
+        self.osu_parameters = Some(Box::new(params));
+
+        self
+    }
+
     /// Perform the difficulty calculation.
     #[allow(clippy::missing_panics_doc)]
     pub fn calculate(&self, map: &Beatmap) -> DifficultyAttributes {
@@ -292,6 +336,64 @@ impl Difficulty {
         M::difficulty(self, map)
     }
 
+    /// Perform the difficulty calculation for a section of the map, e.g. a
+    /// kiai.
+    ///
+    /// Strains of the objects before the section carry over so the section
+    /// is not rated as if it started cold. Attributes that count objects,
+    /// e.g. the max combo, only count the objects that start within the
+    /// section so the attributes can be used for the performance
+    /// calculation of the section.
+    ///
+    /// [`Difficulty::passed_objects`] still counts from the start of the map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mames_pp::{any::DifficultySection, Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let section = DifficultySection::Time {
+    ///     start: 60_000.0,
+    ///     end: 90_000.0,
+    /// };
+    ///
+    /// let attrs = Difficulty::new().calculate_section(&map, section);
+    /// let stars = attrs.stars();
+    /// let pp = attrs.performance().accuracy(98.0).calculate().pp();
+    ///
+    /// println!("{stars:.2}* {pp:.2}pp");
+    /// ```
+    pub fn calculate_section(
+        &self,
+        map: &Beatmap,
+        section: DifficultySection,
+    ) -> DifficultyAttributes {
+        self.with_section(section).calculate(map)
+    }
+
+    /// Perform the difficulty calculation for a section of the map for a
+    /// specific [`IGameMode`].
+    ///
+    /// See [`Difficulty::calculate_section`].
+    pub fn calculate_section_for_mode<M: IGameMode>(
+        &self,
+        map: &Beatmap,
+        section: DifficultySection,
+    ) -> Result<M::DifficultyAttributes, ConvertError> {
+        M::difficulty(&self.with_section(section), map)
+    }
+
+    // The section is not exposed as builder method because gradual
+    // calculations process the whole map.
+    fn with_section(&self, section: DifficultySection) -> Self {
+        Self {
+            section: Some(section),
+            ..self.clone()
+        }
+    }
+
     /// Perform the difficulty calculation but instead of evaluating the skill
     /// strains, return them as is.
     ///
@@ -359,6 +461,10 @@ impl Difficulty {
         self.passed_objects.map_or(usize::MAX, |n| n as usize)
     }
 
+    pub(crate) fn get_section_window(&self, hit_objects: &[HitObject]) -> SectionWindow {
+        SectionWindow::new(self.section, hit_objects, self.get_clock_rate())
+    }
+
     pub(crate) const fn get_ar(&self) -> Option<ModsDependent> {
         self.ar
     }
@@ -383,6 +489,16 @@ impl Difficulty {
     pub(crate) fn get_lazer(&self) -> bool {
         self.lazer.unwrap_or(true)
     }
+
+    pub(crate) fn get_algorithm(&self) -> AlgorithmVersion {
+        self.algorithm.unwrap_or_default()
+    }
+
+    pub(crate) fn get_osu_parameters(&self) -> &OsuParameters {
+        static DEFAULT: OsuParameters = OsuParameters::DEFAULT;
+
+        self.osu_parameters.as_deref().unwrap_or(&DEFAULT)
+    }
 }
 
 const fn non_zero_u64_to_f64(n: NonZeroU64) -> f64 {
@@ -401,6 +517,9 @@ impl Debug for Difficulty {
             od,
             hardrock_offsets,
             lazer,
+            algorithm,
+            osu_parameters,
+            section: _,
         } = self;
 
         f.debug_struct("Difficulty")
@@ -413,6 +532,9 @@ impl Debug for Difficulty {
             .field("od", od)
             .field("hardrock_offsets", hardrock_offsets)
             .field("lazer", lazer)
+            .field("algorithm", algorithm)
+            .field("osu_parameters", osu_parameters)
+            .field("section", section)
             .finish()
     }
 }
@@ -0,0 +1,111 @@
+use crate::model::hit_object::HitObject;
+
+/// The part of a map whose difficulty is evaluated.
+///
+/// Objects before the section are still processed so that strains carry over
+/// into the section but only the strains and objects within the section
+/// contribute to the attributes.
+///
+/// See [`Difficulty::calculate_section`](crate::Difficulty::calculate_section).
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DifficultySection {
+    /// Hit object indices, the end being exclusive.
+    ///
+    /// For converted maps, the indices refer to the converted hit objects.
+    Objects { start: u32, end: u32 },
+    /// Milliseconds of the map's own timeline i.e. not adjusted to the clock
+    /// rate, the end being exclusive.
+    Time { start: f64, end: f64 },
+}
+
+/// A [`DifficultySection`] resolved for a map and clock rate.
+///
+/// Difficulty calculators check every difficulty object's start time against
+/// the window before processing it and only count the objects that start
+/// within the window.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct SectionWindow {
+    /// Bounds of the map's own timeline.
+    start: f64,
+    end: f64,
+    clock_rate: f64,
+    entered: bool,
+}
+
+impl SectionWindow {
+    pub fn new(
+        section: Option<DifficultySection>,
+        hit_objects: &[HitObject],
+        clock_rate: f64,
+    ) -> Self {
+        let start_time_of = |idx: u32| {
+            hit_objects
+                .get(idx as usize)
+                .map_or(f64::INFINITY, |h| h.start_time)
+        };
+
+        let (start, end) = match section {
+            Some(DifficultySection::Objects { start, end }) => {
+                (start_time_of(start), start_time_of(end))
+            }
+            Some(DifficultySection::Time { start, end }) => (start, end),
+            None => return Self::default(),
+        };
+
+        Self {
+            start,
+            end,
+            clock_rate,
+            entered: false,
+        }
+    }
+
+    /// Whether the difficulty object at the given clock-rate adjusted start
+    /// time is the first one within the section, i.e. skills should discard
+    /// their history before processing it.
+    pub fn enters(&mut self, start_time: f64) -> bool {
+        if self.entered || start_time < self.start / self.clock_rate {
+            return false;
+        }
+
+        self.entered = true;
+
+        true
+    }
+
+    /// Whether the difficulty object at the given clock-rate adjusted start
+    /// time lies beyond the section, i.e. processing can stop.
+    pub fn is_past(&self, start_time: f64) -> bool {
+        start_time >= self.end / self.clock_rate
+    }
+
+    /// Whether the hit object at the given start time of the map's own
+    /// timeline is counted towards the section's attributes.
+    pub fn contains(&self, start_time: f64) -> bool {
+        self.start <= start_time && start_time < self.end
+    }
+
+    /// The objects that are counted towards the section's attributes.
+    ///
+    /// The objects must be sorted by their start time of the map's own
+    /// timeline.
+    pub fn slice<'a, T>(&self, objects: &'a [T], start_time: impl Fn(&T) -> f64) -> &'a [T] {
+        let start = objects.partition_point(|h| start_time(h) < self.start);
+        let end = objects.partition_point(|h| start_time(h) < self.end);
+
+        &objects[start..end.max(start)]
+    }
+}
+
+impl Default for SectionWindow {
+    /// The whole map.
+    fn default() -> Self {
+        Self {
+            start: f64::NEG_INFINITY,
+            end: f64::INFINITY,
+            clock_rate: 1.0,
+            entered: true,
+        }
+    }
+}
@@ -15,6 +15,10 @@ pub trait StrainSkill: Sized {
 
     fn count_top_weighted_strains(&self, difficulty_value: f64) -> f64;
 
+    /// Discard the strains of all processed objects while keeping the current
+    /// strain so that it carries over into subsequent objects.
+    fn discard_history(&mut self);
+
     fn save_current_peak(&mut self);
 
     fn start_new_section_from<'a>(
@@ -0,0 +1,606 @@
+use rosu_map::section::general::GameMode;
+
+use crate::{
+    catch::palpable_object_times,
+    model::{
+        beatmap::{BeatmapAttributesBuilder, BreakPeriod},
+        hit_object::HitObject,
+        mode::ConvertError,
+    },
+    Beatmap, GameMods,
+};
+
+use super::HitResult;
+
+/// upstream: `HealthProcessor.DEFAULT_MAX_HEALTH_INCREASE`
+const DEFAULT_MAX_HEALTH_INCREASE: f64 = 0.05;
+
+/// Amount of retries that the Easy mod grants in osu!standard and osu!catch.
+///
+/// upstream: `ModEasyWithExtraLives`
+const EASY_EXTRA_LIVES: u8 = 2;
+
+/// osu!taiko fails if the health at the end of a map does not exceed this.
+///
+/// upstream: `AccumulatingHealthProcessor.CheckDefaultFailCondition`
+const TAIKO_REQUIRED_HEALTH: f64 = 0.5;
+
+/// Simulates the health bar throughout a play to determine whether and
+/// where the player failed.
+///
+/// Each object judgement, see [`HitResult::is_object`], corresponds to one of
+/// the objects that are counted by [`Difficulty::passed_objects`], i.e.
+/// - osu!standard: hit objects
+/// - osu!taiko: circles
+/// - osu!catch: fruits and droplets
+/// - osu!mania: notes
+///
+/// osu!standard, osu!catch, and osu!mania drain health over time outside of
+/// [`Beatmap::breaks`] while osu!taiko only accumulates health and requires
+/// more than half of it at the end of the map. Judgements of nested objects
+/// such as slider ticks or tiny droplets are optional and don't drain
+/// health.
+///
+/// The drain rate is an approximation: unlike lazer, it is computed from
+/// the main objects only so nested objects such as slider ticks, slider
+/// repeats, or hold note ticks are not part of the simulated all-max play.
+///
+/// NoFail, Easy, SuddenDeath, and Perfect are taken into account. Easy only
+/// grants extra lives in osu!standard and osu!catch.
+///
+/// # Example
+///
+/// ```
+/// use mames_pp::{Beatmap, Difficulty};
+/// use mames_pp::any::{HealthProcessor, HitResult};
+/// use rosu_map::section::general::GameMode;
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// let health = HealthProcessor::new(&map, GameMode::Osu, 128).unwrap();
+///
+/// let mut results = vec![HitResult::Great; 50];
+/// results.push(HitResult::Miss);
+///
+/// let outcome = health.process(results);
+///
+/// if let Some(passed_objects) = outcome.passed_objects() {
+///     let attrs = Difficulty::new()
+///         .passed_objects(passed_objects)
+///         .calculate(&map);
+/// }
+/// ```
+///
+/// [`Difficulty::passed_objects`]: crate::Difficulty::passed_objects
+#[derive(Clone, Debug, PartialEq)]
+pub struct HealthProcessor {
+    mode: GameMode,
+    no_fail: bool,
+    sudden_death: bool,
+    perfect: bool,
+    extra_lives: u8,
+    object_times: Vec<f64>,
+    breaks: Vec<BreakPeriod>,
+    kind: HealthKind,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum HealthKind {
+    /// upstream: `DrainingHealthProcessor`
+    Draining {
+        drain_start_time: f64,
+        drain_rate: f64,
+    },
+    /// upstream: `TaikoHealthProcessor`
+    Accumulating {
+        hit_multiplier: f64,
+        miss_multiplier: f64,
+    },
+}
+
+impl HealthProcessor {
+    /// Prepare the health simulation for the map when played in the given
+    /// mode.
+    ///
+    /// Accepted types for mods are the same as for
+    /// [`Difficulty::mods`](crate::Difficulty::mods).
+    pub fn new(
+        map: &Beatmap,
+        mode: GameMode,
+        mods: impl Into<GameMods>,
+    ) -> Result<Self, ConvertError> {
+        let mods = mods.into();
+        let map = map.convert_ref(mode, &mods)?;
+
+        let hp = BeatmapAttributesBuilder::new()
+            .map(&map)
+            .mods(mods.clone())
+            .build()
+            .hp;
+
+        let object_times: Vec<_> = match mode {
+            GameMode::Osu | GameMode::Mania => {
+                map.hit_objects.iter().map(HitObject::end_time).collect()
+            }
+            GameMode::Taiko => map
+                .hit_objects
+                .iter()
+                .filter(|h| h.is_circle())
+                .map(|h| h.start_time)
+                .collect(),
+            GameMode::Catch => palpable_object_times(&map),
+        };
+
+        let kind = if let GameMode::Taiko = mode {
+            let n_hits = object_times.len().max(1) as f64;
+
+            HealthKind::Accumulating {
+                hit_multiplier: 1.0 / (3.0 * n_hits * difficulty_range(hp, 0.5, 0.75, 0.98)),
+                miss_multiplier: difficulty_range(hp, 0.0018, 0.0075, 0.0120),
+            }
+        } else {
+            let drain_start_time = map.hit_objects.first().map_or(0.0, |h| h.start_time);
+
+            // upstream: `ManiaHealthProcessor` passes a drain lenience of 1
+            let drain_lenience = if let GameMode::Mania = mode { 1.0 } else { 0.0 };
+
+            let mut target_minimum_health = difficulty_range(hp, 0.99, 0.9, 0.4);
+            target_minimum_health += drain_lenience * (1.0 - target_minimum_health);
+
+            let drain_rate = compute_drain_rate(
+                &object_times,
+                &map.breaks,
+                drain_start_time,
+                target_minimum_health.clamp(0.0, 1.0),
+                draining_health_increase(HitResult::max(mode)),
+            );
+
+            HealthKind::Draining {
+                drain_start_time,
+                drain_rate,
+            }
+        };
+
+        let extra_lives = if mods.ez() && matches!(mode, GameMode::Osu | GameMode::Catch) {
+            EASY_EXTRA_LIVES
+        } else {
+            0
+        };
+
+        Ok(Self {
+            mode,
+            no_fail: mods.nf(),
+            sudden_death: mods.sd(),
+            perfect: mods.pf(),
+            extra_lives,
+            object_times,
+            breaks: map.breaks.clone(),
+            kind,
+        })
+    }
+
+    /// Amount of objects that are judged.
+    pub const fn n_objects(&self) -> usize {
+        self.object_times.len()
+    }
+
+    /// Walk through the judgements in order and simulate the health bar.
+    ///
+    /// If there are fewer judgements than objects, the play is considered to
+    /// have been stopped after the last judgement. Surplus judgements are
+    /// ignored.
+    pub fn process(&self, results: impl IntoIterator<Item = HitResult>) -> HealthOutcome {
+        let (mut health, mut last_time) = match self.kind {
+            HealthKind::Draining {
+                drain_start_time, ..
+            } => (1.0, drain_start_time),
+            HealthKind::Accumulating { .. } => (0.0, 0.0),
+        };
+
+        let mut min_health = health;
+        let mut extra_lives = self.extra_lives;
+        let mut curr_break = 0;
+        let mut n_judged = 0;
+
+        let outcome = |health, min_health, n_judged, fail| HealthOutcome {
+            health,
+            min_health,
+            n_judged,
+            fail,
+        };
+
+        let fail = |passed_objects, time, reason| {
+            Some(HealthFail {
+                passed_objects,
+                time,
+                reason,
+            })
+        };
+
+        let mut object_times = self.object_times.iter().copied();
+
+        for result in results {
+            let is_object = result.is_object(self.mode);
+
+            // Nested results are applied right away without any drain
+            let time = if !is_object {
+                last_time
+            } else if let Some(time) = object_times.next() {
+                time
+            } else {
+                break;
+            };
+
+            if let (true, HealthKind::Draining { drain_rate, .. }) = (is_object, self.kind) {
+                // If two objects are separated by a break, there is no drain
+                // for the full duration between them.
+                while self
+                    .breaks
+                    .get(curr_break)
+                    .is_some_and(|b| b.end_time <= time)
+                {
+                    last_time = time;
+                    curr_break += 1;
+                }
+
+                let mut now = last_time;
+
+                loop {
+                    let drained = (time - now).max(0.0) * drain_rate;
+
+                    if drained < health || self.no_fail {
+                        health = (health - drained).max(0.0);
+
+                        break;
+                    }
+
+                    now += health / drain_rate;
+
+                    if extra_lives == 0 {
+                        let reason = FailReason::HealthDepleted;
+
+                        return outcome(0.0, 0.0, n_judged, fail(n_judged, now, reason));
+                    }
+
+                    extra_lives -= 1;
+                    min_health = 0.0;
+                    health = 1.0;
+                }
+
+                min_health = f64::min(min_health, health);
+            }
+
+            health = (health + self.health_increase(result)).clamp(0.0, 1.0);
+            min_health = f64::min(min_health, health);
+
+            if is_object {
+                n_judged += 1;
+                last_time = time;
+            }
+
+            if self.no_fail {
+                continue;
+            }
+
+            if self.sudden_death && result.breaks_combo() {
+                let reason = FailReason::SuddenDeath;
+
+                return outcome(health, min_health, n_judged, fail(n_judged, time, reason));
+            }
+
+            if self.perfect && !result.is_max(self.mode) {
+                let reason = FailReason::Perfect;
+
+                return outcome(health, min_health, n_judged, fail(n_judged, time, reason));
+            }
+
+            if matches!(self.kind, HealthKind::Draining { .. }) && health <= 0.0 {
+                if extra_lives == 0 {
+                    let reason = FailReason::HealthDepleted;
+
+                    return outcome(health, min_health, n_judged, fail(n_judged, time, reason));
+                }
+
+                extra_lives -= 1;
+                health = 1.0;
+            }
+        }
+
+        let is_complete = n_judged as usize == self.object_times.len();
+
+        if let HealthKind::Accumulating { .. } = self.kind {
+            if is_complete && !self.no_fail && health <= TAIKO_REQUIRED_HEALTH {
+                let reason = FailReason::InsufficientHealth;
+
+                return outcome(
+                    health,
+                    min_health,
+                    n_judged,
+                    fail(n_judged, last_time, reason),
+                );
+            }
+        }
+
+        outcome(health, min_health, n_judged, None)
+    }
+
+    fn health_increase(&self, result: HitResult) -> f64 {
+        match self.kind {
+            HealthKind::Draining { .. } => draining_health_increase(result),
+            HealthKind::Accumulating {
+                hit_multiplier,
+                miss_multiplier,
+            } => {
+                // upstream: `TaikoJudgement.HealthIncreaseFor`
+                let increase = match result {
+                    HitResult::Great => 2.0,
+                    HitResult::Ok => 1.1,
+                    HitResult::Miss => -1.0,
+                    _ => 0.0,
+                };
+
+                if result.is_hit() {
+                    increase * hit_multiplier
+                } else {
+                    increase * miss_multiplier
+                }
+            }
+        }
+    }
+}
+
+/// The result of [`HealthProcessor::process`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HealthOutcome {
+    /// The health after the last processed judgement, between 0 and 1.
+    pub health: f64,
+    /// The lowest health throughout the play.
+    pub min_health: f64,
+    /// The amount of processed judgements.
+    pub n_judged: u32,
+    /// Information about the fail, if any.
+    pub fail: Option<HealthFail>,
+}
+
+impl HealthOutcome {
+    /// Whether the player failed.
+    pub const fn is_fail(&self) -> bool {
+        self.fail.is_some()
+    }
+
+    /// The amount of passed objects if the player failed.
+    ///
+    /// Intended to be passed to
+    /// [`Difficulty::passed_objects`](crate::Difficulty::passed_objects).
+    pub fn passed_objects(&self) -> Option<u32> {
+        self.fail.map(|fail| fail.passed_objects)
+    }
+}
+
+/// Where and why the player failed.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HealthFail {
+    /// The amount of objects that were judged before failing.
+    pub passed_objects: u32,
+    /// The time of the fail in milliseconds.
+    pub time: f64,
+    /// The reason of the fail.
+    pub reason: FailReason,
+}
+
+/// The reason of a fail.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FailReason {
+    /// The health dropped to zero.
+    HealthDepleted,
+    /// The combo broke with SuddenDeath.
+    SuddenDeath,
+    /// An object was not judged with the best result with Perfect.
+    Perfect,
+    /// osu!taiko: not enough health at the end of the map.
+    InsufficientHealth,
+}
+
+/// upstream: `Judgement.HealthIncreaseFor`
+const fn draining_health_increase(result: HitResult) -> f64 {
+    match result {
+        HitResult::Perfect => DEFAULT_MAX_HEALTH_INCREASE * 1.05,
+        HitResult::Great => DEFAULT_MAX_HEALTH_INCREASE,
+        HitResult::Good => DEFAULT_MAX_HEALTH_INCREASE * 0.75,
+        HitResult::Ok => DEFAULT_MAX_HEALTH_INCREASE * 0.5,
+        HitResult::Meh => -DEFAULT_MAX_HEALTH_INCREASE * 0.05,
+        HitResult::Miss | HitResult::LargeTickMiss => -DEFAULT_MAX_HEALTH_INCREASE,
+        HitResult::LargeTickHit | HitResult::SliderTailHit => DEFAULT_MAX_HEALTH_INCREASE,
+        HitResult::SmallTickHit => DEFAULT_MAX_HEALTH_INCREASE * 0.5,
+        HitResult::SmallTickMiss => -DEFAULT_MAX_HEALTH_INCREASE * 0.5,
+        HitResult::SliderTailMiss => 0.0,
+    }
+}
+
+/// Binary search for the drain rate so that an all-max play ends up with
+/// roughly the targeted minimum health.
+///
+/// Only the given object times are considered, nested objects are not.
+///
+/// upstream: `DrainingHealthProcessor.ComputeDrainRate`
+fn compute_drain_rate(
+    object_times: &[f64],
+    breaks: &[BreakPeriod],
+    drain_start_time: f64,
+    target_minimum_health: f64,
+    health_increase: f64,
+) -> f64 {
+    if object_times.len() <= 1 {
+        return 0.0;
+    }
+
+    let mut adjustment: i32 = 1;
+    let mut result = 1.0;
+
+    // The search is expected to converge within 30 iterations; the overflow
+    // of `adjustment` is the safety measure against infinite loops.
+    while adjustment > 0 {
+        let mut curr_health = 1.0;
+        let mut lowest_health: f64 = 1.0;
+        let mut curr_break = 0;
+
+        for (i, &curr_time) in object_times.iter().enumerate() {
+            let mut last_time = if i > 0 {
+                object_times[i - 1]
+            } else {
+                drain_start_time
+            };
+
+            while breaks
+                .get(curr_break)
+                .is_some_and(|b| b.end_time <= curr_time)
+            {
+                last_time = curr_time;
+                curr_break += 1;
+            }
+
+            curr_health -= (curr_time - last_time) * result;
+            lowest_health = lowest_health.min(curr_health);
+            curr_health = f64::min(1.0, curr_health + health_increase);
+
+            if lowest_health < 0.0 {
+                break;
+            }
+        }
+
+        if (lowest_health - target_minimum_health).abs() <= 0.01 {
+            break;
+        }
+
+        adjustment = adjustment.wrapping_mul(2);
+        result += 1.0 / f64::from(adjustment) * (lowest_health - target_minimum_health).signum();
+    }
+
+    result
+}
+
+/// upstream: `IBeatmapDifficultyInfo.DifficultyRange`
+fn difficulty_range(difficulty: f64, min: f64, mid: f64, max: f64) -> f64 {
+    if difficulty > 5.0 {
+        mid + (max - mid) * (difficulty - 5.0) / 5.0
+    } else if difficulty < 5.0 {
+        mid - (mid - min) * (5.0 - difficulty) / 5.0
+    } else {
+        mid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map() -> Beatmap {
+        Beatmap::from_path("./resources/2785319.osu").unwrap()
+    }
+
+    #[test]
+    fn all_greats_pass() {
+        let map = map();
+
+        for mode in [GameMode::Osu, GameMode::Taiko, GameMode::Catch] {
+            let health = HealthProcessor::new(&map, mode, 0).unwrap();
+            let results = vec![HitResult::Great; health.n_objects()];
+            let outcome = health.process(results);
+
+            assert!(!outcome.is_fail(), "{mode:?}");
+            assert_eq!(outcome.n_judged as usize, health.n_objects(), "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn all_misses_fail() {
+        let map = map();
+        let health = HealthProcessor::new(&map, GameMode::Osu, 0).unwrap();
+        let outcome = health.process(vec![HitResult::Miss; health.n_objects()]);
+
+        let fail = outcome.fail.unwrap();
+        assert_eq!(fail.reason, FailReason::HealthDepleted);
+        assert!((fail.passed_objects as usize) < health.n_objects());
+
+        let no_fail = HealthProcessor::new(&map, GameMode::Osu, 1).unwrap();
+        assert!(!no_fail
+            .process(vec![HitResult::Miss; health.n_objects()])
+            .is_fail());
+
+        let easy = HealthProcessor::new(&map, GameMode::Osu, 2).unwrap();
+        let easy_fail = easy
+            .process(vec![HitResult::Miss; health.n_objects()])
+            .fail
+            .unwrap();
+        assert!(easy_fail.passed_objects > fail.passed_objects);
+    }
+
+    #[test]
+    fn easy_extra_lives_per_mode() {
+        let map = map();
+
+        for (mode, extra_lives) in [
+            (GameMode::Osu, EASY_EXTRA_LIVES),
+            (GameMode::Taiko, 0),
+            (GameMode::Catch, EASY_EXTRA_LIVES),
+            (GameMode::Mania, 0),
+        ] {
+            let easy = HealthProcessor::new(&map, mode, 2).unwrap();
+            assert_eq!(easy.extra_lives, extra_lives, "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn sudden_death_and_perfect() {
+        let map = map();
+        let mut results = vec![HitResult::Great; 10];
+        results.push(HitResult::Ok);
+        results.push(HitResult::Miss);
+
+        let sudden_death = HealthProcessor::new(&map, GameMode::Osu, 32).unwrap();
+        let fail = sudden_death.process(results.iter().copied()).fail.unwrap();
+        assert_eq!(fail.reason, FailReason::SuddenDeath);
+        assert_eq!(fail.passed_objects, 12);
+
+        let perfect = HealthProcessor::new(&map, GameMode::Osu, 16384 | 32).unwrap();
+        let fail = perfect.process(results).fail.unwrap();
+        assert_eq!(fail.reason, FailReason::Perfect);
+        assert_eq!(fail.passed_objects, 11);
+    }
+
+    #[test]
+    fn taiko_requires_health_at_the_end() {
+        let map = map();
+        let health = HealthProcessor::new(&map, GameMode::Taiko, 0).unwrap();
+        let n_objects = health.n_objects();
+
+        let mut results = vec![HitResult::Miss; n_objects * 2 / 3];
+        results.resize(n_objects, HitResult::Great);
+
+        let fail = health.process(results).fail.unwrap();
+        assert_eq!(fail.reason, FailReason::InsufficientHealth);
+        assert_eq!(fail.passed_objects as usize, n_objects);
+    }
+
+    #[test]
+    fn taiko_fails_at_exactly_half_health() {
+        let health = |hit_multiplier| HealthProcessor {
+            mode: GameMode::Taiko,
+            no_fail: false,
+            sudden_death: false,
+            perfect: false,
+            extra_lives: 0,
+            object_times: vec![0.0],
+            breaks: Vec::new(),
+            kind: HealthKind::Accumulating {
+                hit_multiplier,
+                miss_multiplier: 0.0,
+            },
+        };
+
+        // A Great increases health by `2 * hit_multiplier`
+        let outcome = health(0.25).process([HitResult::Great]);
+        assert_eq!(outcome.fail.unwrap().reason, FailReason::InsufficientHealth);
+
+        assert!(!health(0.3).process([HitResult::Great]).is_fail());
+    }
+}
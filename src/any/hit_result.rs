@@ -0,0 +1,113 @@
+use rosu_map::section::general::GameMode;
+
+/// The judgement of a single object.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HitResult {
+    /// osu!mania's 320s.
+    Perfect,
+    /// 300s, or caught fruits in osu!catch.
+    Great,
+    /// osu!mania's 200s.
+    Good,
+    /// 100s.
+    Ok,
+    /// 50s.
+    Meh,
+    /// Misses.
+    Miss,
+    /// Hit slider ticks and repeats in osu!standard, or caught droplets in
+    /// osu!catch.
+    LargeTickHit,
+    /// Missed slider ticks and repeats in osu!standard, or missed droplets in
+    /// osu!catch.
+    LargeTickMiss,
+    /// Hit slider ends of osu!lazer scores without slider accuracy, or caught
+    /// tiny droplets in osu!catch.
+    SmallTickHit,
+    /// Missed slider ends of osu!lazer scores without slider accuracy, or
+    /// missed tiny droplets in osu!catch.
+    SmallTickMiss,
+    /// Hit slider ends in osu!standard.
+    SliderTailHit,
+    /// Missed slider ends in osu!standard.
+    ///
+    /// Unlike other misses, this does not break the combo.
+    SliderTailMiss,
+}
+
+impl HitResult {
+    /// The best possible [`HitResult`] for objects of the given mode.
+    pub const fn max(mode: GameMode) -> Self {
+        match mode {
+            GameMode::Osu | GameMode::Taiko | GameMode::Catch => Self::Great,
+            GameMode::Mania => Self::Perfect,
+        }
+    }
+
+    /// Whether the object was hit.
+    pub const fn is_hit(self) -> bool {
+        !matches!(
+            self,
+            Self::Miss | Self::LargeTickMiss | Self::SmallTickMiss | Self::SliderTailMiss
+        )
+    }
+
+    /// Whether the result is the best possible one for its object.
+    pub const fn is_max(self, mode: GameMode) -> bool {
+        match self {
+            Self::Great => !matches!(mode, GameMode::Mania),
+            Self::Perfect | Self::LargeTickHit | Self::SmallTickHit | Self::SliderTailHit => true,
+            Self::Good
+            | Self::Ok
+            | Self::Meh
+            | Self::Miss
+            | Self::LargeTickMiss
+            | Self::SmallTickMiss
+            | Self::SliderTailMiss => false,
+        }
+    }
+
+    /// Whether the result judges one of the objects that are counted by
+    /// [`Difficulty::passed_objects`] in the given mode.
+    ///
+    /// Otherwise, it judges a nested object such as a slider tick.
+    ///
+    /// [`Difficulty::passed_objects`]: crate::Difficulty::passed_objects
+    pub const fn is_object(self, mode: GameMode) -> bool {
+        match self {
+            Self::Perfect | Self::Great | Self::Good | Self::Ok | Self::Meh | Self::Miss => true,
+            Self::LargeTickHit | Self::LargeTickMiss => matches!(mode, GameMode::Catch),
+            Self::SmallTickHit
+            | Self::SmallTickMiss
+            | Self::SliderTailHit
+            | Self::SliderTailMiss => false,
+        }
+    }
+
+    /// Whether the result breaks the combo.
+    pub const fn breaks_combo(self) -> bool {
+        matches!(self, Self::Miss | Self::LargeTickMiss)
+    }
+
+    /// Whether the result increases the combo.
+    ///
+    /// Small ticks such as tiny droplets don't affect the combo.
+    ///
+    /// upstream: `HitResultExtensions.AffectsCombo`
+    pub const fn increases_combo(self) -> bool {
+        match self {
+            Self::Perfect
+            | Self::Great
+            | Self::Good
+            | Self::Ok
+            | Self::Meh
+            | Self::LargeTickHit
+            | Self::SliderTailHit => true,
+            Self::Miss
+            | Self::LargeTickMiss
+            | Self::SmallTickHit
+            | Self::SmallTickMiss
+            | Self::SliderTailMiss => false,
+        }
+    }
+}
@@ -0,0 +1,405 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use rosu_map::section::general::GameMode;
+use rosu_mods::serde::GameModsSeed;
+use serde::{
+    de::{DeserializeSeed, Error as DeError},
+    Deserialize, Deserializer,
+};
+
+use crate::GameMods;
+
+use super::{DifficultyAttributes, IntoPerformance, Performance, ScoreState};
+
+/// A score as it is sent by osu!lazer or the osu!api v2.
+///
+/// # Example
+///
+/// ```
+/// use mames_pp::{any::LazerScore, Beatmap, Difficulty};
+///
+/// let json = r#"{
+///     "ruleset_id": 0,
+///     "max_combo": 800,
+///     "mods": [{ "acronym": "HD" }, { "acronym": "DT", "settings": { "speed_change": 1.3 } }],
+///     "statistics": { "great": 500, "ok": 20, "miss": 1, "large_tick_hit": 90, "slider_tail_hit": 180 },
+///     "maximum_statistics": { "great": 521, "large_tick_hit": 92, "slider_tail_hit": 184 }
+/// }"#;
+///
+/// let score = LazerScore::from_json(json).unwrap();
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// let attrs = Difficulty::new().mods(score.mods.clone()).calculate(&map);
+///
+/// if let Err(err) = score.validate(&attrs) {
+///     println!("Score was set on a different version of the map: {err}");
+/// }
+///
+/// let pp = score.performance(attrs).calculate().pp();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LazerScore {
+    /// The mode of the score.
+    pub mode: GameMode,
+    /// The mods including their settings.
+    pub mods: GameMods,
+    /// The maximum combo of the score.
+    pub max_combo: u32,
+    /// The judgements of the score.
+    pub statistics: LazerStatistics,
+    /// The judgements of a perfect score on the same map.
+    pub maximum_statistics: LazerStatistics,
+    /// The total score of a score that was set on osu!stable.
+    ///
+    /// `None` for scores set on osu!lazer.
+    pub legacy_total_score: Option<u64>,
+}
+
+/// Judgement counts of a [`LazerScore`], keyed by their hit result name.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct LazerStatistics {
+    pub perfect: u32,
+    pub great: u32,
+    pub good: u32,
+    pub ok: u32,
+    pub meh: u32,
+    pub miss: u32,
+    pub large_tick_hit: u32,
+    pub large_tick_miss: u32,
+    pub small_tick_hit: u32,
+    pub small_tick_miss: u32,
+    pub slider_tail_hit: u32,
+    pub small_bonus: u32,
+    pub large_bonus: u32,
+    pub ignore_hit: u32,
+    pub ignore_miss: u32,
+}
+
+impl LazerScore {
+    /// Deserialize a [`LazerScore`] from JSON.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Map the statistics onto a [`ScoreState`].
+    pub const fn state(&self) -> ScoreState {
+        let stats = &self.statistics;
+
+        let mut state = ScoreState {
+            max_combo: self.max_combo,
+            osu_large_tick_hits: 0,
+            osu_small_tick_hits: 0,
+            slider_end_hits: 0,
+            taiko_drum_roll_tick_hits: 0,
+            taiko_swells_completed: 0,
+            n_geki: stats.perfect,
+            n_katu: stats.good,
+            n300: stats.great,
+            n100: stats.ok,
+            n50: stats.meh,
+            misses: stats.miss,
+        };
+
+        match self.mode {
+            GameMode::Osu => {
+                state.osu_large_tick_hits = stats.large_tick_hit;
+                state.osu_small_tick_hits = stats.small_tick_hit;
+                state.slider_end_hits = stats.slider_tail_hit;
+            }
+            GameMode::Taiko => {
+                state.taiko_drum_roll_tick_hits = stats.small_bonus;
+                state.taiko_swells_completed = stats.large_bonus;
+            }
+            GameMode::Catch => {
+                state.n100 = stats.large_tick_hit;
+                state.n50 = stats.small_tick_hit;
+                state.n_katu = stats.small_tick_miss;
+                state.misses += stats.large_tick_miss;
+            }
+            GameMode::Mania => {}
+        }
+
+        state
+    }
+
+    /// Whether the score was set on osu!stable.
+    pub const fn is_legacy(&self) -> bool {
+        self.legacy_total_score.is_some()
+    }
+
+    /// Create a [`Performance`] calculator for this score.
+    ///
+    /// Mods, the [`ScoreState`], and whether lazer mechanics apply are
+    /// already specified. The legacy total score of osu!stable scores is
+    /// forwarded as well.
+    pub fn performance<'map>(&self, map_or_attrs: impl IntoPerformance<'map>) -> Performance<'map> {
+        let performance = Performance::new(map_or_attrs)
+            .mode_or_ignore(self.mode)
+            .mods(self.mods.clone())
+            .lazer(!self.is_legacy())
+            .state(self.state());
+
+        match self.legacy_total_score {
+            Some(legacy_total_score) => performance.legacy_total_score(legacy_total_score),
+            None => performance,
+        }
+    }
+
+    /// Check whether the maximum statistics match the object counts of the
+    /// given attributes.
+    ///
+    /// A mismatch generally means that the score was set on a different
+    /// version of the map. If there are no maximum statistics, nothing is
+    /// checked.
+    pub fn validate(&self, attrs: &DifficultyAttributes) -> Result<(), LazerScoreMismatch> {
+        if self.maximum_statistics == LazerStatistics::default() {
+            return Ok(());
+        }
+
+        let max = &self.maximum_statistics;
+
+        let checks = match attrs {
+            DifficultyAttributes::Osu(attrs) if self.mode == GameMode::Osu => {
+                let objects = ("great", attrs.n_objects(), max.great);
+
+                if self.mods.no_slider_head_acc(true) {
+                    vec![
+                        objects,
+                        (
+                            "large_tick_hit",
+                            attrs.n_sliders + attrs.n_large_ticks,
+                            max.large_tick_hit,
+                        ),
+                        ("small_tick_hit", attrs.n_sliders, max.small_tick_hit),
+                    ]
+                } else {
+                    vec![
+                        objects,
+                        ("large_tick_hit", attrs.n_large_ticks, max.large_tick_hit),
+                        ("slider_tail_hit", attrs.n_sliders, max.slider_tail_hit),
+                    ]
+                }
+            }
+            DifficultyAttributes::Taiko(attrs) if self.mode == GameMode::Taiko => {
+                vec![("great", attrs.max_combo, max.great)]
+            }
+            DifficultyAttributes::Catch(attrs) if self.mode == GameMode::Catch => vec![
+                ("great", attrs.n_fruits, max.great),
+                ("large_tick_hit", attrs.n_droplets, max.large_tick_hit),
+                ("small_tick_hit", attrs.n_tiny_droplets, max.small_tick_hit),
+            ],
+            // Hold notes are judged at their head and tail
+            DifficultyAttributes::Mania(attrs) if self.mode == GameMode::Mania => {
+                vec![("perfect", attrs.n_objects + attrs.n_hold_notes, max.perfect)]
+            }
+            _ => {
+                let attrs = match attrs {
+                    DifficultyAttributes::Osu(_) => GameMode::Osu,
+                    DifficultyAttributes::Taiko(_) => GameMode::Taiko,
+                    DifficultyAttributes::Catch(_) => GameMode::Catch,
+                    DifficultyAttributes::Mania(_) => GameMode::Mania,
+                };
+
+                return Err(LazerScoreMismatch::Mode {
+                    score: self.mode,
+                    attrs,
+                });
+            }
+        };
+
+        for (statistic, expected, actual) in checks {
+            if expected != actual {
+                return Err(LazerScoreMismatch::Statistic {
+                    statistic,
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'de> Deserialize<'de> for LazerScore {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct RawLazerScore {
+            ruleset_id: u8,
+            #[serde(default)]
+            mods: Option<serde_json::Value>,
+            #[serde(default)]
+            max_combo: u32,
+            #[serde(default)]
+            statistics: LazerStatistics,
+            #[serde(default)]
+            maximum_statistics: LazerStatistics,
+            #[serde(default)]
+            legacy_total_score: Option<u64>,
+        }
+
+        let raw = RawLazerScore::deserialize(d)?;
+
+        let mode = match raw.ruleset_id {
+            0 => GameMode::Osu,
+            1 => GameMode::Taiko,
+            2 => GameMode::Catch,
+            3 => GameMode::Mania,
+            id => return Err(DeError::custom(format!("invalid ruleset id {id}"))),
+        };
+
+        // The mods can only be deserialized once the mode is known
+        let mods = match raw.mods {
+            Some(mods) => GameModsSeed::Mode {
+                mode: rosu_mods::GameMode::from(raw.ruleset_id),
+                deny_unknown_fields: false,
+            }
+            .deserialize(mods)
+            .map_err(DeError::custom)?,
+            None => rosu_mods::GameMods::new(),
+        };
+
+        Ok(Self {
+            mode,
+            mods: mods.into(),
+            max_combo: raw.max_combo,
+            statistics: raw.statistics,
+            maximum_statistics: raw.maximum_statistics,
+            // osu!lazer scores report a legacy total score of zero
+            legacy_total_score: raw.legacy_total_score.filter(|&score| score > 0),
+        })
+    }
+}
+
+/// Error when the maximum statistics of a [`LazerScore`] don't match the
+/// given attributes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LazerScoreMismatch {
+    /// The attributes were calculated for a different mode.
+    Mode { score: GameMode, attrs: GameMode },
+    /// A maximum statistic differs from the attributes' object count.
+    Statistic {
+        statistic: &'static str,
+        expected: u32,
+        actual: u32,
+    },
+}
+
+impl Error for LazerScoreMismatch {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+impl Display for LazerScoreMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            LazerScoreMismatch::Mode { score, attrs } => {
+                write!(
+                    f,
+                    "Score mode {score:?} does not match attributes mode {attrs:?}"
+                )
+            }
+            LazerScoreMismatch::Statistic {
+                statistic,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Maximum statistic `{statistic}` is {actual} but the map has {expected}"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Beatmap, Difficulty};
+
+    use super::*;
+
+    #[test]
+    fn state_and_validation() {
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+        let attrs = Difficulty::new().calculate(&map);
+
+        let DifficultyAttributes::Osu(ref osu) = attrs else {
+            unreachable!()
+        };
+
+        let json = format!(
+            r#"{{
+                "ruleset_id": 0,
+                "max_combo": 100,
+                "mods": [{{ "acronym": "HD" }}],
+                "statistics": {{ "great": 300, "ok": 10, "miss": 2, "large_tick_hit": 50, "slider_tail_hit": 60, "ignore_hit": 5 }},
+                "maximum_statistics": {{ "great": {}, "large_tick_hit": {}, "slider_tail_hit": {}, "ignore_hit": 5 }}
+            }}"#,
+            osu.n_objects(),
+            osu.n_large_ticks,
+            osu.n_sliders,
+        );
+
+        let score = LazerScore::from_json(&json).unwrap();
+        assert!(score.mods.hd());
+
+        let state = score.state();
+        assert_eq!(state.n300, 300);
+        assert_eq!(state.osu_large_tick_hits, 50);
+        assert_eq!(state.slider_end_hits, 60);
+        assert!(score.validate(&attrs).is_ok());
+
+        let mut outdated = score.clone();
+        outdated.maximum_statistics.slider_tail_hit += 1;
+
+        assert_eq!(
+            outdated.validate(&attrs),
+            Err(LazerScoreMismatch::Statistic {
+                statistic: "slider_tail_hit",
+                expected: osu.n_sliders,
+                actual: osu.n_sliders + 1,
+            })
+        );
+    }
+
+    #[test]
+    fn legacy_score() {
+        let json = |legacy_total_score: u64| {
+            format!(
+                r#"{{
+                    "ruleset_id": 0,
+                    "max_combo": 100,
+                    "mods": [{{ "acronym": "CL" }}],
+                    "statistics": {{ "great": 300, "miss": 2 }},
+                    "legacy_total_score": {legacy_total_score}
+                }}"#
+            )
+        };
+
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+
+        let stable = LazerScore::from_json(&json(1_234_567)).unwrap();
+        assert!(stable.is_legacy());
+
+        let Performance::Osu(performance) = stable.performance(&map) else {
+            unreachable!()
+        };
+
+        assert!(!performance.difficulty.get_lazer());
+        assert_eq!(performance.legacy_total_score, Some(1_234_567));
+
+        let lazer = LazerScore::from_json(&json(0)).unwrap();
+        assert!(!lazer.is_legacy());
+
+        let Performance::Osu(performance) = lazer.performance(&map) else {
+            unreachable!()
+        };
+
+        assert!(performance.difficulty.get_lazer());
+        assert_eq!(performance.legacy_total_score, None);
+    }
+}
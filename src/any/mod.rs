@@ -1,19 +1,36 @@
 pub use self::{
+    algorithm::AlgorithmVersion,
     attributes::{DifficultyAttributes, PerformanceAttributes},
+    deviation::{DeviationEstimate, HitJudgements},
     difficulty::{
-        gradual::GradualDifficulty, inspect::InspectDifficulty, Difficulty, ModsDependent,
+        gradual::GradualDifficulty, inspect::InspectDifficulty, section::DifficultySection,
+        Difficulty, ModsDependent,
     },
+    health::{FailReason, HealthFail, HealthOutcome, HealthProcessor},
+    hit_result::HitResult,
     performance::{
-        gradual::GradualPerformance,
+        gradual::{GradualPerformance, HitResultGradualPerformance},
         into::{IntoModePerformance, IntoPerformance},
         HitResultPriority, Performance,
     },
     score_state::ScoreState,
     strains::Strains,
+    trace::{PerformanceTrace, TraceStep, TraceStepKind},
 };
 
+#[cfg(feature = "serde")]
+pub use self::lazer_score::{LazerScore, LazerScoreMismatch, LazerStatistics};
+
+mod algorithm;
 mod attributes;
+pub mod database_attributes;
+pub(crate) mod deviation;
 pub(crate) mod difficulty;
+mod health;
+mod hit_result;
+#[cfg(feature = "serde")]
+mod lazer_score;
 mod performance;
 mod score_state;
 mod strains;
+pub(crate) mod trace;
@@ -1,7 +1,7 @@
 use rosu_map::section::general::GameMode;
 
 use crate::{
-    any::{PerformanceAttributes, ScoreState},
+    any::{HitResult, PerformanceAttributes, ScoreState},
     catch::{Catch, CatchGradualPerformance},
     mania::{Mania, ManiaGradualPerformance},
     model::mode::{ConvertError, IGameMode},
@@ -25,6 +25,10 @@ use crate::{
 /// [`OsuGradualPerformance`], [`TaikoGradualPerformance`],
 /// [`CatchGradualPerformance`], or [`ManiaGradualPerformance`].
 ///
+/// To avoid keeping track of the score state manually, convert it into a
+/// [`HitResultGradualPerformance`] via [`with_hit_results`] which takes a
+/// [`HitResult`] per object instead.
+///
 /// If you only want to calculate difficulty attributes use [`GradualDifficulty`] instead.
 ///
 /// # Example
@@ -87,6 +91,7 @@ use crate::{
 ///
 /// [`next`]: GradualPerformance::next
 /// [`nth`]: GradualPerformance::nth
+/// [`with_hit_results`]: GradualPerformance::with_hit_results
 /// [`GradualDifficulty`]: crate::GradualDifficulty
 // 504 vs 184 bytes is an acceptable difference and the Osu variant (424 bytes)
 // is likely the most used one anyway.
@@ -163,4 +168,297 @@ impl GradualPerformance {
             GradualPerformance::Mania(gradual) => gradual.len(),
         }
     }
+
+    /// Process a [`HitResult`] per object instead of a [`ScoreState`].
+    pub fn with_hit_results(self) -> HitResultGradualPerformance {
+        HitResultGradualPerformance::new(self)
+    }
+
+    const fn mode(&self) -> GameMode {
+        match self {
+            GradualPerformance::Osu(_) => GameMode::Osu,
+            GradualPerformance::Taiko(_) => GameMode::Taiko,
+            GradualPerformance::Catch(_) => GameMode::Catch,
+            GradualPerformance::Mania(_) => GameMode::Mania,
+        }
+    }
+}
+
+/// Gradually calculate the performance attributes by processing a
+/// [`HitResult`] per object.
+///
+/// Unlike [`GradualPerformance`], the [`ScoreState`] including its combo is
+/// tracked internally.
+///
+/// Results of objects that are counted for passed objects are processed via
+/// [`next`] which returns the resulting [`PerformanceAttributes`]. Objects
+/// with nested objects such as sliders or hold notes are processed via
+/// [`next_with_nested`] which takes the results in osu!lazer's judgement
+/// order: first the object's own result, e.g. the slider head, then the
+/// results of ticks and repeats, and lastly the result of the tail. Whether a
+/// result judges an object or a nested object depends on the mode, see
+/// [`HitResult::is_object`].
+///
+/// # Example
+///
+/// ```
+/// use mames_pp::{Beatmap, GradualPerformance, Difficulty};
+/// use mames_pp::any::HitResult;
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// let difficulty = Difficulty::new().mods(64); // DT
+/// let mut gradual = GradualPerformance::new(difficulty, &map).with_hit_results();
+///
+/// // The first 10 objects are 300s
+/// for _ in 0..10 {
+///     let attrs = gradual.next(HitResult::Great).unwrap();
+///     println!("PP: {}", attrs.pp());
+/// }
+///
+/// // Then comes a slider whose head is hit but whose last tick is missed,
+/// // resetting the combo before the tail is hit
+/// let nested = [
+///     HitResult::LargeTickHit,
+///     HitResult::LargeTickMiss,
+///     HitResult::SliderTailHit,
+/// ];
+///
+/// let attrs = gradual.next_with_nested(HitResult::Ok, nested).unwrap();
+/// println!("PP: {}", attrs.pp());
+///
+/// assert_eq!(gradual.state().max_combo, 12);
+/// assert_eq!(gradual.combo(), 1);
+/// ```
+///
+/// [`next`]: HitResultGradualPerformance::next
+/// [`next_with_nested`]: HitResultGradualPerformance::next_with_nested
+pub struct HitResultGradualPerformance {
+    gradual: GradualPerformance,
+    mode: GameMode,
+    state: ScoreState,
+    combo: u32,
+}
+
+impl HitResultGradualPerformance {
+    /// Create a [`HitResultGradualPerformance`] for a map of any mode.
+    pub fn new(gradual: GradualPerformance) -> Self {
+        Self {
+            mode: gradual.mode(),
+            gradual,
+            state: ScoreState::new(),
+            combo: 0,
+        }
+    }
+
+    /// Process the result of the next object and calculate the performance
+    /// attributes for the resulting score state.
+    ///
+    /// If the object has nested objects, use [`next_with_nested`] instead.
+    ///
+    /// [`next_with_nested`]: HitResultGradualPerformance::next_with_nested
+    pub fn next(&mut self, result: HitResult) -> Option<PerformanceAttributes> {
+        self.record(result);
+
+        self.gradual.next(self.state.clone())
+    }
+
+    /// Process the result of the next object, followed by the results of its
+    /// nested objects, and calculate the performance attributes for the
+    /// resulting score state.
+    ///
+    /// The results must be in osu!lazer's judgement order: `result` is the
+    /// object's own result, e.g. the slider head or the hold note head, and
+    /// `nested` contains the results of ticks and repeats followed by the
+    /// result of the tail.
+    pub fn next_with_nested(
+        &mut self,
+        result: HitResult,
+        nested: impl IntoIterator<Item = HitResult>,
+    ) -> Option<PerformanceAttributes> {
+        self.record(result);
+
+        for result in nested {
+            self.record(result);
+        }
+
+        self.gradual.next(self.state.clone())
+    }
+
+    /// The current score state.
+    pub const fn state(&self) -> &ScoreState {
+        &self.state
+    }
+
+    /// The current combo.
+    pub const fn combo(&self) -> u32 {
+        self.combo
+    }
+
+    /// Returns the amount of remaining objects.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.gradual.len()
+    }
+
+    fn record(&mut self, result: HitResult) {
+        let state = &mut self.state;
+
+        match (result, self.mode) {
+            (HitResult::Perfect, _) => state.n_geki += 1,
+            (HitResult::Great, _) => state.n300 += 1,
+            (HitResult::Good, _) => state.n_katu += 1,
+            (HitResult::Ok, _) => state.n100 += 1,
+            (HitResult::Meh, _) => state.n50 += 1,
+            (HitResult::Miss, _) | (HitResult::LargeTickMiss, GameMode::Catch) => {
+                state.misses += 1;
+            }
+            (HitResult::LargeTickHit, GameMode::Catch) => state.n100 += 1,
+            (HitResult::LargeTickHit, _) => state.osu_large_tick_hits += 1,
+            (HitResult::SmallTickHit, GameMode::Catch) => state.n50 += 1,
+            (HitResult::SmallTickHit, _) => state.osu_small_tick_hits += 1,
+            (HitResult::SmallTickMiss, GameMode::Catch) => state.n_katu += 1,
+            (HitResult::SliderTailHit, _) => state.slider_end_hits += 1,
+            (
+                HitResult::LargeTickMiss | HitResult::SmallTickMiss | HitResult::SliderTailMiss,
+                _,
+            ) => {}
+        }
+
+        if result.breaks_combo() {
+            self.combo = 0;
+        } else if result.increases_combo() {
+            self.combo += 1;
+            state.max_combo = state.max_combo.max(self.combo);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::hit_object::HitObjectKind;
+
+    use super::*;
+
+    fn hit_results(path: &str) -> HitResultGradualPerformance {
+        let map = Beatmap::from_path(path).unwrap();
+
+        GradualPerformance::new(Difficulty::new(), &map).with_hit_results()
+    }
+
+    #[test]
+    fn slider_head_miss_then_hit_ticks() {
+        let mut gradual = hit_results("./resources/2785319.osu");
+
+        let nested = [
+            HitResult::LargeTickHit,
+            HitResult::LargeTickHit,
+            HitResult::SliderTailHit,
+        ];
+
+        gradual.next_with_nested(HitResult::Miss, nested).unwrap();
+
+        let state = gradual.state();
+        assert_eq!(gradual.combo(), 3);
+        assert_eq!(state.max_combo, 3);
+        assert_eq!(state.misses, 1);
+        assert_eq!(state.osu_large_tick_hits, 2);
+        assert_eq!(state.slider_end_hits, 1);
+    }
+
+    #[test]
+    fn slider_tick_miss() {
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+        let mut gradual = GradualPerformance::new(Difficulty::new(), &map).with_hit_results();
+        let mut expected = GradualPerformance::new(Difficulty::new(), &map);
+
+        gradual.next(HitResult::Great).unwrap();
+        expected.next(gradual.state().clone()).unwrap();
+
+        let nested = [
+            HitResult::LargeTickHit,
+            HitResult::LargeTickMiss,
+            HitResult::LargeTickHit,
+            HitResult::SliderTailHit,
+        ];
+
+        let attrs = gradual.next_with_nested(HitResult::Great, nested).unwrap();
+
+        let state = gradual.state();
+        assert_eq!(gradual.combo(), 2);
+        assert_eq!(state.max_combo, 3);
+        assert_eq!(state.n300, 2);
+        assert_eq!(state.misses, 0);
+        assert_eq!(state.osu_large_tick_hits, 2);
+        assert_eq!(state.slider_end_hits, 1);
+
+        assert_eq!(Some(attrs), expected.next(state.clone()));
+    }
+
+    #[test]
+    fn slider_tail_miss() {
+        let mut gradual = hit_results("./resources/2785319.osu");
+
+        let nested = [HitResult::LargeTickHit, HitResult::SliderTailMiss];
+        gradual.next_with_nested(HitResult::Great, nested).unwrap();
+
+        assert_eq!(gradual.combo(), 2);
+        assert_eq!(gradual.state().slider_end_hits, 0);
+
+        // Slider ends of scores without slider accuracy
+        let nested = [HitResult::LargeTickHit, HitResult::SmallTickMiss];
+        gradual.next_with_nested(HitResult::Great, nested).unwrap();
+
+        let state = gradual.state();
+        assert_eq!(gradual.combo(), 4);
+        assert_eq!(state.max_combo, 4);
+        assert_eq!(state.osu_small_tick_hits, 0);
+        assert_eq!(state.misses, 0);
+    }
+
+    #[test]
+    fn mania_hold_note() {
+        let map = Beatmap::from_path("./resources/1638954.osu").unwrap();
+        let mut gradual = GradualPerformance::new(Difficulty::new(), &map).with_hit_results();
+
+        let mut hold_notes = map
+            .hit_objects
+            .iter()
+            .enumerate()
+            .filter(|(_, h)| matches!(h.kind, HitObjectKind::Hold(_)))
+            .map(|(i, _)| i as u32);
+
+        let first = hold_notes.next().unwrap();
+        let second = hold_notes.next().unwrap();
+        assert!(first + 1 < second);
+
+        for _ in 0..first {
+            gradual.next(HitResult::Perfect).unwrap();
+        }
+
+        // Hit head, missed tail
+        gradual
+            .next_with_nested(HitResult::Perfect, [HitResult::Miss])
+            .unwrap();
+
+        assert_eq!(gradual.combo(), 0);
+        assert_eq!(gradual.state().max_combo, first + 1);
+
+        let n_notes = second - first - 1;
+
+        for _ in 0..n_notes {
+            gradual.next(HitResult::Perfect).unwrap();
+        }
+
+        // Late head, perfect tail
+        gradual
+            .next_with_nested(HitResult::Good, [HitResult::Perfect])
+            .unwrap();
+
+        let state = gradual.state();
+        assert_eq!(gradual.combo(), n_notes + 2);
+        assert_eq!(state.max_combo, (first + 1).max(n_notes + 2));
+        assert_eq!(state.n_geki, first + n_notes + 2);
+        assert_eq!(state.n_katu, 1);
+        assert_eq!(state.misses, 1);
+    }
 }
@@ -1,13 +1,18 @@
 use rosu_map::section::general::GameMode;
 
 use crate::{
-    catch::CatchPerformance, mania::ManiaPerformance, osu::OsuPerformance, taiko::TaikoPerformance,
+    catch::CatchPerformance,
+    mania::ManiaPerformance,
+    osu::{OsuParameters, OsuPerformance},
+    taiko::TaikoPerformance,
     Difficulty, GameMods,
 };
 
 use self::into::IntoPerformance;
 
-use super::{attributes::PerformanceAttributes, score_state::ScoreState};
+use super::{
+    attributes::PerformanceAttributes, score_state::ScoreState, AlgorithmVersion, PerformanceTrace,
+};
 
 pub mod gradual;
 pub mod into;
@@ -67,6 +72,34 @@ impl<'map> Performance<'map> {
         }
     }
 
+    /// Same as [`Performance::calculate`] but also records each value and
+    /// factor of the pp formula in a [`PerformanceTrace`].
+    #[allow(clippy::missing_panics_doc)]
+    pub fn calculate_with_trace(self) -> (PerformanceAttributes, PerformanceTrace) {
+        match self {
+            Self::Osu(o) => {
+                let (attrs, trace) = o.calculate_with_trace().expect("no conversion required");
+
+                (PerformanceAttributes::Osu(attrs), trace)
+            }
+            Self::Taiko(t) => {
+                let (attrs, trace) = t.calculate_with_trace().expect("no conversion required");
+
+                (PerformanceAttributes::Taiko(attrs), trace)
+            }
+            Self::Catch(f) => {
+                let (attrs, trace) = f.calculate_with_trace().expect("no conversion required");
+
+                (PerformanceAttributes::Catch(attrs), trace)
+            }
+            Self::Mania(m) => {
+                let (attrs, trace) = m.calculate_with_trace().expect("no conversion required");
+
+                (PerformanceAttributes::Mania(attrs), trace)
+            }
+        }
+    }
+
     /// Attempt to convert the map to the specified mode.
     ///
     /// Returns `Err(self)` if the conversion is incompatible or no beatmap is
@@ -324,6 +357,44 @@ impl<'map> Performance<'map> {
         }
     }
 
+    /// Specify the total score of an osu!stable score, i.e. its score V1.
+    ///
+    /// Used to estimate the misses of osu!stable scores more accurately.
+    ///
+    /// Only relevant for osu!standard.
+    pub fn legacy_total_score(self, legacy_total_score: u64) -> Self {
+        match self {
+            Self::Osu(o) => Self::Osu(o.legacy_total_score(legacy_total_score)),
+            Self::Taiko(_) | Self::Catch(_) | Self::Mania(_) => self,
+        }
+    }
+
+    /// Specify the [`AlgorithmVersion`] of the calculation.
+    ///
+    /// Defaults to [`AlgorithmVersion::Mamestagram`].
+    ///
+    /// Only relevant for osu!standard and osu!taiko.
+    pub fn algorithm(self, algorithm: AlgorithmVersion) -> Self {
+        match self {
+            Self::Osu(o) => Self::Osu(o.algorithm(algorithm)),
+            Self::Taiko(t) => Self::Taiko(t.algorithm(algorithm)),
+            Self::Catch(_) | Self::Mania(_) => self,
+        }
+    }
+
+    /// Override the constants of the osu!standard calculation.
+    ///
+    /// Defaults to [`OsuParameters::DEFAULT`].
+    ///
+    /// Only relevant for osu!standard.
+    pub fn osu_parameters(self, params: OsuParameters) -> Self {
+        if let Self::Osu(osu) = self {
+            Self::Osu(osu.osu_parameters(params))
+        } else {
+            self
+        }
+    }
+
     /// Specify the amount of "large tick" hits.
     ///
     /// Only relevant for osu!standard.
@@ -365,6 +436,28 @@ impl<'map> Performance<'map> {
         }
     }
 
+    /// Specify the amount of hit drum roll ticks.
+    ///
+    /// Only relevant for osu!taiko lazer scores.
+    pub fn drum_roll_tick_hits(self, drum_roll_tick_hits: u32) -> Self {
+        if let Self::Taiko(taiko) = self {
+            Self::Taiko(taiko.drum_roll_tick_hits(drum_roll_tick_hits))
+        } else {
+            self
+        }
+    }
+
+    /// Specify the amount of completed swells.
+    ///
+    /// Only relevant for osu!taiko lazer scores.
+    pub fn swells_completed(self, swells_completed: u32) -> Self {
+        if let Self::Taiko(taiko) = self {
+            Self::Taiko(taiko.swells_completed(swells_completed))
+        } else {
+            self
+        }
+    }
+
     /// Specify the amount of 300s of a play.
     pub fn n300(self, n300: u32) -> Self {
         match self {
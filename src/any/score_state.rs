@@ -6,6 +6,7 @@ use crate::{
 
 /// Aggregation for a score's current state.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ScoreState {
     /// Maximum combo that the score has had so far. **Not** the maximum
     /// possible combo of the map so far.
@@ -37,6 +38,14 @@ pub struct ScoreState {
     ///
     /// Only relevant for osu!standard in lazer.
     pub slider_end_hits: u32,
+    /// Amount of hit drum roll ticks for osu!taiko.
+    ///
+    /// Only relevant for osu!lazer.
+    pub taiko_drum_roll_tick_hits: u32,
+    /// Amount of completed swells for osu!taiko.
+    ///
+    /// Only relevant for osu!lazer.
+    pub taiko_swells_completed: u32,
     /// Amount of current gekis (n320 for osu!mania).
     pub n_geki: u32,
     /// Amount of current katus (tiny droplet misses for osu!catch / n200 for
@@ -60,6 +69,8 @@ impl ScoreState {
             osu_large_tick_hits: 0,
             osu_small_tick_hits: 0,
             slider_end_hits: 0,
+            taiko_drum_roll_tick_hits: 0,
+            taiko_swells_completed: 0,
             n_geki: 0,
             n_katu: 0,
             n300: 0,
@@ -109,6 +120,8 @@ impl From<ScoreState> for TaikoScoreState {
             n300: state.n300,
             n100: state.n100,
             misses: state.misses,
+            drum_roll_tick_hits: state.taiko_drum_roll_tick_hits,
+            swells_completed: state.taiko_swells_completed,
         }
     }
 }
@@ -146,6 +159,8 @@ impl From<OsuScoreState> for ScoreState {
             osu_large_tick_hits: state.large_tick_hits,
             osu_small_tick_hits: state.small_tick_hits,
             slider_end_hits: state.slider_end_hits,
+            taiko_drum_roll_tick_hits: 0,
+            taiko_swells_completed: 0,
             n_geki: 0,
             n_katu: 0,
             n300: state.n300,
@@ -163,6 +178,8 @@ impl From<TaikoScoreState> for ScoreState {
             osu_large_tick_hits: 0,
             osu_small_tick_hits: 0,
             slider_end_hits: 0,
+            taiko_drum_roll_tick_hits: state.drum_roll_tick_hits,
+            taiko_swells_completed: state.swells_completed,
             n_geki: 0,
             n_katu: 0,
             n300: state.n300,
@@ -180,6 +197,8 @@ impl From<CatchScoreState> for ScoreState {
             osu_large_tick_hits: 0,
             osu_small_tick_hits: 0,
             slider_end_hits: 0,
+            taiko_drum_roll_tick_hits: 0,
+            taiko_swells_completed: 0,
             n_geki: 0,
             n_katu: state.tiny_droplet_misses,
             n300: state.fruits,
@@ -197,6 +216,8 @@ impl From<ManiaScoreState> for ScoreState {
             osu_large_tick_hits: 0,
             osu_small_tick_hits: 0,
             slider_end_hits: 0,
+            taiko_drum_roll_tick_hits: 0,
+            taiko_swells_completed: 0,
             n_geki: state.n320,
             n_katu: state.n200,
             n300: state.n300,
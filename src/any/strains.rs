@@ -4,6 +4,11 @@ use crate::{catch::CatchStrains, mania::ManiaStrains, osu::OsuStrains, taiko::Ta
 ///
 /// Suitable to plot the difficulty of a map over time.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum Strains {
     Osu(OsuStrains),
     Taiko(TaikoStrains),
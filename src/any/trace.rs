@@ -0,0 +1,135 @@
+use std::cell::RefCell;
+
+/// Record of the values and factors that make up a performance calculation.
+///
+/// Obtained through the `calculate_with_trace` method of the performance
+/// calculators.
+///
+/// # Example
+///
+/// ```
+/// use mames_pp::{osu::OsuPerformance, Beatmap};
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+///
+/// let (attrs, trace) = OsuPerformance::new(&map)
+///     .misses(5)
+///     .calculate_with_trace()
+///     .unwrap();
+///
+/// if let Some(penalty) = trace.factor("aim", "miss penalty") {
+///     println!("lost {:.0}% of the aim pp to misses", (1.0 - penalty) * 100.0);
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PerformanceTrace {
+    steps: Vec<TraceStep>,
+}
+
+/// A single entry of a [`PerformanceTrace`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TraceStep {
+    /// The pp component, e.g. `"aim"`, or `"total"` for the final pp.
+    pub component: &'static str,
+    /// What the value represents, e.g. `"miss penalty"`.
+    pub name: &'static str,
+    /// Whether the value is computed on its own or multiplied onto the
+    /// component.
+    pub kind: TraceStepKind,
+    /// The value itself; a multiplier for [`TraceStepKind::Factor`].
+    pub value: f64,
+}
+
+/// The kind of a [`TraceStep`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TraceStepKind {
+    /// A value the component starts from or ends up with.
+    Value,
+    /// A multiplier applied to the component.
+    Factor,
+}
+
+impl PerformanceTrace {
+    /// All steps in the order they were applied.
+    pub fn steps(&self) -> &[TraceStep] {
+        &self.steps
+    }
+
+    /// The steps of the given component.
+    pub fn component<'a>(&'a self, component: &'a str) -> impl Iterator<Item = &'a TraceStep> {
+        self.steps
+            .iter()
+            .filter(move |step| step.component == component)
+    }
+
+    /// The product of all factors of the given name that were applied to the
+    /// component, or `None` if there were none.
+    pub fn factor(&self, component: &str, name: &str) -> Option<f64> {
+        self.component(component)
+            .filter(|step| step.kind == TraceStepKind::Factor && step.name == name)
+            .map(|step| step.value)
+            .reduce(|a, b| a * b)
+    }
+}
+
+/// Handle through which calculators record a [`PerformanceTrace`].
+///
+/// Disabled handles ignore everything so calculators can record
+/// unconditionally.
+#[derive(Copy, Clone)]
+pub(crate) struct Trace<'a>(Option<&'a RefCell<Vec<TraceStep>>>);
+
+impl<'a> Trace<'a> {
+    pub const fn disabled() -> Self {
+        Self(None)
+    }
+
+    pub const fn new(steps: &'a RefCell<Vec<TraceStep>>) -> Self {
+        Self(Some(steps))
+    }
+
+    /// Record a value of a component and return it.
+    pub fn value(self, component: &'static str, name: &'static str, value: f64) -> f64 {
+        self.push(component, name, TraceStepKind::Value, value)
+    }
+
+    /// Record a factor applied to a component and return it.
+    pub fn factor(self, component: &'static str, name: &'static str, value: f64) -> f64 {
+        self.push(component, name, TraceStepKind::Factor, value)
+    }
+
+    fn push(
+        self,
+        component: &'static str,
+        name: &'static str,
+        kind: TraceStepKind,
+        value: f64,
+    ) -> f64 {
+        if let Some(steps) = self.0 {
+            steps.borrow_mut().push(TraceStep {
+                component,
+                name,
+                kind,
+                value,
+            });
+        }
+
+        value
+    }
+}
+
+/// Run `f` with an enabled [`Trace`] and collect its steps.
+pub(crate) fn traced<T>(f: impl FnOnce(Trace<'_>) -> T) -> (T, PerformanceTrace) {
+    let steps = RefCell::new(Vec::new());
+    let value = f(Trace::new(&steps));
+
+    (
+        value,
+        PerformanceTrace {
+            steps: steps.into_inner(),
+        },
+    )
+}
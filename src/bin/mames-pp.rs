@@ -0,0 +1,488 @@
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use clap::Parser;
+use mames_pp::{
+    any::{
+        AlgorithmVersion, DifficultyAttributes, HitResult, PerformanceAttributes, PerformanceTrace,
+        Strains, TraceStepKind,
+    },
+    model::{mode::GameMode, mods::reexports::GameModsIntermode},
+    osu::OsuParameters,
+    Beatmap, Difficulty, GameMods, GradualPerformance, Performance,
+};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Calculate the difficulty and performance of an osu! beatmap.
+#[derive(Parser)]
+#[command(name = "mames-pp", version)]
+struct Args {
+    /// Path to the .osu file.
+    path: PathBuf,
+    /// Convert the map to the given mode (osu, taiko, catch, mania, or 0-3).
+    #[arg(short, long, value_parser = parse_mode)]
+    mode: Option<GameMode>,
+    /// Mods as acronyms ("HDDT"), legacy bits ("72"), or lazer JSON.
+    #[arg(short = 'M', long, value_parser = parse_mods)]
+    mods: Option<GameMods>,
+    /// Custom clock rate.
+    #[arg(short, long)]
+    clock_rate: Option<f64>,
+    /// Override the approach rate.
+    #[arg(long)]
+    ar: Option<f32>,
+    /// Override the circle size.
+    #[arg(long)]
+    cs: Option<f32>,
+    /// Override the overall difficulty.
+    #[arg(long)]
+    od: Option<f32>,
+    /// Override the drain rate.
+    #[arg(long)]
+    hp: Option<f32>,
+    /// Whether the AR, CS, OD, and HP overrides already consider mods.
+    #[arg(long)]
+    with_mods: bool,
+    /// Amount of passed objects for partial plays.
+    #[arg(long)]
+    passed_objects: Option<u32>,
+    /// Accuracy in percent.
+    #[arg(short, long)]
+    acc: Option<f64>,
+    /// Maximum combo of the score.
+    #[arg(long)]
+    combo: Option<u32>,
+    #[arg(long)]
+    n300: Option<u32>,
+    #[arg(long)]
+    n100: Option<u32>,
+    #[arg(long)]
+    n50: Option<u32>,
+    #[arg(long)]
+    misses: Option<u32>,
+    #[arg(long)]
+    n_geki: Option<u32>,
+    #[arg(long)]
+    n_katu: Option<u32>,
+    /// Hit slider ticks and repeats (osu!standard lazer scores).
+    #[arg(long)]
+    large_tick_hits: Option<u32>,
+    /// Hit slider ends of lazer scores without slider accuracy.
+    #[arg(long)]
+    small_tick_hits: Option<u32>,
+    /// Hit slider ends of lazer scores with slider accuracy.
+    #[arg(long)]
+    slider_end_hits: Option<u32>,
+    /// Hit drum roll ticks (osu!taiko lazer scores).
+    #[arg(long)]
+    drum_roll_tick_hits: Option<u32>,
+    /// Completed swells (osu!taiko lazer scores).
+    #[arg(long)]
+    swells_completed: Option<u32>,
+    /// Calculate for an osu!stable score instead of an osu!lazer score.
+    #[arg(long)]
+    stable: bool,
+    /// Algorithm version (lazer2026 or mamestagram).
+    #[arg(long, value_parser = parse_algorithm)]
+    algorithm: Option<AlgorithmVersion>,
+    /// JSON file overriding osu!standard calculation constants.
+    #[arg(long, value_parser = parse_osu_parameters)]
+    osu_parameters: Option<OsuParameters>,
+    /// Print JSON instead of a table.
+    #[arg(long)]
+    json: bool,
+    /// Include the strains.
+    #[arg(long)]
+    strains: bool,
+    /// Include the stars and pp after each object of the score given by
+    /// `--hit-results`.
+    #[arg(long, requires = "hit_results")]
+    gradual: bool,
+    /// File with the judgements of the score, one whitespace-separated entry
+    /// per object. Judgements of nested objects follow the object's own
+    /// judgement in osu!lazer's order, joined by `+`, e.g.
+    /// `300 100+tick+tick_miss+tail x`.
+    #[arg(long, value_parser = parse_hit_results)]
+    hit_results: Option<HitResults>,
+    /// Include each value and factor of the pp formula.
+    #[arg(long)]
+    trace: bool,
+}
+
+#[derive(Serialize)]
+struct Output {
+    performance: PerformanceAttributes,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    strains: Option<Strains>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gradual: Option<Vec<GradualEntry>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trace: Option<PerformanceTrace>,
+}
+
+/// The object judgements of a score, each with the judgements of its nested
+/// objects.
+#[derive(Clone)]
+struct HitResults(Vec<(HitResult, Vec<HitResult>)>);
+
+#[derive(Serialize)]
+struct GradualEntry {
+    objects: usize,
+    stars: f64,
+    pp: f64,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let mut map = Beatmap::from_path(&args.path)
+        .map_err(|err| format!("failed to decode {}: {err}", args.path.display()))?;
+
+    let mods = args.mods.clone().unwrap_or_default();
+
+    if let Some(mode) = args.mode {
+        map.convert_mut(mode, &mods)
+            .map_err(|err| err.to_string())?;
+    }
+
+    let difficulty = build_difficulty(args, mods);
+    let attrs = difficulty.calculate(&map);
+    let performance = build_performance(args, attrs.clone(), difficulty.clone());
+
+    let (performance, trace) = if args.trace {
+        let (performance, trace) = performance.calculate_with_trace();
+
+        (performance, Some(trace))
+    } else {
+        (performance.calculate(), None)
+    };
+
+    let strains = args.strains.then(|| difficulty.strains(&map));
+
+    let gradual = match args.hit_results {
+        Some(ref hit_results) if args.gradual => {
+            Some(gradual_entries(&difficulty, &map, hit_results))
+        }
+        _ => None,
+    };
+
+    let output = Output {
+        performance,
+        strains,
+        gradual,
+        trace,
+    };
+
+    if args.json {
+        let json = serde_json::to_string_pretty(&output).map_err(|err| err.to_string())?;
+        println!("{json}");
+    } else {
+        print_tables(&output)?;
+    }
+
+    Ok(())
+}
+
+fn gradual_entries(
+    difficulty: &Difficulty,
+    map: &Beatmap,
+    hit_results: &HitResults,
+) -> Vec<GradualEntry> {
+    let mut gradual = GradualPerformance::new(difficulty.clone(), map).with_hit_results();
+
+    hit_results
+        .0
+        .iter()
+        .enumerate()
+        .map_while(|(i, (result, nested))| {
+            let attrs = gradual.next_with_nested(*result, nested.iter().copied())?;
+
+            Some(GradualEntry {
+                objects: i + 1,
+                stars: attrs.stars(),
+                pp: attrs.pp(),
+            })
+        })
+        .collect()
+}
+
+fn build_difficulty(args: &Args, mods: GameMods) -> Difficulty {
+    let mut difficulty = Difficulty::new().mods(mods).lazer(!args.stable);
+
+    if let Some(clock_rate) = args.clock_rate {
+        difficulty = difficulty.clock_rate(clock_rate);
+    }
+
+    if let Some(ar) = args.ar {
+        difficulty = difficulty.ar(ar, args.with_mods);
+    }
+
+    if let Some(cs) = args.cs {
+        difficulty = difficulty.cs(cs, args.with_mods);
+    }
+
+    if let Some(od) = args.od {
+        difficulty = difficulty.od(od, args.with_mods);
+    }
+
+    if let Some(hp) = args.hp {
+        difficulty = difficulty.hp(hp, args.with_mods);
+    }
+
+    if let Some(passed_objects) = args.passed_objects {
+        difficulty = difficulty.passed_objects(passed_objects);
+    }
+
+    if let Some(algorithm) = args.algorithm {
+        difficulty = difficulty.algorithm(algorithm);
+    }
+
+    if let Some(ref params) = args.osu_parameters {
+        difficulty = difficulty.osu_parameters(params.clone());
+    }
+
+    difficulty
+}
+
+fn build_performance<'a>(
+    args: &Args,
+    attrs: DifficultyAttributes,
+    difficulty: Difficulty,
+) -> Performance<'a> {
+    let mut performance = Performance::new(attrs)
+        .difficulty(difficulty)
+        .lazer(!args.stable);
+
+    macro_rules! set {
+        ( $( $field:ident ),* ) => {
+            $(
+                if let Some(value) = args.$field {
+                    performance = performance.$field(value);
+                }
+            )*
+        };
+    }
+
+    set!(
+        combo,
+        n300,
+        n100,
+        n50,
+        misses,
+        n_geki,
+        n_katu,
+        large_tick_hits,
+        small_tick_hits,
+        slider_end_hits,
+        drum_roll_tick_hits,
+        swells_completed
+    );
+
+    if let Some(acc) = args.acc {
+        performance = performance.accuracy(acc);
+    }
+
+    performance
+}
+
+fn parse_mode(s: &str) -> Result<GameMode, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "0" | "osu" | "std" | "standard" => Ok(GameMode::Osu),
+        "1" | "taiko" => Ok(GameMode::Taiko),
+        "2" | "catch" | "ctb" | "fruits" => Ok(GameMode::Catch),
+        "3" | "mania" => Ok(GameMode::Mania),
+        _ => Err(format!("unknown mode `{s}`")),
+    }
+}
+
+fn parse_algorithm(s: &str) -> Result<AlgorithmVersion, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "lazer2026" | "2026" => Ok(AlgorithmVersion::Lazer2026),
+        "mamestagram" | "mames" => Ok(AlgorithmVersion::Mamestagram),
+        _ => Err(format!("unknown algorithm `{s}`")),
+    }
+}
+
+fn parse_osu_parameters(path: &str) -> Result<OsuParameters, String> {
+    let content =
+        fs::read_to_string(path).map_err(|err| format!("failed to read {path}: {err}"))?;
+
+    serde_json::from_str(&content).map_err(|err| format!("invalid parameters JSON: {err}"))
+}
+
+fn parse_hit_results(path: &str) -> Result<HitResults, String> {
+    let content =
+        fs::read_to_string(path).map_err(|err| format!("failed to read {path}: {err}"))?;
+
+    content
+        .split_whitespace()
+        .map(|entry| {
+            let mut results = entry.split('+').map(parse_hit_result);
+            let result = results.next().expect("split yields at least one item")?;
+            let nested = results.collect::<Result<_, _>>()?;
+
+            Ok((result, nested))
+        })
+        .collect::<Result<_, _>>()
+        .map(HitResults)
+}
+
+fn parse_hit_result(s: &str) -> Result<HitResult, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "320" | "perfect" | "geki" => Ok(HitResult::Perfect),
+        "300" | "great" => Ok(HitResult::Great),
+        "200" | "good" | "katu" => Ok(HitResult::Good),
+        "100" | "ok" => Ok(HitResult::Ok),
+        "50" | "meh" => Ok(HitResult::Meh),
+        "x" | "miss" => Ok(HitResult::Miss),
+        "tick" => Ok(HitResult::LargeTickHit),
+        "tick_miss" => Ok(HitResult::LargeTickMiss),
+        "small_tick" => Ok(HitResult::SmallTickHit),
+        "small_tick_miss" => Ok(HitResult::SmallTickMiss),
+        "tail" => Ok(HitResult::SliderTailHit),
+        "tail_miss" => Ok(HitResult::SliderTailMiss),
+        _ => Err(format!("unknown hit result `{s}`")),
+    }
+}
+
+fn parse_mods(s: &str) -> Result<GameMods, String> {
+    if let Ok(bits) = s.parse::<u32>() {
+        return Ok(bits.into());
+    }
+
+    if s.trim_start().starts_with('[') {
+        return serde_json::from_str(s).map_err(|err| format!("invalid mods JSON: {err}"));
+    }
+
+    Ok(GameModsIntermode::from_acronyms(s).into())
+}
+
+fn print_tables(output: &Output) -> Result<(), String> {
+    let Value::Object(performance) = to_value(&output.performance)? else {
+        unreachable!()
+    };
+
+    // Enums are tagged with their mode; only the attributes are of interest
+    let Some((mode, Value::Object(mut performance))) = performance.into_iter().next() else {
+        unreachable!()
+    };
+
+    println!("mode: {mode}");
+
+    if let Some(Value::Object(difficulty)) = performance.remove("difficulty") {
+        print_section("Difficulty", &difficulty);
+    }
+
+    print_section("Performance", &performance);
+
+    if let Some(ref strains) = output.strains {
+        print_strains(strains)?;
+    }
+
+    if let Some(ref gradual) = output.gradual {
+        println!("\nGradual");
+        println!("{:>8}  {:>10}  {:>10}", "objects", "stars", "pp");
+
+        for entry in gradual {
+            println!(
+                "{:>8}  {:>10.4}  {:>10.4}",
+                entry.objects, entry.stars, entry.pp
+            );
+        }
+    }
+
+    if let Some(ref trace) = output.trace {
+        print_trace(trace);
+    }
+
+    Ok(())
+}
+
+fn print_section(title: &str, values: &Map<String, Value>) {
+    let width = values.keys().map(String::len).max().unwrap_or(0);
+
+    println!("\n{title}");
+
+    for (key, value) in values {
+        println!("  {key:<width$}  {value}");
+    }
+}
+
+fn print_trace(trace: &PerformanceTrace) {
+    println!("\nTrace");
+
+    for step in trace.steps() {
+        let op = match step.kind {
+            TraceStepKind::Value => '=',
+            TraceStepKind::Factor => '*',
+        };
+
+        println!(
+            "  {:<16}  {:<28}  {op} {:.6}",
+            step.component, step.name, step.value
+        );
+    }
+}
+
+fn print_strains(strains: &Strains) -> Result<(), String> {
+    let Value::Object(value) = to_value(strains)? else {
+        unreachable!()
+    };
+
+    let Some((_, Value::Object(skills))) = value.into_iter().next() else {
+        unreachable!()
+    };
+
+    let skills: Vec<_> = skills
+        .into_iter()
+        .filter_map(|(name, values)| match values {
+            Value::Array(values) => Some((name, values)),
+            _ => None,
+        })
+        .collect();
+
+    println!("\nStrains");
+    print!("{:>10}", "time");
+
+    for (name, _) in skills.iter() {
+        print!("  {name:>14}");
+    }
+
+    println!();
+
+    let len = skills
+        .iter()
+        .map(|(_, values)| values.len())
+        .max()
+        .unwrap_or(0);
+
+    for i in 0..len {
+        print!("{:>10}", i as f64 * strains.section_len());
+
+        for (_, values) in skills.iter() {
+            match values.get(i).and_then(Value::as_f64) {
+                Some(strain) => print!("  {strain:>14.4}"),
+                None => print!("  {:>14}", ""),
+            }
+        }
+
+        println!();
+    }
+
+    Ok(())
+}
+
+fn to_value<T: Serialize>(value: &T) -> Result<Value, String> {
+    serde_json::to_value(value).map_err(|err| err.to_string())
+}
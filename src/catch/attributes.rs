@@ -1,9 +1,10 @@
 use std::mem;
 
-use crate::catch::performance::CatchPerformance;
+use crate::{any::difficulty::section::SectionWindow, catch::performance::CatchPerformance};
 
 /// The result of a difficulty calculation on an osu!catch map.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CatchDifficultyAttributes {
     /// The final star rating
     pub stars: f64,
@@ -58,6 +59,7 @@ impl CatchDifficultyAttributes {
 
 /// The result of a performance calculation on an osu!catch map.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CatchPerformanceAttributes {
     /// The difficulty attributes that were used for the performance calculation
     pub difficulty: CatchDifficultyAttributes,
@@ -117,6 +119,7 @@ pub enum ObjectCountBuilder {
     Regular {
         count: ObjectCount,
         take: usize,
+        section: SectionWindow,
     },
     Gradual {
         count: GradualObjectCount,
@@ -125,10 +128,16 @@ pub enum ObjectCountBuilder {
 }
 
 impl ObjectCountBuilder {
-    pub fn new_regular(take: usize) -> Self {
+    /// Count the first `take` objects but only those that start within the
+    /// section.
+    ///
+    /// Nested objects of juice streams are checked individually so a section
+    /// may contain only part of a juice stream.
+    pub fn new_regular(take: usize, section: SectionWindow) -> Self {
         Self::Regular {
             count: ObjectCount::default(),
             take,
+            section,
         }
     }
 
@@ -155,12 +164,19 @@ impl ObjectCountBuilder {
         }
     }
 
-    pub fn record_fruit(&mut self) {
+    pub fn record_fruit(&mut self, start_time: f64) {
         match self {
-            Self::Regular { count, take } => {
+            Self::Regular {
+                count,
+                take,
+                section,
+            } => {
                 if *take > 0 {
                     *take -= 1;
-                    count.fruits += 1;
+
+                    if section.contains(start_time) {
+                        count.fruits += 1;
+                    }
                 }
             }
             Self::Gradual { count, all } => {
@@ -170,26 +186,37 @@ impl ObjectCountBuilder {
         }
     }
 
-    pub fn record_droplet(&mut self) {
+    pub fn record_droplet(&mut self, start_time: f64) {
         match self {
-            Self::Regular { count, take } => {
+            Self::Regular {
+                count,
+                take,
+                section,
+            } => {
                 if *take > 0 {
                     *take -= 1;
-                    count.droplets += 1;
+
+                    if section.contains(start_time) {
+                        count.droplets += 1;
+                    }
                 }
             }
             Self::Gradual { count, all } => all.push(mem::take(count)),
         }
     }
 
-    pub fn record_tiny_droplets(&mut self, n: u32) {
+    pub fn record_tiny_droplet(&mut self, start_time: f64) {
         match self {
-            Self::Regular { count, take } => {
-                if *take > 0 {
-                    count.tiny_droplets += n;
+            Self::Regular {
+                count,
+                take,
+                section,
+            } => {
+                if *take > 0 && section.contains(start_time) {
+                    count.tiny_droplets += 1;
                 }
             }
-            Self::Gradual { count, .. } => count.tiny_droplets += n,
+            Self::Gradual { count, .. } => count.tiny_droplets += 1,
         }
     }
 }
@@ -1,6 +1,7 @@
 use rosu_map::section::{general::GameMode, hit_objects::CurveBuffers};
 
 use crate::{
+    any::difficulty::section::SectionWindow,
     model::{
         beatmap::Beatmap,
         hit_object::{HitObject, HitObjectKind, HoldNote, Spinner},
@@ -77,6 +78,16 @@ pub fn convert_objects(
     palpable_objects
 }
 
+/// Start times of all fruits and droplets.
+pub fn palpable_object_times(map: &Beatmap) -> Vec<f64> {
+    let mut count = ObjectCountBuilder::new_regular(usize::MAX, SectionWindow::default());
+
+    convert_objects(map, &mut count, Reflection::None, false, map.cs)
+        .iter()
+        .map(|h| h.start_time)
+        .collect()
+}
+
 fn convert_object<'a>(
     h: &'a HitObject,
     map: &Beatmap,
@@ -84,7 +95,7 @@ fn convert_object<'a>(
     bufs: &'a mut JuiceStreamBufs,
 ) -> ObjectIter<'a> {
     let state = match h.kind {
-        HitObjectKind::Circle => ObjectIterState::Fruit(Some(Fruit::new(count))),
+        HitObjectKind::Circle => ObjectIterState::Fruit(Some(Fruit::new(count, h.start_time))),
         HitObjectKind::Slider(ref slider) => {
             let effective_x = h.pos.x.clamp(0.0, PLAYFIELD_WIDTH);
             let stream = JuiceStream::new(effective_x, h.start_time, slider, map, count, bufs);
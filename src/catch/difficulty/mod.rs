@@ -74,7 +74,8 @@ impl DifficultyValues {
 
         let hr_offsets = difficulty.get_hardrock_offsets();
         let reflection = difficulty.get_mods().reflection();
-        let mut count = ObjectCountBuilder::new_regular(take);
+        let mut section = difficulty.get_section_window(&map.hit_objects);
+        let mut count = ObjectCountBuilder::new_regular(take, section);
 
         let palpable_objects =
             convert_objects(map, &mut count, reflection, hr_offsets, map_attrs.cs as f32);
@@ -91,6 +92,14 @@ impl DifficultyValues {
         let mut movement = Movement::new(half_catcher_width, clock_rate);
 
         for curr in diff_objects.iter() {
+            if section.is_past(curr.start_time) {
+                break;
+            }
+
+            if section.enters(curr.start_time) {
+                movement.discard_history();
+            }
+
             movement.process(curr, &diff_objects);
         }
 
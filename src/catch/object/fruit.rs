@@ -5,8 +5,8 @@ pub struct Fruit {
 }
 
 impl Fruit {
-    pub fn new(count: &mut ObjectCountBuilder) -> Self {
-        count.record_fruit();
+    pub fn new(count: &mut ObjectCountBuilder, start_time: f64) -> Self {
+        count.record_fruit(start_time);
 
         Self { x_offset: 0.0 }
     }
@@ -76,7 +76,6 @@ impl<'a> JuiceStream<'a> {
 
         for e in events {
             if let Some(last_event_time) = last_event_time {
-                let mut tiny_droplets = 0;
                 let since_last_tick = f64::from(e.time as i32 - last_event_time as i32);
 
                 if since_last_tick > 80.0 {
@@ -89,7 +88,7 @@ impl<'a> JuiceStream<'a> {
                     let mut t = time_between_tiny;
 
                     while t < since_last_tick {
-                        tiny_droplets += 1;
+                        count.record_tiny_droplet(last_event_time + t);
 
                         let nested = NestedJuiceStreamObject {
                             pos: 0.0,        // not important
@@ -102,20 +101,18 @@ impl<'a> JuiceStream<'a> {
                         t += time_between_tiny;
                     }
                 }
-
-                count.record_tiny_droplets(tiny_droplets);
             }
 
             last_event_time = Some(e.time);
 
             let kind = match e.kind {
                 SliderEventType::Tick => {
-                    count.record_droplet();
+                    count.record_droplet(e.time);
 
                     NestedJuiceStreamObjectKind::Droplet
                 }
                 SliderEventType::Head | SliderEventType::Repeat | SliderEventType::Tail => {
-                    count.record_fruit();
+                    count.record_fruit(e.time);
 
                     NestedJuiceStreamObjectKind::Fruit
                 }
@@ -1,4 +1,5 @@
 use crate::{
+    any::trace::Trace,
     catch::{CatchDifficultyAttributes, CatchPerformanceAttributes, CatchScoreState},
     GameMods,
 };
@@ -7,6 +8,7 @@ pub(super) struct CatchPerformanceCalculator<'mods> {
     attrs: CatchDifficultyAttributes,
     mods: &'mods GameMods,
     state: CatchScoreState,
+    trace: Trace<'mods>,
 }
 
 impl<'a> CatchPerformanceCalculator<'a> {
@@ -14,8 +16,14 @@ impl<'a> CatchPerformanceCalculator<'a> {
         attrs: CatchDifficultyAttributes,
         mods: &'a GameMods,
         state: CatchScoreState,
+        trace: Trace<'a>,
     ) -> Self {
-        Self { attrs, mods, state }
+        Self {
+            attrs,
+            mods,
+            state,
+            trace,
+        }
     }
 }
 
@@ -26,7 +34,11 @@ impl CatchPerformanceCalculator<'_> {
         let max_combo = attributes.max_combo();
 
         // * We are heavily relying on aim in catch the beat
-        let mut pp = (5.0 * (stars / 0.0049).max(1.0) - 4.0).powf(2.0) / 100_000.0;
+        let mut pp = self.trace.value(
+            "total",
+            "difficulty to performance",
+            (5.0 * (stars / 0.0049).max(1.0) - 4.0).powf(2.0) / 100_000.0,
+        );
 
         // * Longer maps are worth more. "Longer" means how many hits there are which can contribute to combo
         // upstream `totalComboHits()` = numMiss + num100 + num300 = misses + droplets + fruits
@@ -39,16 +51,24 @@ impl CatchPerformanceCalculator<'_> {
             len_bonus += (f64::from(combo_hits) / 2500.0).log10() * 0.475;
         }
 
-        pp *= len_bonus;
+        pp *= self.trace.factor("total", "length bonus", len_bonus);
 
         // Penalize misses exponentially
-        pp *= 0.97_f64.powf(f64::from(self.state.misses));
+        pp *= self.trace.factor(
+            "total",
+            "miss penalty",
+            0.97_f64.powf(f64::from(self.state.misses)),
+        );
 
         // Combo scaling — upstream の指数は 0.35 (旧 0.8 は誤り)
         // guard は upstream: catchAttributes.MaxCombo > 0
         if max_combo > 0 {
-            pp *= (f64::from(self.state.max_combo).powf(0.35) / f64::from(max_combo).powf(0.35))
-                .min(1.0);
+            pp *= self.trace.factor(
+                "total",
+                "combo scaling",
+                (f64::from(self.state.max_combo).powf(0.35) / f64::from(max_combo).powf(0.35))
+                    .min(1.0),
+            );
         }
 
         // AR scaling
@@ -59,30 +79,44 @@ impl CatchPerformanceCalculator<'_> {
         } else if ar < 8.0 {
             ar_factor += 0.025 * (8.0 - ar);
         }
-        pp *= ar_factor;
+        pp *= self.trace.factor("total", "AR bonus", ar_factor);
 
         // HD bonus
         if self.mods.hd() {
             if ar <= 10.0 {
-                pp *= 1.05 + 0.075 * (10.0 - ar);
+                pp *= self
+                    .trace
+                    .factor("total", "hidden bonus", 1.05 + 0.075 * (10.0 - ar));
             } else if ar > 10.0 {
-                pp *= 1.01 + 0.04 * (11.0 - ar.min(11.0));
+                pp *=
+                    self.trace
+                        .factor("total", "hidden bonus", 1.01 + 0.04 * (11.0 - ar.min(11.0)));
             }
         }
 
         // FL bonus
         if self.mods.fl() {
-            pp *= 1.35 * len_bonus;
+            pp *= self
+                .trace
+                .factor("total", "flashlight bonus", 1.35 * len_bonus);
         }
 
         // Accuracy scaling
-        pp *= self.state.accuracy().powf(5.5);
+        pp *= self
+            .trace
+            .factor("total", "accuracy", self.state.accuracy().powf(5.5));
 
         // NF penalty
         if self.mods.nf() {
-            pp *= (1.0 - 0.02 * f64::from(self.state.misses)).max(0.9);
+            pp *= self.trace.factor(
+                "total",
+                "no fail",
+                (1.0 - 0.02 * f64::from(self.state.misses)).max(0.9),
+            );
         }
 
+        self.trace.value("total", "pp", pp);
+
         CatchPerformanceAttributes {
             difficulty: self.attrs,
             pp,
@@ -5,7 +5,10 @@ use rosu_map::section::general::GameMode;
 use self::calculator::CatchPerformanceCalculator;
 
 use crate::{
-    any::{Difficulty, IntoModePerformance, IntoPerformance},
+    any::{
+        trace::{self, Trace},
+        Difficulty, IntoModePerformance, IntoPerformance, PerformanceTrace,
+    },
     model::{mode::ConvertError, mods::GameMods},
     osu::OsuPerformance,
     util::map_or_attrs::MapOrAttrs,
@@ -416,7 +419,24 @@ impl<'map> CatchPerformance<'map> {
     }
 
     /// Calculate all performance related values, including pp and stars.
-    pub fn calculate(mut self) -> Result<CatchPerformanceAttributes, ConvertError> {
+    pub fn calculate(self) -> Result<CatchPerformanceAttributes, ConvertError> {
+        self.calculate_inner(Trace::disabled())
+    }
+
+    /// Same as [`CatchPerformance::calculate`] but also records each value
+    /// and factor of the pp formula in a [`PerformanceTrace`].
+    pub fn calculate_with_trace(
+        self,
+    ) -> Result<(CatchPerformanceAttributes, PerformanceTrace), ConvertError> {
+        let (res, trace) = trace::traced(|trace| self.calculate_inner(trace));
+
+        res.map(|attrs| (attrs, trace))
+    }
+
+    fn calculate_inner(
+        mut self,
+        trace: Trace<'_>,
+    ) -> Result<CatchPerformanceAttributes, ConvertError> {
         let state = self.generate_state()?;
 
         let attrs = match self.map_or_attrs {
@@ -424,7 +444,10 @@ impl<'map> CatchPerformance<'map> {
             MapOrAttrs::Map(ref map) => self.difficulty.calculate_for_mode::<Catch>(map)?,
         };
 
-        Ok(CatchPerformanceCalculator::new(attrs, self.difficulty.get_mods(), state).calculate())
+        Ok(
+            CatchPerformanceCalculator::new(attrs, self.difficulty.get_mods(), state, trace)
+                .calculate(),
+        )
     }
 
     pub(crate) const fn from_map_or_attrs(map_or_attrs: MapOrAttrs<'map, Catch>) -> Self {
@@ -11,6 +11,7 @@ use crate::{
 ///
 /// Suitable to plot the difficulty of a map over time.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CatchStrains {
     /// Strain peaks of the movement skill.
     pub movement: Vec<f64>,
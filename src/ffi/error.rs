@@ -0,0 +1,95 @@
+use std::io;
+
+use crate::model::{
+    beatmap::{CheckedDecodeError, ParseBeatmapError},
+    mode::ConvertError,
+};
+
+/// Return code of every `mames_pp_*` function.
+///
+/// Codes `10..30` mirror [`ParseBeatmapError`] and are only returned by
+/// `mames_pp_beatmap_from_bytes_strict`. Codes `30..40` mirror
+/// [`ConvertError`].
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MamesPpError {
+    /// No error.
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// An argument was out of range or not valid UTF-8.
+    InvalidArgument = 2,
+    /// Reading the beatmap failed.
+    Io = 3,
+    /// The output buffer is too small. The required length has been written.
+    BufferTooSmall = 4,
+    /// The gradual calculation has already processed all objects.
+    Finished = 5,
+    ParseEffectFlags = 10,
+    ParseEventType = 11,
+    ParseHitObjectType = 12,
+    ParseHitSoundType = 13,
+    InvalidEventLine = 14,
+    InvalidRepeatCount = 15,
+    InvalidTimingPointLine = 16,
+    InvalidHitObjectLine = 17,
+    ParseMode = 18,
+    ParseNumber = 19,
+    TimeSignature = 20,
+    TimingControlPointNaN = 21,
+    UnknownHitObjectType = 22,
+    /// Cannot convert an already converted map.
+    AlreadyConverted = 30,
+    /// Cannot convert the map to the requested mode.
+    IncompatibleMode = 31,
+    /// A panic was caught before it could cross the FFI boundary.
+    Panic = 99,
+}
+
+impl From<&ParseBeatmapError> for MamesPpError {
+    fn from(err: &ParseBeatmapError) -> Self {
+        match err {
+            ParseBeatmapError::EffectFlags(_) => Self::ParseEffectFlags,
+            ParseBeatmapError::EventType(_) => Self::ParseEventType,
+            ParseBeatmapError::HitObjectType(_) => Self::ParseHitObjectType,
+            ParseBeatmapError::HitSoundType(_) => Self::ParseHitSoundType,
+            ParseBeatmapError::InvalidEventLine => Self::InvalidEventLine,
+            ParseBeatmapError::InvalidRepeatCount => Self::InvalidRepeatCount,
+            ParseBeatmapError::InvalidTimingPointLine => Self::InvalidTimingPointLine,
+            ParseBeatmapError::InvalidHitObjectLine => Self::InvalidHitObjectLine,
+            ParseBeatmapError::Mode(_) => Self::ParseMode,
+            ParseBeatmapError::Number(_) => Self::ParseNumber,
+            ParseBeatmapError::TimeSignature => Self::TimeSignature,
+            ParseBeatmapError::TimingControlPointNaN => Self::TimingControlPointNaN,
+            ParseBeatmapError::UnknownHitObjectType => Self::UnknownHitObjectType,
+        }
+    }
+}
+
+impl From<ConvertError> for MamesPpError {
+    fn from(err: ConvertError) -> Self {
+        match err {
+            ConvertError::AlreadyConverted => Self::AlreadyConverted,
+            ConvertError::Convert { .. } => Self::IncompatibleMode,
+        }
+    }
+}
+
+impl From<io::Error> for MamesPpError {
+    fn from(err: io::Error) -> Self {
+        err.get_ref()
+            .and_then(|err| err.downcast_ref::<ParseBeatmapError>())
+            .map_or(Self::Io, Self::from)
+    }
+}
+
+impl From<CheckedDecodeError> for MamesPpError {
+    fn from(err: CheckedDecodeError) -> Self {
+        match err {
+            CheckedDecodeError::Io(err) => err.into(),
+            CheckedDecodeError::Rejected(diagnostics) => diagnostics
+                .first()
+                .map_or(Self::Io, |diagnostic| Self::from(&diagnostic.error)),
+        }
+    }
+}
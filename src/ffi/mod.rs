@@ -0,0 +1,769 @@
+//! C ABI for embedding the calculation into non-Rust programs.
+//!
+//! All types that are not `#[repr(C)]` are handed out as opaque pointers that
+//! must be released through their `mames_pp_*_free` function. Every other
+//! function returns a [`MamesPpError`] and writes its result into an out
+//! pointer. Panics are caught and reported as [`MamesPpError::Panic`].
+//!
+//! The header `include/mames_pp.h` is generated with
+//! `cbindgen --config cbindgen.toml --output include/mames_pp.h`, which the
+//! test `tests/ffi_header.rs` checks, and a library can be built with
+//! `cargo rustc --release --features ffi --crate-type cdylib`.
+
+use std::{
+    ffi::{c_char, CStr},
+    mem,
+    panic::{self, AssertUnwindSafe},
+    slice,
+};
+
+use crate::{
+    any::{AlgorithmVersion, DifficultyAttributes, PerformanceAttributes, ScoreState},
+    model::{beatmap::DecodeMode, mode::GameMode, mods::reexports::GameModsIntermode},
+    Beatmap, Difficulty, GameMods, GradualPerformance, Performance,
+};
+
+pub use self::error::MamesPpError;
+
+mod error;
+
+/// Opaque handle of a [`Beatmap`].
+pub struct MamesPpBeatmap(Beatmap);
+
+/// Opaque handle of a [`Difficulty`].
+pub struct MamesPpDifficulty(Difficulty);
+
+/// Opaque handle of [`DifficultyAttributes`].
+pub struct MamesPpDifficultyAttributes(DifficultyAttributes);
+
+/// Opaque handle of a [`GradualPerformance`].
+pub struct MamesPpGradualPerformance(GradualPerformance);
+
+/// C counterpart of [`ScoreState`].
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct MamesPpScoreState {
+    pub max_combo: u32,
+    pub osu_large_tick_hits: u32,
+    pub osu_small_tick_hits: u32,
+    pub slider_end_hits: u32,
+    pub taiko_drum_roll_tick_hits: u32,
+    pub taiko_swells_completed: u32,
+    pub n_geki: u32,
+    pub n_katu: u32,
+    pub n300: u32,
+    pub n100: u32,
+    pub n50: u32,
+    pub misses: u32,
+}
+
+impl From<MamesPpScoreState> for ScoreState {
+    fn from(state: MamesPpScoreState) -> Self {
+        Self {
+            max_combo: state.max_combo,
+            osu_large_tick_hits: state.osu_large_tick_hits,
+            osu_small_tick_hits: state.osu_small_tick_hits,
+            slider_end_hits: state.slider_end_hits,
+            taiko_drum_roll_tick_hits: state.taiko_drum_roll_tick_hits,
+            taiko_swells_completed: state.taiko_swells_completed,
+            n_geki: state.n_geki,
+            n_katu: state.n_katu,
+            n300: state.n300,
+            n100: state.n100,
+            n50: state.n50,
+            misses: state.misses,
+        }
+    }
+}
+
+/// The result of a performance calculation.
+///
+/// Values that don't exist for the mode are zero.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct MamesPpPerformanceAttributes {
+    /// 0 = osu!standard, 1 = osu!taiko, 2 = osu!catch, 3 = osu!mania
+    pub mode: u8,
+    pub pp: f64,
+    pub stars: f64,
+    pub max_combo: u32,
+    pub pp_acc: f64,
+    pub pp_aim: f64,
+    pub pp_speed: f64,
+    pub pp_flashlight: f64,
+    pub pp_reading: f64,
+    pub pp_difficulty: f64,
+    pub effective_miss_count: f64,
+}
+
+impl From<&PerformanceAttributes> for MamesPpPerformanceAttributes {
+    fn from(attrs: &PerformanceAttributes) -> Self {
+        let mut this = Self {
+            pp: attrs.pp(),
+            stars: attrs.stars(),
+            max_combo: attrs.max_combo(),
+            ..Self::default()
+        };
+
+        match attrs {
+            PerformanceAttributes::Osu(attrs) => {
+                this.mode = 0;
+                this.pp_acc = attrs.pp_acc;
+                this.pp_aim = attrs.pp_aim;
+                this.pp_speed = attrs.pp_speed;
+                this.pp_flashlight = attrs.pp_flashlight;
+                this.pp_reading = attrs.pp_reading;
+                this.effective_miss_count = attrs.effective_miss_count;
+            }
+            PerformanceAttributes::Taiko(attrs) => {
+                this.mode = 1;
+                this.pp_acc = attrs.pp_acc;
+                this.pp_difficulty = attrs.pp_difficulty;
+                this.effective_miss_count = attrs.effective_miss_count;
+            }
+            PerformanceAttributes::Catch(_) => this.mode = 2,
+            PerformanceAttributes::Mania(attrs) => {
+                this.mode = 3;
+                this.pp_difficulty = attrs.pp_difficulty;
+            }
+        }
+
+        this
+    }
+}
+
+/// A single `(attribute_id, value)` pair.
+///
+/// See [`database_attributes`](crate::any::database_attributes).
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct MamesPpDatabaseAttribute {
+    pub id: u16,
+    pub value: f64,
+}
+
+fn guard(f: impl FnOnce() -> Result<(), MamesPpError>) -> MamesPpError {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => MamesPpError::Ok,
+        Ok(Err(err)) => err,
+        Err(_) => MamesPpError::Panic,
+    }
+}
+
+unsafe fn as_ref<'a, T>(ptr: *const T) -> Result<&'a T, MamesPpError> {
+    ptr.as_ref().ok_or(MamesPpError::NullPointer)
+}
+
+unsafe fn as_mut<'a, T>(ptr: *mut T) -> Result<&'a mut T, MamesPpError> {
+    ptr.as_mut().ok_or(MamesPpError::NullPointer)
+}
+
+unsafe fn write<T>(out: *mut T, value: T) -> Result<(), MamesPpError> {
+    if out.is_null() {
+        return Err(MamesPpError::NullPointer);
+    }
+
+    out.write(value);
+
+    Ok(())
+}
+
+fn update_difficulty(
+    difficulty: *mut MamesPpDifficulty,
+    f: impl FnOnce(Difficulty) -> Difficulty,
+) -> MamesPpError {
+    guard(|| {
+        let difficulty = unsafe { as_mut(difficulty)? };
+        let prev = mem::take(&mut difficulty.0);
+        difficulty.0 = f(prev);
+
+        Ok(())
+    })
+}
+
+const fn mode_from_u8(mode: u8) -> Result<GameMode, MamesPpError> {
+    match mode {
+        0 => Ok(GameMode::Osu),
+        1 => Ok(GameMode::Taiko),
+        2 => Ok(GameMode::Catch),
+        3 => Ok(GameMode::Mania),
+        _ => Err(MamesPpError::InvalidArgument),
+    }
+}
+
+/// Parse a beatmap from the content of a `.osu` file.
+///
+/// Lines that cannot be decoded are skipped; see
+/// [`mames_pp_beatmap_from_bytes_strict`] to reject them instead.
+///
+/// # Safety
+///
+/// `data` must point to `len` readable bytes and `out` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_beatmap_from_bytes(
+    data: *const u8,
+    len: usize,
+    out: *mut *mut MamesPpBeatmap,
+) -> MamesPpError {
+    guard(|| {
+        if data.is_null() {
+            return Err(MamesPpError::NullPointer);
+        }
+
+        let bytes = slice::from_raw_parts(data, len);
+        let map = Beatmap::from_bytes(bytes)?;
+
+        write(out, Box::into_raw(Box::new(MamesPpBeatmap(map))))
+    })
+}
+
+/// Parse a beatmap from the content of a `.osu` file and fail with the
+/// error of the first line that cannot be decoded.
+///
+/// # Safety
+///
+/// `data` must point to `len` readable bytes and `out` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_beatmap_from_bytes_strict(
+    data: *const u8,
+    len: usize,
+    out: *mut *mut MamesPpBeatmap,
+) -> MamesPpError {
+    guard(|| {
+        if data.is_null() {
+            return Err(MamesPpError::NullPointer);
+        }
+
+        let bytes = slice::from_raw_parts(data, len);
+        let checked = Beatmap::from_bytes_checked(bytes, DecodeMode::Strict)?;
+
+        write(out, Box::into_raw(Box::new(MamesPpBeatmap(checked.map))))
+    })
+}
+
+/// Convert the beatmap to the given mode in-place.
+///
+/// # Safety
+///
+/// `map` must be a valid beatmap handle and `difficulty` either null or a
+/// valid difficulty handle whose mods will be considered.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_beatmap_convert(
+    map: *mut MamesPpBeatmap,
+    mode: u8,
+    difficulty: *const MamesPpDifficulty,
+) -> MamesPpError {
+    guard(|| {
+        let map = as_mut(map)?;
+        let mode = mode_from_u8(mode)?;
+
+        let mods = match difficulty.as_ref() {
+            Some(difficulty) => difficulty.0.get_mods().clone(),
+            None => GameMods::default(),
+        };
+
+        map.0.convert_mut(mode, &mods).map_err(MamesPpError::from)
+    })
+}
+
+/// Free a beatmap handle. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `map` must be null or a handle that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_beatmap_free(map: *mut MamesPpBeatmap) {
+    if !map.is_null() {
+        drop(Box::from_raw(map));
+    }
+}
+
+/// Create a new difficulty configuration without mods.
+///
+/// # Safety
+///
+/// `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_new(out: *mut *mut MamesPpDifficulty) -> MamesPpError {
+    guard(|| {
+        let difficulty = Box::new(MamesPpDifficulty(Difficulty::new()));
+
+        write(out, Box::into_raw(difficulty))
+    })
+}
+
+/// Specify mods through their legacy bits.
+///
+/// # Safety
+///
+/// `difficulty` must be a valid difficulty handle.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_mods_bits(
+    difficulty: *mut MamesPpDifficulty,
+    bits: u32,
+) -> MamesPpError {
+    update_difficulty(difficulty, |difficulty| difficulty.mods(bits))
+}
+
+/// Specify mods through a nul-terminated string of acronyms, e.g. `"HDDT"`.
+///
+/// # Safety
+///
+/// `difficulty` must be a valid difficulty handle and `acronyms` a valid
+/// nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_mods_acronyms(
+    difficulty: *mut MamesPpDifficulty,
+    acronyms: *const c_char,
+) -> MamesPpError {
+    if acronyms.is_null() {
+        return MamesPpError::NullPointer;
+    }
+
+    let Ok(acronyms) = CStr::from_ptr(acronyms).to_str() else {
+        return MamesPpError::InvalidArgument;
+    };
+
+    update_difficulty(difficulty, |difficulty| {
+        difficulty.mods(GameModsIntermode::from_acronyms(acronyms))
+    })
+}
+
+/// Adjust the clock rate.
+///
+/// # Safety
+///
+/// `difficulty` must be a valid difficulty handle.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_clock_rate(
+    difficulty: *mut MamesPpDifficulty,
+    clock_rate: f64,
+) -> MamesPpError {
+    update_difficulty(difficulty, |difficulty| difficulty.clock_rate(clock_rate))
+}
+
+/// Override the map's approach rate.
+///
+/// If `with_mods` is true, the value already considers mods.
+///
+/// # Safety
+///
+/// `difficulty` must be a valid difficulty handle.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_ar(
+    difficulty: *mut MamesPpDifficulty,
+    value: f32,
+    with_mods: bool,
+) -> MamesPpError {
+    update_difficulty(difficulty, |difficulty| difficulty.ar(value, with_mods))
+}
+
+/// Override the map's circle size.
+///
+/// If `with_mods` is true, the value already considers mods.
+///
+/// # Safety
+///
+/// `difficulty` must be a valid difficulty handle.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_cs(
+    difficulty: *mut MamesPpDifficulty,
+    value: f32,
+    with_mods: bool,
+) -> MamesPpError {
+    update_difficulty(difficulty, |difficulty| difficulty.cs(value, with_mods))
+}
+
+/// Override the map's drain rate.
+///
+/// If `with_mods` is true, the value already considers mods.
+///
+/// # Safety
+///
+/// `difficulty` must be a valid difficulty handle.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_hp(
+    difficulty: *mut MamesPpDifficulty,
+    value: f32,
+    with_mods: bool,
+) -> MamesPpError {
+    update_difficulty(difficulty, |difficulty| difficulty.hp(value, with_mods))
+}
+
+/// Override the map's overall difficulty.
+///
+/// If `with_mods` is true, the value already considers mods.
+///
+/// # Safety
+///
+/// `difficulty` must be a valid difficulty handle.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_od(
+    difficulty: *mut MamesPpDifficulty,
+    value: f32,
+    with_mods: bool,
+) -> MamesPpError {
+    update_difficulty(difficulty, |difficulty| difficulty.od(value, with_mods))
+}
+
+/// Amount of passed objects for partial plays, e.g. a fail.
+///
+/// # Safety
+///
+/// `difficulty` must be a valid difficulty handle.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_passed_objects(
+    difficulty: *mut MamesPpDifficulty,
+    passed_objects: u32,
+) -> MamesPpError {
+    update_difficulty(difficulty, |difficulty| {
+        difficulty.passed_objects(passed_objects)
+    })
+}
+
+/// Whether the calculated attributes belong to an osu!lazer or osu!stable
+/// score.
+///
+/// # Safety
+///
+/// `difficulty` must be a valid difficulty handle.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_lazer(
+    difficulty: *mut MamesPpDifficulty,
+    lazer: bool,
+) -> MamesPpError {
+    update_difficulty(difficulty, |difficulty| difficulty.lazer(lazer))
+}
+
+/// Specify the algorithm version.
+///
+/// `0` is osu!lazer 2026 and `1` is Mamestagram.
+///
+/// # Safety
+///
+/// `difficulty` must be a valid difficulty handle.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_algorithm(
+    difficulty: *mut MamesPpDifficulty,
+    algorithm: u8,
+) -> MamesPpError {
+    let algorithm = match algorithm {
+        0 => AlgorithmVersion::Lazer2026,
+        1 => AlgorithmVersion::Mamestagram,
+        _ => return MamesPpError::InvalidArgument,
+    };
+
+    update_difficulty(difficulty, |difficulty| difficulty.algorithm(algorithm))
+}
+
+/// Calculate the difficulty attributes of a beatmap.
+///
+/// # Safety
+///
+/// `difficulty` and `map` must be valid handles and `out` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_calculate(
+    difficulty: *const MamesPpDifficulty,
+    map: *const MamesPpBeatmap,
+    out: *mut *mut MamesPpDifficultyAttributes,
+) -> MamesPpError {
+    guard(|| {
+        let difficulty = as_ref(difficulty)?;
+        let map = as_ref(map)?;
+        let attrs = difficulty.0.calculate(&map.0);
+
+        write(
+            out,
+            Box::into_raw(Box::new(MamesPpDifficultyAttributes(attrs))),
+        )
+    })
+}
+
+/// Free a difficulty handle. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `difficulty` must be null or a handle that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_free(difficulty: *mut MamesPpDifficulty) {
+    if !difficulty.is_null() {
+        drop(Box::from_raw(difficulty));
+    }
+}
+
+/// The star rating of difficulty attributes.
+///
+/// # Safety
+///
+/// `attrs` must be a valid handle and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_attributes_stars(
+    attrs: *const MamesPpDifficultyAttributes,
+    out: *mut f64,
+) -> MamesPpError {
+    guard(|| write(out, as_ref(attrs)?.0.stars()))
+}
+
+/// The maximum combo of difficulty attributes.
+///
+/// # Safety
+///
+/// `attrs` must be a valid handle and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_attributes_max_combo(
+    attrs: *const MamesPpDifficultyAttributes,
+    out: *mut u32,
+) -> MamesPpError {
+    guard(|| write(out, as_ref(attrs)?.0.max_combo()))
+}
+
+/// Write all difficulty attributes as `(attribute_id, value)` pairs.
+///
+/// The amount of pairs is written to `len`. If `capacity` is too small,
+/// nothing is written to `out` and [`MamesPpError::BufferTooSmall`] is
+/// returned so that the call can be repeated with a larger buffer.
+///
+/// # Safety
+///
+/// `attrs` must be a valid handle, `out` must be valid for `capacity` writes
+/// or null if `capacity` is zero, and `len` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_attributes_database(
+    attrs: *const MamesPpDifficultyAttributes,
+    out: *mut MamesPpDatabaseAttribute,
+    capacity: usize,
+    len: *mut usize,
+) -> MamesPpError {
+    guard(|| {
+        let values = as_ref(attrs)?.0.to_database_attributes();
+        write(len, values.len())?;
+
+        if values.len() > capacity {
+            return Err(MamesPpError::BufferTooSmall);
+        } else if out.is_null() {
+            return Err(MamesPpError::NullPointer);
+        }
+
+        for (i, (id, value)) in values.into_iter().enumerate() {
+            out.add(i).write(MamesPpDatabaseAttribute { id, value });
+        }
+
+        Ok(())
+    })
+}
+
+/// Free a difficulty attributes handle. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `attrs` must be null or a handle that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_difficulty_attributes_free(
+    attrs: *mut MamesPpDifficultyAttributes,
+) {
+    if !attrs.is_null() {
+        drop(Box::from_raw(attrs));
+    }
+}
+
+/// Calculate the performance of a score.
+///
+/// The attributes must have been calculated with the same `difficulty`. If
+/// `state` is null, the performance of a perfect play is calculated.
+///
+/// # Safety
+///
+/// `attrs` and `difficulty` must be valid handles, `state` must be null or
+/// valid for reads, and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_performance_calculate(
+    attrs: *const MamesPpDifficultyAttributes,
+    difficulty: *const MamesPpDifficulty,
+    state: *const MamesPpScoreState,
+    out: *mut MamesPpPerformanceAttributes,
+) -> MamesPpError {
+    guard(|| {
+        let attrs = as_ref(attrs)?.0.clone();
+        let difficulty = as_ref(difficulty)?.0.clone();
+
+        let mut performance = Performance::new(attrs).difficulty(difficulty);
+
+        if let Some(state) = state.as_ref() {
+            performance = performance.state(ScoreState::from(*state));
+        }
+
+        write(out, (&performance.calculate()).into())
+    })
+}
+
+/// Prepare a gradual performance calculation.
+///
+/// # Safety
+///
+/// `difficulty` and `map` must be valid handles and `out` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_gradual_performance_new(
+    difficulty: *const MamesPpDifficulty,
+    map: *const MamesPpBeatmap,
+    out: *mut *mut MamesPpGradualPerformance,
+) -> MamesPpError {
+    guard(|| {
+        let difficulty = as_ref(difficulty)?.0.clone();
+        let map = as_ref(map)?;
+        let gradual = GradualPerformance::new(difficulty, &map.0);
+
+        write(
+            out,
+            Box::into_raw(Box::new(MamesPpGradualPerformance(gradual))),
+        )
+    })
+}
+
+/// Process the next `n + 1` objects with the score state after them.
+///
+/// Returns [`MamesPpError::Finished`] if all objects were already processed.
+///
+/// # Safety
+///
+/// `gradual` must be a valid handle, `state` must be valid for reads, and
+/// `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_gradual_performance_nth(
+    gradual: *mut MamesPpGradualPerformance,
+    state: *const MamesPpScoreState,
+    n: usize,
+    out: *mut MamesPpPerformanceAttributes,
+) -> MamesPpError {
+    guard(|| {
+        let gradual = as_mut(gradual)?;
+        let state = ScoreState::from(*as_ref(state)?);
+
+        let attrs = gradual.0.nth(state, n).ok_or(MamesPpError::Finished)?;
+
+        write(out, (&attrs).into())
+    })
+}
+
+/// The amount of remaining objects.
+///
+/// # Safety
+///
+/// `gradual` must be a valid handle and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_gradual_performance_len(
+    gradual: *const MamesPpGradualPerformance,
+    out: *mut usize,
+) -> MamesPpError {
+    guard(|| write(out, as_ref(gradual)?.0.len()))
+}
+
+/// Free a gradual performance handle. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `gradual` must be null or a handle that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn mames_pp_gradual_performance_free(
+    gradual: *mut MamesPpGradualPerformance,
+) {
+    if !gradual.is_null() {
+        drop(Box::from_raw(gradual));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use super::*;
+
+    #[test]
+    fn calculate_through_handles() {
+        let bytes = std::fs::read("./resources/2785319.osu").unwrap();
+
+        unsafe {
+            let mut map = ptr::null_mut();
+            let mut difficulty = ptr::null_mut();
+            let mut attrs = ptr::null_mut();
+
+            assert_eq!(
+                mames_pp_beatmap_from_bytes(bytes.as_ptr(), bytes.len(), &mut map),
+                MamesPpError::Ok
+            );
+            assert_eq!(mames_pp_difficulty_new(&mut difficulty), MamesPpError::Ok);
+            assert_eq!(
+                mames_pp_difficulty_mods_acronyms(difficulty, c"HDDT".as_ptr()),
+                MamesPpError::Ok
+            );
+            assert_eq!(
+                mames_pp_difficulty_calculate(difficulty, map, &mut attrs),
+                MamesPpError::Ok
+            );
+
+            let expected = Difficulty::new()
+                .mods(GameModsIntermode::from_acronyms("HDDT"))
+                .calculate(&(*map).0);
+
+            let mut stars = 0.0;
+            assert_eq!(
+                mames_pp_difficulty_attributes_stars(attrs, &mut stars),
+                MamesPpError::Ok
+            );
+            assert!((stars - expected.stars()).abs() < f64::EPSILON);
+
+            let mut len = 0;
+            assert_eq!(
+                mames_pp_difficulty_attributes_database(attrs, ptr::null_mut(), 0, &mut len),
+                MamesPpError::BufferTooSmall
+            );
+            assert_eq!(len, expected.to_database_attributes().len());
+
+            let mut performance = MamesPpPerformanceAttributes::default();
+            assert_eq!(
+                mames_pp_performance_calculate(attrs, difficulty, ptr::null(), &mut performance),
+                MamesPpError::Ok
+            );
+            assert!(performance.pp > 0.0);
+
+            assert_eq!(
+                mames_pp_beatmap_convert(map, 4, ptr::null()),
+                MamesPpError::InvalidArgument
+            );
+            assert_eq!(
+                mames_pp_difficulty_calculate(difficulty, ptr::null(), &mut attrs),
+                MamesPpError::NullPointer
+            );
+
+            mames_pp_difficulty_attributes_free(attrs);
+            mames_pp_difficulty_free(difficulty);
+            mames_pp_beatmap_free(map);
+        }
+    }
+
+    #[test]
+    fn strict_decoding() {
+        let bytes = b"osu file format v14\n\n[HitObjects]\n256,192,1000,1,0\n256,192,3000,2,0,B|300:192,10000,100\n";
+
+        unsafe {
+            let mut map = ptr::null_mut();
+
+            assert_eq!(
+                mames_pp_beatmap_from_bytes_strict(bytes.as_ptr(), bytes.len(), &mut map),
+                MamesPpError::InvalidRepeatCount
+            );
+            assert!(map.is_null());
+
+            assert_eq!(
+                mames_pp_beatmap_from_bytes(bytes.as_ptr(), bytes.len(), &mut map),
+                MamesPpError::Ok
+            );
+            assert_eq!((*map).0.hit_objects.len(), 1);
+
+            mames_pp_beatmap_free(map);
+        }
+    }
+}
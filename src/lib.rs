@@ -120,7 +120,12 @@
 //! | Flag          | Description         | Dependencies
 //! | ------------- | ------------------- | ------------
 //! | `default`     | No features enabled |
+//! | `cli`         | Builds the `mames-pp` binary to calculate stars and pp from the command line, e.g. `cargo run --features cli -- map.osu --mods HDDT --acc 98.5`. | [`clap`], [`serde`], [`serde_json`]
+//! | `ffi`         | Exposes a C ABI in the `ffi` module. The header is at `include/mames_pp.h`. |
+//! | `metadata`    | Decodes the title, artist, creator, difficulty name, and IDs of a beatmap, and the MD5 checksum of its file, into `Beatmap::metadata` which stays empty otherwise. |
 //! | `raw_strains` | With this feature, internal strain values will be stored in a plain `Vec`. This introduces an out-of-memory risk on maliciously long maps (see [/b/3739922](https://osu.ppy.sh/b/3739922)), but comes with a ~5% gain in performance. |
+//! | `serde`       | Implements `Serialize` and `Deserialize` for attributes, score states, strains, and `Difficulty`, and enables importing osu!lazer score JSON through `any::LazerScore`. | [`serde`], [`serde_json`]
+//! | `svg`         | Renders strain graphs, osu!standard playfield snapshots, and osu!taiko / osu!mania note strips as SVG in the `svg` module. |
 //! | `sync`        | Some gradual calculation types can only be shared across threads if this feature is enabled. This feature adds a small performance penalty. |
 //! | `tracing`     | Any error encountered during beatmap decoding will be logged through `tracing::error`. If this feature is **not** enabled, errors will be ignored. | [`tracing`]
 //!
@@ -133,6 +138,9 @@
 //! [osu!]: https://osu.ppy.sh/home
 //! [osu!lazer]: https://github.com/ppy/osu
 //! [osu!tools]: https://github.com/ppy/osu-tools
+//! [`clap`]: https://docs.rs/clap
+//! [`serde`]: https://docs.rs/serde
+//! [`serde_json`]: https://docs.rs/serde_json
 //! [`tracing`]: https://docs.rs/tracing
 //! [rosu-pp-js]: https://github.com/MaxOhn/rosu-pp-js
 //! [rosu-pp-py]: https://github.com/MaxOhn/rosu-pp-py
@@ -182,3 +190,11 @@ pub mod mania;
 
 /// Types used in and around this crate.
 pub mod model;
+
+/// C ABI to use this crate from other languages.
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+/// SVG rendering of strains and maps.
+#[cfg(feature = "svg")]
+pub mod svg;
@@ -3,7 +3,7 @@ use std::cmp;
 use rosu_map::section::general::GameMode;
 
 use crate::{
-    any::difficulty::skills::StrainSkill,
+    any::difficulty::{section::SectionWindow, skills::StrainSkill},
     mania::object::ObjectParams,
     model::{hit_object::HitObject, mode::ConvertError},
     Beatmap, Difficulty,
@@ -70,7 +70,7 @@ impl ManiaGradualDifficulty {
         let take = difficulty.get_passed_objects();
         let total_columns = map.cs.round_ties_even().max(1.0);
         let clock_rate = difficulty.get_clock_rate();
-        let mut params = ObjectParams::new(&map);
+        let mut params = ObjectParams::new(&map, SectionWindow::default());
 
         let mania_objects = map
             .hit_objects
@@ -26,7 +26,11 @@ pub fn difficulty(
 ) -> Result<ManiaDifficultyAttributes, ConvertError> {
     let map = map.convert_ref(GameMode::Mania, difficulty.get_mods())?;
 
-    let n_objects = cmp::min(difficulty.get_passed_objects(), map.hit_objects.len()) as u32;
+    let passed_objects = cmp::min(difficulty.get_passed_objects(), map.hit_objects.len());
+    let section = difficulty.get_section_window(&map.hit_objects);
+    let n_objects = section
+        .slice(&map.hit_objects[..passed_objects], |h| h.start_time)
+        .len() as u32;
 
     let values = DifficultyValues::calculate(difficulty, &map);
 
@@ -50,7 +54,8 @@ impl DifficultyValues {
         let take = difficulty.get_passed_objects();
         let total_columns = map.cs.round_ties_even().max(1.0);
         let clock_rate = difficulty.get_clock_rate();
-        let mut params = ObjectParams::new(map);
+        let mut section = difficulty.get_section_window(&map.hit_objects);
+        let mut params = ObjectParams::new(map, section);
 
         let mania_objects = map
             .hit_objects
@@ -63,6 +68,14 @@ impl DifficultyValues {
         let mut strain = Strain::new(total_columns as usize);
 
         for curr in diff_objects.iter() {
+            if section.is_past(curr.start_time) {
+                break;
+            }
+
+            if section.enters(curr.start_time) {
+                strain.discard_history();
+            }
+
             strain.process(curr, &diff_objects);
         }
 
@@ -16,6 +16,8 @@ pub use self::{
     strains::ManiaStrains,
 };
 
+pub(crate) use self::object::ManiaObject;
+
 mod attributes;
 mod convert;
 mod difficulty;
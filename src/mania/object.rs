@@ -1,9 +1,12 @@
 use rosu_map::section::{general::GameMode, hit_objects::CurveBuffers};
 
-use crate::model::{
-    beatmap::Beatmap,
-    control_point::{DifficultyPoint, TimingPoint},
-    hit_object::{HitObject, HitObjectKind, HoldNote, Spinner},
+use crate::{
+    any::difficulty::section::SectionWindow,
+    model::{
+        beatmap::Beatmap,
+        control_point::{DifficultyPoint, TimingPoint},
+        hit_object::{HitObject, HitObjectKind, HoldNote, Spinner},
+    },
 };
 
 pub struct ManiaObject {
@@ -15,14 +18,17 @@ pub struct ManiaObject {
 impl ManiaObject {
     pub fn new(h: &HitObject, total_columns: f32, params: &mut ObjectParams<'_>) -> Self {
         let column = Self::column(h.pos.x, total_columns);
-        params.max_combo += 1;
 
         match h.kind {
-            HitObjectKind::Circle => Self {
-                start_time: h.start_time,
-                end_time: h.start_time,
-                column,
-            },
+            HitObjectKind::Circle => {
+                params.count(h.start_time, 1, false);
+
+                Self {
+                    start_time: h.start_time,
+                    end_time: h.start_time,
+                    column,
+                }
+            }
             HitObjectKind::Slider(ref slider) => {
                 const BASE_SCORING_DIST: f32 = 100.0;
 
@@ -46,8 +52,7 @@ impl ManiaObject {
 
                 let duration = (slider.span_count() as f64) * dist / velocity;
 
-                params.max_combo += (duration / 100.0) as u32;
-                params.n_hold_notes += 1;
+                params.count(h.start_time, 1 + (duration / 100.0) as u32, true);
 
                 Self {
                     start_time: h.start_time,
@@ -57,8 +62,7 @@ impl ManiaObject {
             }
             HitObjectKind::Spinner(Spinner { duration })
             | HitObjectKind::Hold(HoldNote { duration }) => {
-                params.max_combo += (duration / 100.0) as u32;
-                params.n_hold_notes += 1;
+                params.count(h.start_time, 1 + (duration / 100.0) as u32, true);
 
                 Self {
                     start_time: h.start_time,
@@ -78,21 +82,31 @@ impl ManiaObject {
 
 pub struct ObjectParams<'a> {
     map: &'a Beatmap,
+    section: SectionWindow,
     max_combo: u32,
     n_hold_notes: u32,
     curve_bufs: CurveBuffers,
 }
 
 impl<'a> ObjectParams<'a> {
-    pub fn new(map: &'a Beatmap) -> Self {
+    /// Only objects that start within the section are counted.
+    pub fn new(map: &'a Beatmap, section: SectionWindow) -> Self {
         Self {
             map,
+            section,
             max_combo: 0,
             n_hold_notes: 0,
             curve_bufs: CurveBuffers::default(),
         }
     }
 
+    fn count(&mut self, start_time: f64, combo: u32, is_hold_note: bool) {
+        if self.section.contains(start_time) {
+            self.max_combo += combo;
+            self.n_hold_notes += u32::from(is_hold_note);
+        }
+    }
+
     pub const fn max_combo(&self) -> u32 {
         self.max_combo
     }
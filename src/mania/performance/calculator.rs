@@ -1,4 +1,5 @@
 use crate::{
+    any::trace::Trace,
     mania::{ManiaDifficultyAttributes, ManiaPerformanceAttributes, ManiaScoreState},
     GameMods,
 };
@@ -7,6 +8,7 @@ pub(super) struct ManiaPerformanceCalculator<'mods> {
     attrs: ManiaDifficultyAttributes,
     mods: &'mods GameMods,
     state: ManiaScoreState,
+    trace: Trace<'mods>,
 }
 
 impl<'a> ManiaPerformanceCalculator<'a> {
@@ -14,8 +16,14 @@ impl<'a> ManiaPerformanceCalculator<'a> {
         attrs: ManiaDifficultyAttributes,
         mods: &'a GameMods,
         state: ManiaScoreState,
+        trace: Trace<'a>,
     ) -> Self {
-        Self { attrs, mods, state }
+        Self {
+            attrs,
+            mods,
+            state,
+            trace,
+        }
     }
 }
 
@@ -24,15 +32,17 @@ impl ManiaPerformanceCalculator<'_> {
         let mut multiplier = 1.0;
 
         if self.mods.nf() {
-            multiplier *= 0.75;
+            multiplier *= self.trace.factor("total", "no fail", 0.75);
         }
 
         if self.mods.ez() {
-            multiplier *= 0.5;
+            multiplier *= self.trace.factor("total", "easy", 0.5);
         }
 
         let difficulty_value = self.compute_difficulty_value();
-        let pp = difficulty_value * multiplier;
+        let pp = self
+            .trace
+            .value("total", "pp", difficulty_value * multiplier);
 
         ManiaPerformanceAttributes {
             difficulty: self.attrs,
@@ -43,11 +53,27 @@ impl ManiaPerformanceCalculator<'_> {
 
     fn compute_difficulty_value(&self) -> f64 {
         // * Star rating to pp curve
-        8.0 * f64::powf(f64::max(self.attrs.stars - 0.15, 0.05), 2.2)
-             // * From 80% accuracy, 1/20th of total pp is awarded per additional 1% accuracy
-             * f64::max(0.0, 5.0 * self.calculate_custom_accuracy() - 4.0)
-             // * Length bonus, capped at 1500 notes
-             * (1.0 + 0.1 * f64::min(1.0, self.total_hits() / 1500.0))
+        let difficulty_value = self.trace.value(
+            "difficulty",
+            "difficulty to performance",
+            8.0 * f64::powf(f64::max(self.attrs.stars - 0.15, 0.05), 2.2),
+        );
+
+        // * From 80% accuracy, 1/20th of total pp is awarded per additional 1% accuracy
+        let accuracy_factor = self.trace.factor(
+            "difficulty",
+            "accuracy",
+            f64::max(0.0, 5.0 * self.calculate_custom_accuracy() - 4.0),
+        );
+
+        // * Length bonus, capped at 1500 notes
+        let length_bonus = self.trace.factor(
+            "difficulty",
+            "length bonus",
+            1.0 + 0.1 * f64::min(1.0, self.total_hits() / 1500.0),
+        );
+
+        difficulty_value * accuracy_factor * length_bonus
     }
 
     const fn total_hits(&self) -> f64 {
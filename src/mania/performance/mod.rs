@@ -5,7 +5,10 @@ use rosu_map::section::general::GameMode;
 use self::calculator::ManiaPerformanceCalculator;
 
 use crate::{
-    any::{Difficulty, HitResultPriority, IntoModePerformance, IntoPerformance},
+    any::{
+        trace::{self, Trace},
+        Difficulty, HitResultPriority, IntoModePerformance, IntoPerformance, PerformanceTrace,
+    },
     model::{mode::ConvertError, mods::GameMods},
     osu::OsuPerformance,
     util::map_or_attrs::MapOrAttrs,
@@ -786,7 +789,24 @@ impl<'map> ManiaPerformance<'map> {
     }
 
     /// Calculate all performance related values, including pp and stars.
-    pub fn calculate(mut self) -> Result<ManiaPerformanceAttributes, ConvertError> {
+    pub fn calculate(self) -> Result<ManiaPerformanceAttributes, ConvertError> {
+        self.calculate_inner(Trace::disabled())
+    }
+
+    /// Same as [`ManiaPerformance::calculate`] but also records each value
+    /// and factor of the pp formula in a [`PerformanceTrace`].
+    pub fn calculate_with_trace(
+        self,
+    ) -> Result<(ManiaPerformanceAttributes, PerformanceTrace), ConvertError> {
+        let (res, trace) = trace::traced(|trace| self.calculate_inner(trace));
+
+        res.map(|attrs| (attrs, trace))
+    }
+
+    fn calculate_inner(
+        mut self,
+        trace: Trace<'_>,
+    ) -> Result<ManiaPerformanceAttributes, ConvertError> {
         let state = self.generate_state()?;
 
         let attrs = match self.map_or_attrs {
@@ -794,7 +814,10 @@ impl<'map> ManiaPerformance<'map> {
             MapOrAttrs::Map(ref map) => self.difficulty.calculate_for_mode::<Mania>(map)?,
         };
 
-        Ok(ManiaPerformanceCalculator::new(attrs, self.difficulty.get_mods(), state).calculate())
+        Ok(
+            ManiaPerformanceCalculator::new(attrs, self.difficulty.get_mods(), state, trace)
+                .calculate(),
+        )
     }
 
     pub(crate) const fn from_map_or_attrs(map_or_attrs: MapOrAttrs<'map, Mania>) -> Self {
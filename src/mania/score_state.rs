@@ -1,5 +1,6 @@
 /// Aggregation for a score's current state.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ManiaScoreState {
     /// Amount of current 320s.
     pub n320: u32,
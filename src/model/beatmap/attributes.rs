@@ -242,20 +242,10 @@ impl BeatmapAttributesBuilder {
         let ar_clock_rate = if self.ar.with_mods() { 1.0 } else { clock_rate };
         let od_clock_rate = if self.od.with_mods() { 1.0 } else { clock_rate };
 
-        let mod_mult = |val: f32| {
-            if mods.hr() {
-                (val * 1.4).min(10.0)
-            } else if mods.ez() {
-                val * 0.5
-            } else {
-                val
-            }
-        };
-
         let raw_ar = if self.ar.with_mods() {
             self.ar.value(mods, GameMods::ar)
         } else {
-            mod_mult(self.ar.value(mods, GameMods::ar))
+            mod_mult(self.ar.value(mods, GameMods::ar), mods)
         };
 
         let preempt = difficulty_range(f64::from(raw_ar), AR_WINDOWS) / ar_clock_rate;
@@ -263,11 +253,7 @@ impl BeatmapAttributesBuilder {
         // OD
         let (great, ok, meh) = match self.mode {
             GameMode::Osu | GameMode::Catch => {
-                let raw_od = if self.od.with_mods() {
-                    self.od.value(mods, GameMods::od)
-                } else {
-                    mod_mult(self.od.value(mods, GameMods::od))
-                };
+                let raw_od = self.mods_adjusted_od();
 
                 let great = difficulty_range(f64::from(raw_od), OSU_GREAT) / od_clock_rate;
                 let ok = difficulty_range(f64::from(raw_od), OSU_OK) / od_clock_rate;
@@ -276,11 +262,7 @@ impl BeatmapAttributesBuilder {
                 (great, Some(ok), Some(meh))
             }
             GameMode::Taiko => {
-                let raw_od = if self.od.with_mods() {
-                    self.od.value(mods, GameMods::od)
-                } else {
-                    mod_mult(self.od.value(mods, GameMods::od))
-                };
+                let raw_od = self.mods_adjusted_od();
 
                 // Taiko hit windows are floored and shifted before clock-rate adjustment.
                 // See `TaikoHitWindows.SetDifficulty` in lazer.
@@ -381,9 +363,25 @@ impl BeatmapAttributesBuilder {
         }
     }
 
+    /// The overall difficulty after applying HR or EZ but without
+    /// accounting for the clock rate.
+    pub(crate) fn mods_adjusted_od(&self) -> f32 {
+        let od = self.od.value(&self.mods, GameMods::od);
+
+        if self.od.with_mods() {
+            od
+        } else {
+            mod_mult(od, &self.mods)
+        }
+    }
+
     pub(crate) const fn osu_great_hit_window_to_od(hit_window: f64) -> f64 {
         (OSU_GREAT.min - hit_window) / 6.0
     }
+
+    pub(crate) const fn osu_od_to_great_hit_window(od: f64) -> f64 {
+        OSU_GREAT.min - 6.0 * od
+    }
 }
 
 impl From<&Beatmap> for BeatmapAttributesBuilder {
@@ -406,6 +404,17 @@ fn difficulty_range(difficulty: f64, windows: GameModeHitWindows) -> f64 {
     }
 }
 
+/// Applies the HR or EZ multiplier to AR or OD.
+fn mod_mult(value: f32, mods: &GameMods) -> f32 {
+    if mods.hr() {
+        (value * 1.4).min(10.0)
+    } else if mods.ez() {
+        value * 0.5
+    } else {
+        value
+    }
+}
+
 impl Default for BeatmapAttributesBuilder {
     fn default() -> Self {
         Self::new()
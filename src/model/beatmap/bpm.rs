@@ -5,8 +5,12 @@ use crate::model::{control_point::TimingPoint, hit_object::HitObject};
 pub fn bpm(last_hit_object: Option<&HitObject>, timing_points: &[TimingPoint]) -> f64 {
     // This is incorrect if the last object is a slider since there
     // is no reasonable way to get the slider end time at this point.
-    let last_time = last_hit_object
-        .map(HitObject::end_time)
+    most_common_bpm(last_hit_object.map(HitObject::end_time), timing_points)
+}
+
+/// The BPM that is active for the longest time until `last_time`.
+pub fn most_common_bpm(last_time: Option<f64>, timing_points: &[TimingPoint]) -> f64 {
+    let last_time = last_time
         .or_else(|| timing_points.last().map(|t| t.time))
         .unwrap_or(0.0);
 
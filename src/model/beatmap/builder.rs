@@ -0,0 +1,769 @@
+use std::{error, fmt, ops::RangeInclusive};
+
+use rosu_map::{
+    section::{general::GameMode, hit_objects::hit_samples::HitSoundType},
+    DecodeState, LATEST_FORMAT_VERSION,
+};
+
+use crate::{
+    model::hit_object::{
+        HitObject, HitObjectKind, HoldNote, PathControlPoint, PathType, Pos, Slider, Spinner,
+    },
+    util::float_ext::FloatExt,
+};
+
+use super::{
+    decode::{BeatmapState, MAX_COORDINATE_VALUE},
+    Beatmap, DEFAULT_SLIDER_LENIENCY,
+};
+
+/// The maximum amount of repeats of a slider, see `LegacyBeatmapDecoder`.
+const MAX_REPEATS: usize = 8999;
+
+/// The limits of approach rate, circle size, drain rate, and overall
+/// difficulty.
+const ATTRIBUTE_LIMITS: RangeInclusive<f32> = 0.0..=10.0;
+
+/// The limits of the circle size of osu!mania maps, i.e. the amount of keys.
+const MANIA_KEY_LIMITS: RangeInclusive<f32> = 1.0..=20.0;
+
+/// The limits of the slider multiplier, see `LegacyBeatmapDecoder`.
+const SLIDER_MULTIPLIER_LIMITS: RangeInclusive<f64> = 0.4..=3.6;
+
+/// The limits of the slider tick rate, see `LegacyBeatmapDecoder`.
+const SLIDER_TICK_RATE_LIMITS: RangeInclusive<f64> = 0.5..=8.0;
+
+/// The position of spinners, i.e. the playfield's center.
+const SPINNER_POS: Pos = Pos { x: 256.0, y: 192.0 };
+
+/// A builder to construct a [`Beatmap`] without a `.osu` file.
+///
+/// Every helper validates its arguments. The first invalid argument is
+/// remembered and returned by [`BeatmapBuilder::build`].
+///
+/// Control points and hit objects may be added in any order; they are sorted
+/// the same way as when decoding a `.osu` file.
+///
+/// # Example
+///
+/// ```
+/// use mames_pp::{
+///     model::{beatmap::BeatmapBuilder, hit_object::{PathType, Pos}, mode::GameMode},
+///     Difficulty,
+/// };
+///
+/// let map = BeatmapBuilder::new(GameMode::Osu)
+///     .ar(9.0)
+///     .od(8.0)
+///     .timing_point(0.0, 300.0)
+///     .slider_velocity(1200.0, 1.5)
+///     .circle(Pos::new(100.0, 100.0), 0.0)
+///     .circle(Pos::new(300.0, 100.0), 300.0)
+///     .slider(
+///         Pos::new(300.0, 300.0),
+///         600.0,
+///         PathType::BEZIER,
+///         &[Pos::new(400.0, 250.0), Pos::new(450.0, 300.0)],
+///         1,
+///         None,
+///     )
+///     .spinner(1200.0, 3000.0)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(map.hit_objects.len(), 4);
+/// assert_eq!(map.hit_sounds.len(), 4);
+///
+/// let stars = Difficulty::new().calculate(&map).stars();
+/// assert!(stars > 0.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[must_use]
+pub struct BeatmapBuilder {
+    mode: GameMode,
+    ar: f32,
+    cs: f32,
+    hp: f32,
+    od: f32,
+    slider_multiplier: f64,
+    slider_tick_rate: f64,
+    stack_leniency: f32,
+    timing_lines: Vec<TimingLine>,
+    hit_objects: Vec<HitObject>,
+    hit_sounds: Vec<HitSoundType>,
+    error: Option<BuildBeatmapError>,
+}
+
+/// The equivalent of a line in the `[TimingPoints]` section.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct TimingLine {
+    time: f64,
+    beat_len: f64,
+    timing_change: bool,
+}
+
+impl BeatmapBuilder {
+    /// Create a new [`BeatmapBuilder`] for the given mode.
+    ///
+    /// Attributes are set to `5.0` and all other values to the defaults of a
+    /// `.osu` file.
+    pub const fn new(mode: GameMode) -> Self {
+        Self {
+            mode,
+            ar: 5.0,
+            cs: 5.0,
+            hp: 5.0,
+            od: 5.0,
+            slider_multiplier: 1.4,
+            slider_tick_rate: 1.0,
+            stack_leniency: DEFAULT_SLIDER_LENIENCY,
+            timing_lines: Vec::new(),
+            hit_objects: Vec::new(),
+            hit_sounds: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Specify the approach rate.
+    ///
+    /// Must lie within `0.0..=10.0`.
+    pub fn ar(mut self, ar: f32) -> Self {
+        let res = validate_range(ar, ATTRIBUTE_LIMITS, BuildBeatmapError::ApproachRate);
+
+        if self.check(res) {
+            self.ar = ar;
+        }
+
+        self
+    }
+
+    /// Specify the circle size.
+    ///
+    /// Must lie within `0.0..=10.0`. For osu!mania this is the amount of keys
+    /// which must lie within `1.0..=20.0`.
+    pub fn cs(mut self, cs: f32) -> Self {
+        let limits = if self.mode == GameMode::Mania {
+            MANIA_KEY_LIMITS
+        } else {
+            ATTRIBUTE_LIMITS
+        };
+
+        let res = validate_range(cs, limits, BuildBeatmapError::CircleSize);
+
+        if self.check(res) {
+            self.cs = cs;
+        }
+
+        self
+    }
+
+    /// Specify the drain rate.
+    ///
+    /// Must lie within `0.0..=10.0`.
+    pub fn hp(mut self, hp: f32) -> Self {
+        let res = validate_range(hp, ATTRIBUTE_LIMITS, BuildBeatmapError::DrainRate);
+
+        if self.check(res) {
+            self.hp = hp;
+        }
+
+        self
+    }
+
+    /// Specify the overall difficulty.
+    ///
+    /// Must lie within `0.0..=10.0`.
+    pub fn od(mut self, od: f32) -> Self {
+        let res = validate_range(od, ATTRIBUTE_LIMITS, BuildBeatmapError::OverallDifficulty);
+
+        if self.check(res) {
+            self.od = od;
+        }
+
+        self
+    }
+
+    /// Specify the base slider velocity in hundreds of osu!pixels per beat.
+    ///
+    /// Must lie within `0.4..=3.6`.
+    pub fn slider_multiplier(mut self, slider_multiplier: f64) -> Self {
+        let res = validate_range(
+            slider_multiplier,
+            SLIDER_MULTIPLIER_LIMITS,
+            BuildBeatmapError::SliderMultiplier,
+        );
+
+        if self.check(res) {
+            self.slider_multiplier = slider_multiplier;
+        }
+
+        self
+    }
+
+    /// Specify the amount of slider ticks per beat.
+    ///
+    /// Must lie within `0.5..=8.0`.
+    pub fn slider_tick_rate(mut self, slider_tick_rate: f64) -> Self {
+        let res = validate_range(
+            slider_tick_rate,
+            SLIDER_TICK_RATE_LIMITS,
+            BuildBeatmapError::SliderTickRate,
+        );
+
+        if self.check(res) {
+            self.slider_tick_rate = slider_tick_rate;
+        }
+
+        self
+    }
+
+    /// Specify the stack leniency.
+    ///
+    /// Must lie within `0.0..=1.0`.
+    pub fn stack_leniency(mut self, stack_leniency: f32) -> Self {
+        let res = validate_range(stack_leniency, 0.0..=1.0, BuildBeatmapError::StackLeniency);
+
+        if self.check(res) {
+            self.stack_leniency = stack_leniency;
+        }
+
+        self
+    }
+
+    /// Add a timing point i.e. an uninherited control point.
+    ///
+    /// `beat_len` is in milliseconds and must be positive.
+    pub fn timing_point(mut self, time: f64, beat_len: f64) -> Self {
+        let validate = || {
+            validate_time(time)?;
+
+            if beat_len.is_finite() && beat_len > 0.0 {
+                Ok(())
+            } else {
+                Err(BuildBeatmapError::BeatLength(beat_len))
+            }
+        };
+
+        if self.check(validate()) {
+            self.timing_lines.push(TimingLine {
+                time,
+                beat_len,
+                timing_change: true,
+            });
+        }
+
+        self
+    }
+
+    /// Add a slider velocity point i.e. an inherited control point.
+    ///
+    /// `multiplier` must be positive, `1.0` being the base velocity.
+    pub fn slider_velocity(mut self, time: f64, multiplier: f64) -> Self {
+        let validate = || {
+            validate_time(time)?;
+
+            if multiplier.is_finite() && multiplier > 0.0 {
+                Ok(())
+            } else {
+                Err(BuildBeatmapError::SliderVelocity(multiplier))
+            }
+        };
+
+        if self.check(validate()) {
+            self.timing_lines.push(TimingLine {
+                time,
+                beat_len: -100.0 / multiplier,
+                timing_change: false,
+            });
+        }
+
+        self
+    }
+
+    /// Add a circle.
+    ///
+    /// For osu!taiko this is a hit and for osu!mania a note whose column is
+    /// determined by `pos.x`.
+    pub fn circle(mut self, pos: Pos, start_time: f64) -> Self {
+        let validate = || {
+            validate_time(start_time)?;
+
+            validate_pos(pos)
+        };
+
+        if self.check(validate()) {
+            self.push(pos, start_time, HitObjectKind::Circle);
+        }
+
+        self
+    }
+
+    /// Add a slider.
+    ///
+    /// `points` are the absolute positions of the control points that follow
+    /// the slider head at `pos`; they form a single segment of the given
+    /// [`PathType`]. Just like in `.osu` files, a perfect curve is only
+    /// kept for exactly two non-collinear points.
+    ///
+    /// `repeats` is the amount of times the slider turns around and
+    /// `pixel_len` the length of the slider in osu!pixels. If `pixel_len` is
+    /// `None`, the length of the path is used.
+    pub fn slider(
+        mut self,
+        pos: Pos,
+        start_time: f64,
+        path_type: PathType,
+        points: &[Pos],
+        repeats: usize,
+        pixel_len: Option<f64>,
+    ) -> Self {
+        let validate = || {
+            validate_time(start_time)?;
+            validate_pos(pos)?;
+
+            if points.is_empty() {
+                return Err(BuildBeatmapError::EmptySliderPath);
+            }
+
+            points.iter().copied().try_for_each(validate_pos)?;
+
+            if repeats > MAX_REPEATS {
+                return Err(BuildBeatmapError::RepeatCount(repeats));
+            }
+
+            match pixel_len {
+                Some(len)
+                    if !(f64::MIN_POSITIVE..=f64::from(MAX_COORDINATE_VALUE)).contains(&len) =>
+                {
+                    Err(BuildBeatmapError::SliderLength(len))
+                }
+                _ => Ok(()),
+            }
+        };
+
+        if !self.check(validate()) {
+            return self;
+        }
+
+        let path_type = match points {
+            _ if path_type != PathType::PERFECT_CURVE => path_type,
+            &[a, b] if is_linear(Pos::default(), a - pos, b - pos) => PathType::LINEAR,
+            [_, _] => path_type,
+            _ => PathType::BEZIER,
+        };
+
+        let mut head = PathControlPoint::default();
+        head.path_type = Some(path_type);
+
+        let control_points = Some(head)
+            .into_iter()
+            .chain(
+                points
+                    .iter()
+                    .map(|&point| PathControlPoint::new(point - pos)),
+            )
+            .collect();
+
+        let slider = Slider {
+            expected_dist: pixel_len,
+            repeats,
+            control_points,
+            node_sounds: vec![HitSoundType::default(); repeats + 2].into_boxed_slice(),
+        };
+
+        self.push(pos, start_time, HitObjectKind::Slider(slider));
+
+        self
+    }
+
+    /// Add a spinner.
+    ///
+    /// For osu!mania this is converted into a hold note.
+    pub fn spinner(mut self, start_time: f64, end_time: f64) -> Self {
+        if self.check(validate_duration(start_time, end_time)) {
+            let spinner = Spinner {
+                duration: end_time - start_time,
+            };
+
+            self.push(SPINNER_POS, start_time, HitObjectKind::Spinner(spinner));
+        }
+
+        self
+    }
+
+    /// Add an osu!mania hold note whose column is determined by `x`.
+    ///
+    /// Only available for osu!mania maps; use [`BeatmapBuilder::spinner`] or
+    /// [`BeatmapBuilder::slider`] for the other modes.
+    pub fn hold(mut self, x: f32, start_time: f64, end_time: f64) -> Self {
+        let pos = Pos::new(x, SPINNER_POS.y);
+        let mode = self.mode;
+
+        let validate = || {
+            if mode != GameMode::Mania {
+                return Err(BuildBeatmapError::HoldNoteMode(mode));
+            }
+
+            validate_duration(start_time, end_time)?;
+
+            validate_pos(pos)
+        };
+
+        if self.check(validate()) {
+            let hold = HoldNote {
+                duration: end_time - start_time,
+            };
+
+            self.push(pos, start_time, HitObjectKind::Hold(hold));
+        }
+
+        self
+    }
+
+    /// Specify the [`HitSoundType`] of the most recently added hit object.
+    ///
+    /// For sliders, the sound is applied to all nodes. Relevant for
+    /// osu!taiko, e.g. `HitSoundType::CLAP` turns a hit into a kat.
+    pub fn hit_sound(mut self, sound: HitSoundType) -> Self {
+        let Some(last) = self.hit_sounds.last_mut() else {
+            self.check(Err(BuildBeatmapError::NoHitObject));
+
+            return self;
+        };
+
+        *last = sound;
+
+        if let Some(HitObject {
+            kind: HitObjectKind::Slider(slider),
+            ..
+        }) = self.hit_objects.last_mut()
+        {
+            slider.node_sounds.fill(sound);
+        }
+
+        self
+    }
+
+    /// Build the [`Beatmap`].
+    ///
+    /// Returns the first error of any helper that received invalid
+    /// arguments.
+    pub fn build(self) -> Result<Beatmap, BuildBeatmapError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        let mut state = BeatmapState::create(LATEST_FORMAT_VERSION);
+        state.set_mode(self.mode);
+
+        let mut timing_lines = self.timing_lines;
+        timing_lines.sort_by(|a, b| a.time.total_cmp(&b.time));
+
+        for line in timing_lines {
+            state.add_timing_line(line.time, line.beat_len, line.timing_change, false);
+        }
+
+        for (h, sound) in self.hit_objects.into_iter().zip(self.hit_sounds) {
+            state.push_hit_object(h, sound);
+        }
+
+        Ok(Beatmap {
+            stack_leniency: self.stack_leniency,
+            ar: self.ar,
+            cs: self.cs,
+            hp: self.hp,
+            od: self.od,
+            slider_multiplier: self.slider_multiplier,
+            slider_tick_rate: self.slider_tick_rate,
+            ..Beatmap::from(state)
+        })
+    }
+
+    /// Remembers the error, if any, and returns whether the helper should
+    /// proceed.
+    fn check(&mut self, res: Result<(), BuildBeatmapError>) -> bool {
+        match res {
+            Ok(()) => true,
+            Err(err) => {
+                self.error.get_or_insert(err);
+
+                false
+            }
+        }
+    }
+
+    fn push(&mut self, pos: Pos, start_time: f64, kind: HitObjectKind) {
+        self.hit_objects.push(HitObject {
+            pos,
+            start_time,
+            kind,
+        });
+
+        self.hit_sounds.push(HitSoundType::default());
+    }
+}
+
+fn validate_time(time: f64) -> Result<(), BuildBeatmapError> {
+    if time.is_finite() {
+        Ok(())
+    } else {
+        Err(BuildBeatmapError::Time(time))
+    }
+}
+
+fn validate_range<T: PartialOrd>(
+    value: T,
+    limits: RangeInclusive<T>,
+    err: fn(T) -> BuildBeatmapError,
+) -> Result<(), BuildBeatmapError> {
+    if limits.contains(&value) {
+        Ok(())
+    } else {
+        Err(err(value))
+    }
+}
+
+fn validate_pos(pos: Pos) -> Result<(), BuildBeatmapError> {
+    let limits = -(MAX_COORDINATE_VALUE as f32)..=MAX_COORDINATE_VALUE as f32;
+
+    if limits.contains(&pos.x) && limits.contains(&pos.y) {
+        Ok(())
+    } else {
+        Err(BuildBeatmapError::Position { x: pos.x, y: pos.y })
+    }
+}
+
+fn validate_duration(start_time: f64, end_time: f64) -> Result<(), BuildBeatmapError> {
+    validate_time(start_time)?;
+    validate_time(end_time)?;
+
+    if end_time < start_time {
+        Err(BuildBeatmapError::EndTime {
+            start_time,
+            end_time,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+// See `LegacyBeatmapDecoder.isLinear`
+fn is_linear(p0: Pos, p1: Pos, p2: Pos) -> bool {
+    ((p1.y - p0.y) * (p2.x - p0.x)).eq((p1.x - p0.x) * (p2.y - p0.y))
+}
+
+/// All the ways that [`BeatmapBuilder::build`] can fail.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BuildBeatmapError {
+    ApproachRate(f32),
+    /// Beat lengths must be positive.
+    BeatLength(f64),
+    CircleSize(f32),
+    DrainRate(f32),
+    /// Sliders require at least one control point besides their head.
+    EmptySliderPath,
+    /// The end time of a spinner or hold note lies before its start time.
+    EndTime {
+        start_time: f64,
+        end_time: f64,
+    },
+    /// [`BeatmapBuilder::hold`] was used for a map that is not osu!mania.
+    HoldNoteMode(GameMode),
+    /// [`BeatmapBuilder::hit_sound`] was used before adding a hit object.
+    NoHitObject,
+    OverallDifficulty(f32),
+    /// Coordinates must not exceed the limits of `.osu` files.
+    Position {
+        x: f32,
+        y: f32,
+    },
+    RepeatCount(usize),
+    SliderLength(f64),
+    SliderMultiplier(f64),
+    SliderTickRate(f64),
+    /// Slider velocity multipliers must be positive.
+    SliderVelocity(f64),
+    StackLeniency(f32),
+    /// Times must be finite.
+    Time(f64),
+}
+
+impl error::Error for BuildBeatmapError {}
+
+impl fmt::Display for BuildBeatmapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ApproachRate(ar) => write!(f, "invalid approach rate {ar}"),
+            Self::BeatLength(beat_len) => write!(f, "invalid beat length {beat_len}"),
+            Self::CircleSize(cs) => write!(f, "invalid circle size {cs}"),
+            Self::DrainRate(hp) => write!(f, "invalid drain rate {hp}"),
+            Self::EmptySliderPath => f.write_str("slider path has no control points"),
+            Self::EndTime {
+                start_time,
+                end_time,
+            } => write!(f, "end time {end_time} lies before start time {start_time}"),
+            Self::HoldNoteMode(mode) => {
+                write!(f, "hold notes are not available for {mode:?} maps")
+            }
+            Self::NoHitObject => f.write_str("no hit object to apply the hit sound to"),
+            Self::OverallDifficulty(od) => write!(f, "invalid overall difficulty {od}"),
+            Self::Position { x, y } => write!(f, "invalid position ({x}, {y})"),
+            Self::RepeatCount(repeats) => write!(f, "repeat count {repeats} is way too high"),
+            Self::SliderLength(len) => write!(f, "invalid slider length {len}"),
+            Self::SliderMultiplier(multiplier) => {
+                write!(f, "invalid slider multiplier {multiplier}")
+            }
+            Self::SliderTickRate(tick_rate) => write!(f, "invalid slider tick rate {tick_rate}"),
+            Self::SliderVelocity(multiplier) => {
+                write!(f, "invalid slider velocity multiplier {multiplier}")
+            }
+            Self::StackLeniency(leniency) => write!(f, "invalid stack leniency {leniency}"),
+            Self::Time(time) => write!(f, "invalid time {time}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_decoded_map() {
+        let bytes = b"osu file format v14
+
+[General]
+Mode: 0
+
+[Difficulty]
+HPDrainRate:6
+CircleSize:4
+OverallDifficulty:8
+ApproachRate:9
+SliderMultiplier:1.8
+SliderTickRate:1
+
+[TimingPoints]
+0,300,4,2,0,100,1,0
+1000,-50,4,2,0,100,0,0
+
+[HitObjects]
+300,100,300,1,2,0:0:0:0:
+100,100,0,1,0,0:0:0:0:
+200,200,1200,2,0,P|300:100|400:200,2,250
+256,192,2400,12,0,3000,0:0:0:0:
+";
+
+        let decoded = Beatmap::from_bytes(bytes).unwrap();
+
+        let built = BeatmapBuilder::new(GameMode::Osu)
+            .hp(6.0)
+            .cs(4.0)
+            .od(8.0)
+            .ar(9.0)
+            .slider_multiplier(1.8)
+            .spinner(2400.0, 3000.0)
+            .slider_velocity(1000.0, 2.0)
+            .circle(Pos::new(300.0, 100.0), 300.0)
+            .hit_sound(HitSoundType::WHISTLE)
+            .circle(Pos::new(100.0, 100.0), 0.0)
+            .slider(
+                Pos::new(200.0, 200.0),
+                1200.0,
+                PathType::PERFECT_CURVE,
+                &[Pos::new(300.0, 100.0), Pos::new(400.0, 200.0)],
+                1,
+                Some(250.0),
+            )
+            .timing_point(0.0, 300.0)
+            .build()
+            .unwrap();
+
+        assert_eq!(built.timing_points, decoded.timing_points);
+        assert_eq!(built.difficulty_points, decoded.difficulty_points);
+        assert_eq!(built.effect_points, decoded.effect_points);
+        assert_eq!(built.hit_objects, decoded.hit_objects);
+        assert_eq!(built.hit_sounds, decoded.hit_sounds);
+        assert_eq!(
+            (built.ar, built.slider_multiplier),
+            (decoded.ar, decoded.slider_multiplier)
+        );
+    }
+
+    #[test]
+    fn reports_first_error() {
+        let err = BeatmapBuilder::new(GameMode::Osu)
+            .hit_sound(HitSoundType::CLAP)
+            .timing_point(0.0, -1.0)
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err, BuildBeatmapError::NoHitObject);
+
+        let err = BeatmapBuilder::new(GameMode::Mania)
+            .hold(64.0, 1000.0, 500.0)
+            .slider(Pos::new(0.0, 0.0), 0.0, PathType::LINEAR, &[], 0, None)
+            .build()
+            .unwrap_err();
+
+        assert!(matches!(err, BuildBeatmapError::EndTime { .. }));
+
+        let err = BeatmapBuilder::new(GameMode::Osu)
+            .circle(Pos::new(f32::NAN, 0.0), 0.0)
+            .build()
+            .unwrap_err();
+
+        assert!(matches!(err, BuildBeatmapError::Position { .. }));
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        let build = |builder: BeatmapBuilder| builder.build().unwrap_err();
+
+        let osu = BeatmapBuilder::new(GameMode::Osu);
+
+        assert!(matches!(
+            build(osu.clone().ar(f32::NAN)),
+            BuildBeatmapError::ApproachRate(_)
+        ));
+        assert_eq!(
+            build(osu.clone().cs(-1.0)),
+            BuildBeatmapError::CircleSize(-1.0)
+        );
+        assert_eq!(
+            build(osu.clone().hp(11.0)),
+            BuildBeatmapError::DrainRate(11.0)
+        );
+        assert!(matches!(
+            build(osu.clone().od(f32::INFINITY)),
+            BuildBeatmapError::OverallDifficulty(_)
+        ));
+        assert_eq!(
+            build(osu.clone().slider_multiplier(0.0)),
+            BuildBeatmapError::SliderMultiplier(0.0)
+        );
+        assert_eq!(
+            build(osu.clone().slider_tick_rate(-1.0)),
+            BuildBeatmapError::SliderTickRate(-1.0)
+        );
+        assert!(matches!(
+            build(osu.clone().stack_leniency(f32::NAN)),
+            BuildBeatmapError::StackLeniency(_)
+        ));
+        assert_eq!(
+            build(osu.hold(64.0, 0.0, 500.0)),
+            BuildBeatmapError::HoldNoteMode(GameMode::Osu)
+        );
+
+        let mania = BeatmapBuilder::new(GameMode::Mania)
+            .cs(7.0)
+            .hold(64.0, 0.0, 500.0)
+            .build()
+            .unwrap();
+
+        assert_eq!(mania.hit_objects.len(), 1);
+        assert_eq!(
+            build(BeatmapBuilder::new(GameMode::Mania).cs(0.0)),
+            BuildBeatmapError::CircleSize(0.0)
+        );
+    }
+}
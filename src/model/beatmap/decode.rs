@@ -25,7 +25,10 @@ use crate::{
     util::{float_ext::FloatExt, hint::unlikely, sort},
 };
 
-use super::{Beatmap, DEFAULT_SLIDER_LENIENCY};
+#[cfg(feature = "metadata")]
+use rosu_map::section::metadata::MetadataKey;
+
+use super::{Beatmap, BeatmapMetadata, DEFAULT_SLIDER_LENIENCY};
 
 /// The state of a [`Beatmap`] for [`DecodeBeatmap`].
 pub struct BeatmapState {
@@ -36,10 +39,12 @@ pub struct BeatmapState {
     difficulty: Difficulty,
     breaks: Vec<BreakPeriod>,
     timing_points: Vec<TimingPoint>,
+    clamped_timing_points: Vec<TimingPoint>,
     difficulty_points: Vec<DifficultyPoint>,
     effect_points: Vec<EffectPoint>,
     hit_objects: Vec<HitObject>,
     hit_sounds: Vec<HitSoundType>,
+    metadata: BeatmapMetadata,
 
     pending_control_points_time: f64,
     pending_timing_point: Option<TimingPoint>,
@@ -66,6 +71,59 @@ impl BeatmapState {
         self.pending_control_points_time = time;
     }
 
+    /// Adds the control points of a `[TimingPoints]` line.
+    pub(super) fn add_timing_line(
+        &mut self,
+        time: f64,
+        beat_len: f64,
+        timing_change: bool,
+        kiai: bool,
+    ) {
+        let speed_multiplier = if beat_len < 0.0 {
+            100.0 / -beat_len
+        } else {
+            1.0
+        };
+
+        if timing_change {
+            let timing = TimingPoint::new(time, beat_len);
+
+            // fork 独自: サニティチェック用に clamp 前の beat length を残す
+            if timing.beat_len.not_eq(beat_len) {
+                self.clamped_timing_points
+                    .push(TimingPoint { time, beat_len });
+            }
+
+            self.add_pending_point(time, timing, timing_change);
+        }
+
+        let difficulty = DifficultyPoint::new(time, beat_len, speed_multiplier);
+        self.add_pending_point(time, difficulty, timing_change);
+
+        let mut effect = EffectPoint::new(time, kiai);
+
+        if matches!(self.mode, GameMode::Taiko | GameMode::Mania) {
+            effect.scroll_speed = speed_multiplier.clamp(0.01, 10.0);
+        }
+
+        self.add_pending_point(time, effect, timing_change);
+
+        self.pending_control_points_time = time;
+    }
+
+    /// fork 独自: [`BeatmapBuilder`] 用。
+    ///
+    /// [`BeatmapBuilder`]: super::BeatmapBuilder
+    pub(super) const fn set_mode(&mut self, mode: GameMode) {
+        self.mode = mode;
+    }
+
+    /// `hit_sounds` は常に `hit_objects` と同じ長さに保たれる。
+    pub(super) fn push_hit_object(&mut self, h: HitObject, sound: HitSoundType) {
+        self.hit_objects.push(h);
+        self.hit_sounds.push(sound);
+    }
+
     fn flush_pending_points(&mut self) {
         if let Some(point) = self.pending_timing_point.take() {
             self.add_control_point(point);
@@ -256,10 +314,12 @@ impl DecodeState for BeatmapState {
             difficulty: Difficulty::default(),
             breaks: Vec::new(),
             timing_points: Vec::with_capacity(1),
+            clamped_timing_points: Vec::new(),
             difficulty_points: Vec::new(),
             effect_points: Vec::with_capacity(32),
             hit_objects: Vec::with_capacity(512),
             hit_sounds: Vec::with_capacity(512),
+            metadata: BeatmapMetadata::default(),
             pending_control_points_time: 0.0,
             pending_timing_point: None,
             pending_difficulty_point: None,
@@ -311,10 +371,12 @@ impl From<BeatmapState> for Beatmap {
             slider_tick_rate,
             breaks: state.breaks,
             timing_points: state.timing_points,
+            clamped_timing_points: state.clamped_timing_points,
             difficulty_points: state.difficulty_points,
             effect_points: state.effect_points,
             hit_objects: state.hit_objects,
             hit_sounds: state.hit_sounds,
+            metadata: state.metadata,
         }
     }
 }
@@ -423,7 +485,7 @@ impl From<ParseDifficultyError> for ParseBeatmapError {
     }
 }
 
-const MAX_COORDINATE_VALUE: i32 = 131_072;
+pub(super) const MAX_COORDINATE_VALUE: i32 = 131_072;
 
 impl DecodeBeatmap for Beatmap {
     type Error = ParseBeatmapError;
@@ -447,10 +509,34 @@ impl DecodeBeatmap for Beatmap {
         Ok(())
     }
 
+    #[cfg(not(feature = "metadata"))]
     fn parse_metadata(_: &mut Self::State, _: &str) -> Result<(), Self::Error> {
         Ok(())
     }
 
+    #[cfg(feature = "metadata")]
+    fn parse_metadata(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        let Ok(KeyValue { key, value }) = KeyValue::parse(line.trim_comment()) else {
+            return Ok(());
+        };
+
+        let metadata = &mut state.metadata;
+
+        match key {
+            MetadataKey::Title => metadata.title = value.to_owned(),
+            MetadataKey::TitleUnicode => metadata.title_unicode = value.to_owned(),
+            MetadataKey::Artist => metadata.artist = value.to_owned(),
+            MetadataKey::ArtistUnicode => metadata.artist_unicode = value.to_owned(),
+            MetadataKey::Creator => metadata.creator = value.to_owned(),
+            MetadataKey::Version => metadata.version = value.to_owned(),
+            MetadataKey::BeatmapID => metadata.beatmap_id = value.parse_num()?,
+            MetadataKey::BeatmapSetID => metadata.beatmap_set_id = value.parse_num()?,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
     fn parse_difficulty(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
         let Ok(KeyValue { key, value }) = KeyValue::parse(line.trim_comment()) else {
             return Ok(());
@@ -526,12 +612,6 @@ impl DecodeBeatmap for Beatmap {
             return Err(ParseNumberError::NumberOverflow.into());
         }
 
-        let speed_multiplier = if beat_len < 0.0 {
-            100.0 / -beat_len
-        } else {
-            1.0
-        };
-
         if let Some(numerator) = split.next() {
             if unlikely(i32::parse(numerator)? < 1) {
                 return Err(ParseBeatmapError::TimeSignature);
@@ -552,27 +632,11 @@ impl DecodeBeatmap for Beatmap {
             .transpose()?
             .is_some_and(|flags| flags.has_flag(EffectFlags::KIAI));
 
-        if timing_change {
-            if unlikely(beat_len.is_nan()) {
-                return Err(ParseBeatmapError::TimingControlPointNaN);
-            }
-
-            let timing = TimingPoint::new(time, beat_len);
-            state.add_pending_point(time, timing, timing_change);
+        if unlikely(timing_change && beat_len.is_nan()) {
+            return Err(ParseBeatmapError::TimingControlPointNaN);
         }
 
-        let difficulty = DifficultyPoint::new(time, beat_len, speed_multiplier);
-        state.add_pending_point(time, difficulty, timing_change);
-
-        let mut effect = EffectPoint::new(time, kiai);
-
-        if matches!(state.mode, GameMode::Taiko | GameMode::Mania) {
-            effect.scroll_speed = speed_multiplier.clamp(0.01, 10.0);
-        }
-
-        state.add_pending_point(time, effect, timing_change);
-
-        state.pending_control_points_time = time;
+        state.add_timing_line(time, beat_len, timing_change, kiai);
 
         Ok(())
     }
@@ -715,12 +779,13 @@ impl DecodeBeatmap for Beatmap {
             return Err(ParseBeatmapError::UnknownHitObjectType);
         };
 
-        state.hit_objects.push(HitObject {
+        let h = HitObject {
             pos,
             start_time,
             kind,
-        });
-        state.hit_sounds.push(sound);
+        };
+
+        state.push_hit_object(h, sound);
 
         Ok(())
     }
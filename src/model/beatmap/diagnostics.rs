@@ -0,0 +1,390 @@
+use std::{error, fmt, fs, io, path::Path};
+
+use rosu_map::{DecodeBeatmap, DecodeState};
+
+use super::{
+    decode::{BeatmapState, ParseBeatmapError},
+    Beatmap,
+};
+
+/// How [`Beatmap::from_bytes_checked`] handles lines that cannot be decoded.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum DecodeMode {
+    /// Skip invalid lines, just like [`Beatmap::from_bytes`], but report
+    /// them.
+    #[default]
+    Lenient,
+    /// Reject the map if any line is invalid.
+    Strict,
+}
+
+/// A section of a `.osu` file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BeatmapSection {
+    General,
+    Editor,
+    Metadata,
+    Difficulty,
+    Events,
+    TimingPoints,
+    Colors,
+    HitObjects,
+    Variables,
+    CatchTheBeat,
+    Mania,
+}
+
+impl BeatmapSection {
+    /// The name of the section as written in the file.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::General => "General",
+            Self::Editor => "Editor",
+            Self::Metadata => "Metadata",
+            Self::Difficulty => "Difficulty",
+            Self::Events => "Events",
+            Self::TimingPoints => "TimingPoints",
+            Self::Colors => "Colours",
+            Self::HitObjects => "HitObjects",
+            Self::Variables => "Variables",
+            Self::CatchTheBeat => "CatchTheBeat",
+            Self::Mania => "Mania",
+        }
+    }
+}
+
+/// A line of a `.osu` file that could not be decoded.
+#[derive(Debug)]
+pub struct BeatmapDiagnostic {
+    /// The line number, starting at 1.
+    pub line: usize,
+    /// The section of the line.
+    pub section: BeatmapSection,
+    /// The content of the line.
+    pub content: String,
+    /// The reason why the line was skipped.
+    pub error: ParseBeatmapError,
+}
+
+impl fmt::Display for BeatmapDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {} in [{}]: {} `{}`",
+            self.line,
+            self.section.name(),
+            self.error,
+            self.content
+        )
+    }
+}
+
+/// A [`Beatmap`] decoded through [`Beatmap::from_bytes_checked`].
+#[derive(Debug)]
+pub struct CheckedBeatmap {
+    pub map: Beatmap,
+    /// The lines that were skipped.
+    ///
+    /// Always empty for [`DecodeMode::Strict`].
+    pub diagnostics: Vec<BeatmapDiagnostic>,
+}
+
+/// All the ways that [`Beatmap::from_bytes_checked`] can fail.
+#[derive(Debug)]
+pub enum CheckedDecodeError {
+    Io(io::Error),
+    /// [`DecodeMode::Strict`] was specified and the contained lines could not
+    /// be decoded.
+    Rejected(Vec<BeatmapDiagnostic>),
+}
+
+impl error::Error for CheckedDecodeError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            CheckedDecodeError::Io(err) => Some(err),
+            CheckedDecodeError::Rejected(diagnostics) => diagnostics
+                .first()
+                .map(|diagnostic| &diagnostic.error as &(dyn error::Error + 'static)),
+        }
+    }
+}
+
+impl fmt::Display for CheckedDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(_) => f.write_str("failed to read beatmap"),
+            Self::Rejected(diagnostics) => {
+                write!(f, "beatmap has {} invalid line(s)", diagnostics.len())
+            }
+        }
+    }
+}
+
+impl From<io::Error> for CheckedDecodeError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl Beatmap {
+    /// Parse a [`Beatmap`] by providing a path to a `.osu` file and report
+    /// every line that could not be decoded.
+    ///
+    /// See [`Beatmap::from_bytes_checked`].
+    pub fn from_path_checked<P: AsRef<Path>>(
+        path: P,
+        mode: DecodeMode,
+    ) -> Result<CheckedBeatmap, CheckedDecodeError> {
+        Self::from_bytes_checked(&fs::read(path)?, mode)
+    }
+
+    /// Parse a [`Beatmap`] by providing the content of a `.osu` file as a
+    /// slice of bytes and report every line that could not be decoded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mames_pp::{model::beatmap::DecodeMode, Beatmap};
+    ///
+    /// let bytes = b"osu file format v14\n\n[HitObjects]\n256,192,1000,1,0\n256,192\n";
+    ///
+    /// let checked = Beatmap::from_bytes_checked(bytes, DecodeMode::Lenient).unwrap();
+    /// assert_eq!(checked.map.hit_objects.len(), 1);
+    /// assert_eq!(checked.diagnostics[0].line, 5);
+    ///
+    /// assert!(Beatmap::from_bytes_checked(bytes, DecodeMode::Strict).is_err());
+    /// ```
+    pub fn from_bytes_checked(
+        bytes: &[u8],
+        mode: DecodeMode,
+    ) -> Result<CheckedBeatmap, CheckedDecodeError> {
+        let Checked { map, skipped } = rosu_map::from_bytes(bytes)?;
+        let diagnostics = with_line_numbers(bytes, skipped);
+
+        if mode == DecodeMode::Strict && !diagnostics.is_empty() {
+            return Err(CheckedDecodeError::Rejected(diagnostics));
+        }
+
+        Ok(CheckedBeatmap {
+            map: map.with_checksum(bytes),
+            diagnostics,
+        })
+    }
+}
+
+/// A line that [`DecodeBeatmap`] passed to [`Beatmap`]'s parsing but was
+/// skipped due to an error.
+struct SkippedLine {
+    section: BeatmapSection,
+    content: String,
+    error: ParseBeatmapError,
+}
+
+/// Decodes a [`Beatmap`] through its own [`DecodeBeatmap`] implementation
+/// while keeping the errors that would otherwise be discarded.
+struct Checked {
+    map: Beatmap,
+    skipped: Vec<SkippedLine>,
+}
+
+struct CheckedState {
+    inner: BeatmapState,
+    skipped: Vec<SkippedLine>,
+}
+
+impl CheckedState {
+    fn parse(
+        &mut self,
+        section: BeatmapSection,
+        line: &str,
+        f: fn(&mut BeatmapState, &str) -> Result<(), ParseBeatmapError>,
+    ) -> Result<(), ParseBeatmapError> {
+        if let Err(error) = f(&mut self.inner, line) {
+            self.skipped.push(SkippedLine {
+                section,
+                content: line.to_owned(),
+                error,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl DecodeState for CheckedState {
+    fn create(version: i32) -> Self {
+        Self {
+            inner: BeatmapState::create(version),
+            skipped: Vec::new(),
+        }
+    }
+}
+
+impl From<CheckedState> for Checked {
+    fn from(state: CheckedState) -> Self {
+        Self {
+            map: state.inner.into(),
+            skipped: state.skipped,
+        }
+    }
+}
+
+impl DecodeBeatmap for Checked {
+    type Error = ParseBeatmapError;
+    type State = CheckedState;
+
+    fn parse_general(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.parse(BeatmapSection::General, line, Beatmap::parse_general)
+    }
+
+    fn parse_editor(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.parse(BeatmapSection::Editor, line, Beatmap::parse_editor)
+    }
+
+    fn parse_metadata(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.parse(BeatmapSection::Metadata, line, Beatmap::parse_metadata)
+    }
+
+    fn parse_difficulty(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.parse(BeatmapSection::Difficulty, line, Beatmap::parse_difficulty)
+    }
+
+    fn parse_events(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.parse(BeatmapSection::Events, line, Beatmap::parse_events)
+    }
+
+    fn parse_timing_points(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.parse(
+            BeatmapSection::TimingPoints,
+            line,
+            Beatmap::parse_timing_points,
+        )
+    }
+
+    fn parse_colors(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.parse(BeatmapSection::Colors, line, Beatmap::parse_colors)
+    }
+
+    fn parse_hit_objects(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.parse(BeatmapSection::HitObjects, line, Beatmap::parse_hit_objects)
+    }
+
+    fn parse_variables(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.parse(BeatmapSection::Variables, line, Beatmap::parse_variables)
+    }
+
+    fn parse_catch_the_beat(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.parse(
+            BeatmapSection::CatchTheBeat,
+            line,
+            Beatmap::parse_catch_the_beat,
+        )
+    }
+
+    fn parse_mania(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.parse(BeatmapSection::Mania, line, Beatmap::parse_mania)
+    }
+}
+
+/// [`DecodeBeatmap`] does not expose line numbers so the skipped lines are
+/// looked up in the original content. They are in order so each lookup
+/// continues after the previous one.
+fn with_line_numbers(bytes: &[u8], skipped: Vec<SkippedLine>) -> Vec<BeatmapDiagnostic> {
+    let mut lines = bytes
+        .split(|&byte| byte == b'\n')
+        .map(String::from_utf8_lossy)
+        .zip(1..);
+
+    skipped
+        .into_iter()
+        .map(|skipped| {
+            let content = skipped.content.trim();
+
+            let line = lines
+                .find(|(line, _)| line.trim_start_matches('\u{FEFF}').trim() == content)
+                .map_or(0, |(_, number)| number);
+
+            BeatmapDiagnostic {
+                line,
+                section: skipped.section,
+                content: skipped.content,
+                error: skipped.error,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_invalid_lines() {
+        let bytes = b"osu file format v14
+
+[General]
+Mode: 0
+
+[TimingPoints]
+0,NaN,4,2,0,100,1,0
+
+[HitObjects]
+256,192,1000,1,0
+256,192,2000,1,0,0:0:0:0:
+256,192,3000,2,0,B|300:192,10000,100
+256,192,3000,2,0,B|300:192,10000,100
+";
+
+        let checked = Beatmap::from_bytes_checked(bytes, DecodeMode::Lenient).unwrap();
+        assert_eq!(checked.map.hit_objects.len(), 2);
+
+        let lines: Vec<_> = checked
+            .diagnostics
+            .iter()
+            .map(|diagnostic| (diagnostic.line, diagnostic.section))
+            .collect();
+
+        assert_eq!(
+            lines,
+            [
+                (7, BeatmapSection::TimingPoints),
+                (12, BeatmapSection::HitObjects),
+                (13, BeatmapSection::HitObjects),
+            ]
+        );
+
+        assert!(matches!(
+            checked.diagnostics[1].error,
+            ParseBeatmapError::InvalidRepeatCount
+        ));
+
+        let Err(CheckedDecodeError::Rejected(diagnostics)) =
+            Beatmap::from_bytes_checked(bytes, DecodeMode::Strict)
+        else {
+            panic!("expected strict decoding to reject the map");
+        };
+
+        assert_eq!(diagnostics.len(), 3);
+    }
+
+    #[test]
+    fn matches_from_bytes() {
+        for entry in fs::read_dir("./resources").unwrap() {
+            let path = entry.unwrap().path();
+
+            if path.extension().is_none_or(|ext| ext != "osu") {
+                continue;
+            }
+
+            let bytes = fs::read(&path).unwrap();
+            let checked = Beatmap::from_bytes_checked(&bytes, DecodeMode::Lenient).unwrap();
+
+            assert_eq!(
+                checked.map,
+                Beatmap::from_bytes(&bytes).unwrap(),
+                "{}",
+                path.display()
+            );
+        }
+    }
+}
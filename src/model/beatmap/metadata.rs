@@ -0,0 +1,59 @@
+/// Identifying information of a [`Beatmap`].
+///
+/// Only decoded with the `metadata` feature. Otherwise, all fields keep
+/// their default value.
+///
+/// [`Beatmap`]: super::Beatmap
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BeatmapMetadata {
+    pub title: String,
+    pub title_unicode: String,
+    pub artist: String,
+    pub artist_unicode: String,
+    pub creator: String,
+    /// The difficulty name.
+    pub version: String,
+    pub beatmap_id: i32,
+    pub beatmap_set_id: i32,
+    /// The MD5 checksum of the decoded `.osu` file as lowercase hex string.
+    ///
+    /// `None` if the [`Beatmap`] was not decoded from a file's content.
+    ///
+    /// [`Beatmap`]: super::Beatmap
+    pub md5: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Beatmap;
+
+    #[cfg(not(feature = "metadata"))]
+    #[test]
+    fn skip_metadata() {
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+
+        assert_eq!(map.metadata, super::BeatmapMetadata::default());
+    }
+
+    #[cfg(feature = "metadata")]
+    #[test]
+    fn decode_metadata() {
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+        let metadata = &map.metadata;
+
+        assert_eq!(metadata.title, "re[in]flaw");
+        assert_eq!(metadata.artist, "MYUKKE.");
+        assert_eq!(metadata.creator, "captin1");
+        assert_eq!(metadata.version, "toybot's Expert");
+        assert_eq!(metadata.beatmap_id, 2_785_319);
+        assert_eq!(metadata.beatmap_set_id, 1_344_871);
+        assert_eq!(
+            metadata.md5.as_deref(),
+            Some("3ca9a5e23c9a4e9332f4f6e2a48865f7")
+        );
+
+        let bytes = std::fs::read("./resources/2785319.osu").unwrap();
+        assert_eq!(Beatmap::from_bytes(&bytes).unwrap().metadata, *metadata);
+    }
+}
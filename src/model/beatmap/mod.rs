@@ -14,7 +14,14 @@ use crate::{
 
 pub use self::{
     attributes::{BeatmapAttributes, BeatmapAttributesBuilder, HitWindows},
+    builder::{BeatmapBuilder, BuildBeatmapError},
     decode::{BeatmapState, ParseBeatmapError},
+    diagnostics::{
+        BeatmapDiagnostic, BeatmapSection, CheckedBeatmap, CheckedDecodeError, DecodeMode,
+    },
+    metadata::BeatmapMetadata,
+    sanity::{SanityIssue, SanityLimits, SanityReport},
+    statistics::BeatmapStatistics,
 };
 
 use super::{
@@ -28,7 +35,12 @@ use super::{
 
 mod attributes;
 mod bpm;
+mod builder;
 mod decode;
+mod diagnostics;
+mod metadata;
+mod sanity;
+mod statistics;
 
 /// All beatmap data that is relevant for difficulty and performance
 /// calculation.
@@ -54,24 +66,49 @@ pub struct Beatmap {
 
     // TimingPoints
     pub timing_points: Vec<TimingPoint>,
+    /// fork 独自: 読み込み時に beat length が clamp された timing point。
+    ///
+    /// beat length は clamp 前の値。
+    pub clamped_timing_points: Vec<TimingPoint>,
     pub difficulty_points: Vec<DifficultyPoint>,
     pub effect_points: Vec<EffectPoint>,
 
     // HitObjects
     pub hit_objects: Vec<HitObject>,
     pub hit_sounds: Vec<HitSoundType>,
+
+    // Metadata
+    pub metadata: BeatmapMetadata,
 }
 
 impl Beatmap {
     /// Parse a [`Beatmap`] by providing a path to a `.osu` file.
     pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, io::Error> {
-        rosu_map::from_path(path)
+        // The checksum requires the file's full content
+        if cfg!(feature = "metadata") {
+            Self::from_bytes(&std::fs::read(path)?)
+        } else {
+            rosu_map::from_path(path)
+        }
     }
 
     /// Parse a [`Beatmap`] by providing the content of a `.osu` file as a
     /// slice of bytes.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, io::Error> {
-        rosu_map::from_bytes(bytes)
+        rosu_map::from_bytes(bytes).map(|map: Self| map.with_checksum(bytes))
+    }
+
+    #[cfg(feature = "metadata")]
+    fn with_checksum(mut self, bytes: &[u8]) -> Self {
+        self.metadata.md5 = Some(crate::util::md5::hex(bytes));
+
+        self
+    }
+
+    #[cfg(not(feature = "metadata"))]
+    #[allow(clippy::unused_self)]
+    const fn with_checksum(self, _: &[u8]) -> Self {
+        self
     }
 
     /// Returns a [`BeatmapAttributesBuilder`] to calculate modified beatmap
@@ -80,11 +117,40 @@ impl Beatmap {
         BeatmapAttributesBuilder::new().map(self)
     }
 
-    /// The beats per minute of the map.
+    /// The most common beats per minute of the map.
+    ///
+    /// The end time of a slider is not known at this point so the value may
+    /// be off if the last object is a slider. Use [`Beatmap::statistics`]
+    /// for the exact value.
     pub fn bpm(&self) -> f64 {
         bpm::bpm(self.hit_objects.last(), &self.timing_points)
     }
 
+    /// Calculate the [`BeatmapStatistics`] after converting the map to the
+    /// given mode.
+    ///
+    /// The clock rate is taken from the mods i.e. 1.5 for DT, 0.75 for HT
+    /// and 1.0 otherwise. Just like for [`Difficulty::clock_rate`], it is
+    /// clamped between 0.01 and 100.
+    pub fn statistics(
+        &self,
+        mode: GameMode,
+        mods: &GameMods,
+    ) -> Result<BeatmapStatistics, ConvertError> {
+        let map = self.convert_ref(mode, mods)?;
+        let clock_rate = mods.clock_rate().clamp(0.01, 100.0);
+
+        Ok(BeatmapStatistics::new(&map, clock_rate))
+    }
+
+    /// Check the map for hazards such as absurd slider lengths or timing
+    /// tricks.
+    ///
+    /// Intended to validate untrusted maps before any expensive calculation.
+    pub fn check_sanity(&self, limits: &SanityLimits) -> SanityReport {
+        SanityReport::new(self, limits)
+    }
+
     /// Create a performance calculator for this [`Beatmap`].
     pub fn performance(&self) -> Performance<'_> {
         Performance::new(self)
@@ -190,7 +256,7 @@ impl FromStr for Beatmap {
     /// Parse a [`Beatmap`] by providing the content of a `.osu` file as a
     /// string.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        rosu_map::from_str(s)
+        rosu_map::from_str(s).map(|map: Self| map.with_checksum(s.as_bytes()))
     }
 }
 
@@ -211,10 +277,12 @@ impl Default for Beatmap {
             slider_tick_rate: 1.0,
             breaks: Vec::default(),
             timing_points: Vec::default(),
+            clamped_timing_points: Vec::default(),
             difficulty_points: Vec::default(),
             effect_points: Vec::default(),
             hit_objects: Vec::default(),
             hit_sounds: Vec::default(),
+            metadata: BeatmapMetadata::default(),
         }
     }
 }
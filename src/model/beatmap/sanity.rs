@@ -0,0 +1,389 @@
+use std::fmt;
+
+use crate::{
+    model::{
+        control_point::{DifficultyPoint, TimingPoint},
+        hit_object::HitObjectKind,
+    },
+    util::{float_ext::FloatExt, get_precision_adjusted_beat_len},
+};
+
+use super::{decode::MAX_COORDINATE_VALUE, Beatmap};
+
+/// Limits of the checks performed by [`Beatmap::check_sanity`].
+///
+/// The defaults only flag maps that cannot reasonably be played.
+///
+/// # Example
+///
+/// ```
+/// use mames_pp::{model::beatmap::SanityLimits, Beatmap};
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+///
+/// let limits = SanityLimits {
+///     max_objects: 10_000,
+///     ..SanityLimits::default()
+/// };
+///
+/// let report = map.check_sanity(&limits);
+///
+/// for issue in report.issues.iter() {
+///     println!("{issue}");
+/// }
+///
+/// assert!(report.is_sane());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct SanityLimits {
+    /// The maximum amount of hit objects.
+    ///
+    /// Other hit object checks are skipped if this is exceeded.
+    pub max_objects: usize,
+    /// The maximum amount of timing and difficulty points combined.
+    pub max_control_points: usize,
+    /// The maximum amount of hit objects starting at the same time.
+    pub max_simultaneous_objects: usize,
+    /// The maximum absolute value of coordinates of hit objects and slider
+    /// control points.
+    pub max_coordinate: f32,
+    /// The maximum absolute start time of hit objects in milliseconds.
+    pub max_time: f64,
+    /// The maximum specified slider length in osu!pixels.
+    pub max_slider_len: f64,
+    /// The maximum amount of slider repeats.
+    pub max_repeats: usize,
+    /// The maximum slider velocity in osu!pixels per millisecond.
+    pub max_slider_velocity: f64,
+    /// The minimum [`Beatmap::slider_multiplier`].
+    pub min_slider_multiplier: f64,
+    /// The maximum [`Beatmap::slider_multiplier`].
+    pub max_slider_multiplier: f64,
+    /// The minimum beat length of timing points in milliseconds.
+    pub min_beat_len: f64,
+    /// The maximum beat length of timing points in milliseconds.
+    pub max_beat_len: f64,
+}
+
+impl Default for SanityLimits {
+    fn default() -> Self {
+        Self {
+            max_objects: 100_000,
+            max_control_points: 20_000,
+            max_simultaneous_objects: 20,
+            max_coordinate: MAX_COORDINATE_VALUE as f32,
+            max_time: 86_400_000.0,
+            max_slider_len: 50_000.0,
+            max_repeats: 1000,
+            max_slider_velocity: 20.0,
+            // osu! editor limits
+            min_slider_multiplier: 0.4,
+            max_slider_multiplier: 3.6,
+            // See `TimingControlPoint.BeatLengthBindable`
+            min_beat_len: 6.0,
+            max_beat_len: 60_000.0,
+        }
+    }
+}
+
+/// A hazard found by [`Beatmap::check_sanity`].
+///
+/// Hit objects are referred to by their index in [`Beatmap::hit_objects`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SanityIssue {
+    TooManyObjects { count: usize },
+    TooManyControlPoints { count: usize },
+    SimultaneousObjects { time: f64, count: usize },
+    Coordinate { idx: usize, x: f32, y: f32 },
+    StartTime { idx: usize, time: f64 },
+    SliderLength { idx: usize, len: f64 },
+    RepeatCount { idx: usize, repeats: usize },
+    SliderVelocity { idx: usize, velocity: f64 },
+    SliderMultiplier { value: f64 },
+    BeatLength { time: f64, beat_len: f64 },
+}
+
+impl fmt::Display for SanityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooManyObjects { count } => write!(f, "too many hit objects ({count})"),
+            Self::TooManyControlPoints { count } => {
+                write!(f, "too many control points ({count})")
+            }
+            Self::SimultaneousObjects { time, count } => {
+                write!(f, "{count} hit objects at {time}ms")
+            }
+            Self::Coordinate { idx, x, y } => {
+                write!(f, "hit object {idx} exceeds coordinate limit at ({x}, {y})")
+            }
+            Self::StartTime { idx, time } => {
+                write!(f, "hit object {idx} has invalid start time {time}")
+            }
+            Self::SliderLength { idx, len } => write!(f, "slider {idx} has length {len}"),
+            Self::RepeatCount { idx, repeats } => {
+                write!(f, "slider {idx} has {repeats} repeats")
+            }
+            Self::SliderVelocity { idx, velocity } => {
+                write!(f, "slider {idx} has velocity {velocity}px/ms")
+            }
+            Self::SliderMultiplier { value } => write!(f, "invalid slider multiplier {value}"),
+            Self::BeatLength { time, beat_len } => {
+                write!(f, "timing point at {time}ms has beat length {beat_len}")
+            }
+        }
+    }
+}
+
+/// The result of [`Beatmap::check_sanity`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SanityReport {
+    /// All hazards in the order they were found.
+    pub issues: Vec<SanityIssue>,
+}
+
+impl SanityReport {
+    /// Whether no hazard was found.
+    pub fn is_sane(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    pub(super) fn new(map: &Beatmap, limits: &SanityLimits) -> Self {
+        let mut issues = Vec::new();
+
+        let n_control_points = map.timing_points.len() + map.difficulty_points.len();
+
+        if n_control_points > limits.max_control_points {
+            issues.push(SanityIssue::TooManyControlPoints {
+                count: n_control_points,
+            });
+        }
+
+        for point in map.timing_points.iter() {
+            // `TimingPoint::new` clamps the beat length so the decoded value
+            // must be checked instead
+            let beat_len = map
+                .clamped_timing_points
+                .iter()
+                .find(|raw| raw.time.eq(point.time))
+                .map_or(point.beat_len, |raw| raw.beat_len);
+
+            if !(limits.min_beat_len..=limits.max_beat_len).contains(&beat_len) {
+                issues.push(SanityIssue::BeatLength {
+                    time: point.time,
+                    beat_len,
+                });
+            }
+        }
+
+        if !(limits.min_slider_multiplier..=limits.max_slider_multiplier)
+            .contains(&map.slider_multiplier)
+        {
+            issues.push(SanityIssue::SliderMultiplier {
+                value: map.slider_multiplier,
+            });
+        }
+
+        if map.hit_objects.len() > limits.max_objects {
+            issues.push(SanityIssue::TooManyObjects {
+                count: map.hit_objects.len(),
+            });
+
+            return Self { issues };
+        }
+
+        let coordinates = -limits.max_coordinate..=limits.max_coordinate;
+        let exceeds_coordinate =
+            |x: f32, y: f32| !(coordinates.contains(&x) && coordinates.contains(&y));
+
+        let mut simultaneous = 0;
+
+        for (idx, h) in map.hit_objects.iter().enumerate() {
+            if !(-limits.max_time..=limits.max_time).contains(&h.start_time) {
+                issues.push(SanityIssue::StartTime {
+                    idx,
+                    time: h.start_time,
+                });
+            }
+
+            if exceeds_coordinate(h.pos.x, h.pos.y) {
+                issues.push(SanityIssue::Coordinate {
+                    idx,
+                    x: h.pos.x,
+                    y: h.pos.y,
+                });
+            }
+
+            let next_time = map.hit_objects.get(idx + 1).map(|next| next.start_time);
+            simultaneous += 1;
+
+            if next_time.is_none_or(|next_time| next_time.not_eq(h.start_time)) {
+                if simultaneous > limits.max_simultaneous_objects {
+                    issues.push(SanityIssue::SimultaneousObjects {
+                        time: h.start_time,
+                        count: simultaneous,
+                    });
+                }
+
+                simultaneous = 0;
+            }
+
+            let HitObjectKind::Slider(ref slider) = h.kind else {
+                continue;
+            };
+
+            if let Some(point) = slider
+                .control_points
+                .iter()
+                .map(|point| (h.pos.x + point.pos.x, h.pos.y + point.pos.y))
+                .find(|&(x, y)| exceeds_coordinate(x, y))
+            {
+                issues.push(SanityIssue::Coordinate {
+                    idx,
+                    x: point.0,
+                    y: point.1,
+                });
+            }
+
+            if let Some(len) = slider.expected_dist {
+                if !(..=limits.max_slider_len).contains(&len) {
+                    issues.push(SanityIssue::SliderLength { idx, len });
+                }
+            }
+
+            if slider.repeats > limits.max_repeats {
+                issues.push(SanityIssue::RepeatCount {
+                    idx,
+                    repeats: slider.repeats,
+                });
+            }
+
+            let beat_len = map
+                .timing_point_at(h.start_time)
+                .map_or(TimingPoint::DEFAULT_BEAT_LEN, |point| point.beat_len);
+
+            let slider_velocity = map
+                .difficulty_point_at(h.start_time)
+                .map_or(DifficultyPoint::DEFAULT_SLIDER_VELOCITY, |point| {
+                    point.slider_velocity
+                });
+
+            // See `OsuSlider::new`
+            let velocity = 100.0 * map.slider_multiplier
+                / get_precision_adjusted_beat_len(slider_velocity, beat_len);
+
+            if !(..=limits.max_slider_velocity).contains(&velocity) {
+                issues.push(SanityIssue::SliderVelocity { idx, velocity });
+            }
+        }
+
+        Self { issues }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::hit_object::{HitObject, Pos};
+
+    use super::*;
+
+    #[test]
+    fn sane_map() {
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+
+        assert!(map.check_sanity(&SanityLimits::default()).is_sane());
+    }
+
+    #[test]
+    fn nan_slider() {
+        let map = Beatmap::from_path("./resources/nan-slider.osu").unwrap();
+        let report = map.check_sanity(&SanityLimits::default());
+
+        assert!(matches!(
+            report.issues.as_slice(),
+            [SanityIssue::SliderVelocity { .. }]
+        ));
+    }
+
+    #[test]
+    fn extreme_beat_len() {
+        const MAP: &str = "osu file format v14
+
+[TimingPoints]
+0,300,4,2,0,100,1,0
+1000,100000,4,2,0,100,1,0
+2000,1,4,2,0,100,1,0
+
+[HitObjects]
+256,192,500,1,0,0:0:0:0:
+";
+
+        let map: Beatmap = MAP.parse().unwrap();
+        let report = map.check_sanity(&SanityLimits::default());
+
+        assert_eq!(
+            report.issues,
+            [
+                SanityIssue::BeatLength {
+                    time: 1000.0,
+                    beat_len: 100_000.0,
+                },
+                SanityIssue::BeatLength {
+                    time: 2000.0,
+                    beat_len: 1.0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn flags_hazards() {
+        let mut map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+        map.slider_multiplier = 100.0;
+
+        let h = HitObject {
+            pos: Pos::new(1e6, 0.0),
+            start_time: map.hit_objects[0].start_time,
+            kind: HitObjectKind::Circle,
+        };
+
+        map.hit_objects.insert(0, h);
+
+        let limits = SanityLimits {
+            max_simultaneous_objects: 1,
+            ..SanityLimits::default()
+        };
+
+        let issues = map.check_sanity(&limits).issues;
+
+        assert_eq!(issues[0], SanityIssue::SliderMultiplier { value: 100.0 });
+        assert!(issues.contains(&SanityIssue::Coordinate {
+            idx: 0,
+            x: 1e6,
+            y: 0.0
+        }));
+        assert!(issues
+            .iter()
+            .any(|issue| matches!(issue, SanityIssue::SimultaneousObjects { count: 2, .. })));
+        assert!(issues
+            .iter()
+            .any(|issue| matches!(issue, SanityIssue::SliderVelocity { .. })));
+
+        let limits = SanityLimits {
+            max_objects: 10,
+            ..limits
+        };
+
+        assert!(map
+            .check_sanity(&limits)
+            .issues
+            .iter()
+            .all(|issue| !matches!(issue, SanityIssue::Coordinate { .. })));
+    }
+}
@@ -0,0 +1,260 @@
+use rosu_map::section::{general::GameMode, hit_objects::CurveBuffers};
+
+use crate::{
+    model::{
+        control_point::{DifficultyPoint, TimingPoint},
+        hit_object::{HitObject, HitObjectKind, Slider},
+    },
+    taiko,
+    util::get_precision_adjusted_beat_len,
+};
+
+use super::{bpm, Beatmap};
+
+/// General statistics of a [`Beatmap`] as shown in osu!'s song select.
+///
+/// Durations are in milliseconds and, just like the BPM values, already
+/// adjusted to the clock rate.
+///
+/// Obtained through [`Beatmap::statistics`].
+///
+/// # Example
+///
+/// ```
+/// use mames_pp::{Beatmap, GameMods};
+/// use rosu_map::section::general::GameMode;
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+///
+/// // DoubleTime
+/// let stats = map.statistics(GameMode::Osu, &GameMods::from(64)).unwrap();
+///
+/// println!(
+///     "{}:{:02} ({:.0} BPM)",
+///     (stats.length / 60_000.0) as u32,
+///     (stats.length / 1000.0) as u32 % 60,
+///     stats.most_common_bpm,
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BeatmapStatistics {
+    /// The clock rate that was applied.
+    pub clock_rate: f64,
+    /// The time between the start of the first object and the end of the
+    /// last object.
+    pub length: f64,
+    /// The [`length`] without breaks.
+    ///
+    /// [`length`]: BeatmapStatistics::length
+    pub drain_time: f64,
+    /// The BPM of the slowest timing point.
+    pub min_bpm: f64,
+    /// The BPM of the fastest timing point.
+    pub max_bpm: f64,
+    /// The BPM that is active for the longest time.
+    pub most_common_bpm: f64,
+    /// The amount of circles, fruits, or notes depending on the mode.
+    pub n_circles: u32,
+    /// The amount of sliders, drum rolls, or juice streams depending on the
+    /// mode.
+    pub n_sliders: u32,
+    /// The amount of spinners, swells, or banana showers depending on the
+    /// mode.
+    pub n_spinners: u32,
+    /// The amount of osu!mania hold notes.
+    pub n_holds: u32,
+    /// The amount of objects starting within each second, counting from the
+    /// start of the first object.
+    pub density: Vec<u32>,
+}
+
+impl BeatmapStatistics {
+    /// The duration of each [`density`] section.
+    ///
+    /// [`density`]: BeatmapStatistics::density
+    pub const DENSITY_SECTION_LEN: f64 = 1000.0;
+
+    pub(super) fn new(map: &Beatmap, clock_rate: f64) -> Self {
+        let mut bufs = CurveBuffers::default();
+
+        let mut n_circles = 0;
+        let mut n_sliders = 0;
+        let mut n_spinners = 0;
+        let mut n_holds = 0;
+
+        let mut last_time = None::<f64>;
+
+        for h in map.hit_objects.iter() {
+            match h.kind {
+                HitObjectKind::Circle => n_circles += 1,
+                HitObjectKind::Slider(_) => n_sliders += 1,
+                HitObjectKind::Spinner(_) => n_spinners += 1,
+                HitObjectKind::Hold(_) => n_holds += 1,
+            }
+
+            let end_time = end_time(map, h, &mut bufs);
+            last_time = Some(last_time.map_or(end_time, |last| last.max(end_time)));
+        }
+
+        let first_time = map.hit_objects.first().map_or(0.0, |h| h.start_time);
+
+        let length = last_time.map_or(0.0, |last_time| last_time - first_time);
+        let drain_time = (length - map.total_break_time()).max(0.0);
+
+        let (min_beat_len, max_beat_len) = map
+            .timing_points
+            .iter()
+            .map(|point| point.beat_len)
+            .fold(None, |extrema, beat_len| match extrema {
+                Some((min, max)) => Some((f64::min(min, beat_len), f64::max(max, beat_len))),
+                None => Some((beat_len, beat_len)),
+            })
+            .unwrap_or((TimingPoint::DEFAULT_BEAT_LEN, TimingPoint::DEFAULT_BEAT_LEN));
+
+        let mut density = Vec::new();
+
+        for h in map.hit_objects.iter() {
+            let idx =
+                ((h.start_time - first_time) / clock_rate / Self::DENSITY_SECTION_LEN) as usize;
+
+            if idx >= density.len() {
+                density.resize(idx + 1, 0);
+            }
+
+            density[idx] += 1;
+        }
+
+        Self {
+            clock_rate,
+            length: length / clock_rate,
+            drain_time: drain_time / clock_rate,
+            min_bpm: 60_000.0 / max_beat_len * clock_rate,
+            max_bpm: 60_000.0 / min_beat_len * clock_rate,
+            most_common_bpm: bpm::most_common_bpm(last_time, &map.timing_points) * clock_rate,
+            n_circles,
+            n_sliders,
+            n_spinners,
+            n_holds,
+            density,
+        }
+    }
+}
+
+/// The end time of the hit object in the map's mode.
+fn end_time(map: &Beatmap, h: &HitObject, bufs: &mut CurveBuffers) -> f64 {
+    let HitObjectKind::Slider(ref slider) = h.kind else {
+        return h.end_time();
+    };
+
+    let duration = if map.mode == GameMode::Taiko {
+        f64::from(taiko::drum_roll_duration(map, h.start_time, slider))
+    } else {
+        slider_duration(map, h.start_time, slider, bufs)
+    };
+
+    h.start_time + duration
+}
+
+/// See `OsuSlider::new` and `JuiceStream::new`.
+fn slider_duration(
+    map: &Beatmap,
+    start_time: f64,
+    slider: &Slider,
+    bufs: &mut CurveBuffers,
+) -> f64 {
+    const BASE_SCORING_DIST: f64 = 100.0;
+
+    let beat_len = map
+        .timing_point_at(start_time)
+        .map_or(TimingPoint::DEFAULT_BEAT_LEN, |point| point.beat_len);
+
+    let slider_velocity = map
+        .difficulty_point_at(start_time)
+        .map_or(DifficultyPoint::DEFAULT_SLIDER_VELOCITY, |point| {
+            point.slider_velocity
+        });
+
+    let mode = if map.mode == GameMode::Catch {
+        GameMode::Catch
+    } else {
+        GameMode::Osu
+    };
+
+    let path = slider.curve(mode, bufs);
+
+    let velocity = BASE_SCORING_DIST * map.slider_multiplier
+        / get_precision_adjusted_beat_len(slider_velocity, beat_len);
+
+    slider.span_count() as f64 * path.dist() / velocity
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+
+    use crate::GameMods;
+
+    use super::*;
+
+    #[test]
+    fn statistics() {
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+        let stats = map.statistics(GameMode::Osu, &GameMods::default()).unwrap();
+
+        assert_eq!(stats.min_bpm, stats.max_bpm);
+        assert_eq!(stats.min_bpm, stats.most_common_bpm);
+        assert!((stats.most_common_bpm - 170.0).abs() < 1e-6);
+
+        let last = map.hit_objects.last().unwrap();
+        assert!(last.is_slider());
+        assert!(stats.length > last.start_time - map.hit_objects[0].start_time);
+        assert_eq!(stats.drain_time, stats.length - map.total_break_time());
+
+        let n_objects = stats.n_circles + stats.n_sliders + stats.n_spinners + stats.n_holds;
+        assert_eq!(n_objects as usize, map.hit_objects.len());
+        assert_eq!(stats.density.iter().sum::<u32>(), n_objects);
+
+        let dt = map.statistics(GameMode::Osu, &GameMods::from(64)).unwrap();
+        assert!((dt.most_common_bpm - 255.0).abs() < 1e-6);
+        assert!((dt.length - stats.length / 1.5).abs() < 1e-9);
+        assert!(dt.density.len() < stats.density.len());
+    }
+
+    #[test]
+    fn clamped_clock_rate() {
+        use rosu_mods::{generated_mods::DoubleTimeOsu, GameMod};
+
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+
+        for speed_change in [0.0, -1.0] {
+            let gamemod = GameMod::DoubleTimeOsu(DoubleTimeOsu {
+                speed_change: Some(speed_change),
+                ..DoubleTimeOsu::default()
+            });
+            let mods = GameMods::from(rosu_mods::GameMods::from(gamemod));
+            let stats = map.statistics(GameMode::Osu, &mods).unwrap();
+
+            assert_eq!(stats.clock_rate, 0.01);
+            assert!(stats.length.is_finite());
+        }
+    }
+
+    #[test]
+    fn converted() {
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+        let mania = map
+            .statistics(GameMode::Mania, &GameMods::default())
+            .unwrap();
+
+        assert_eq!(mania.n_sliders, 0);
+        assert_eq!(mania.n_spinners, 0);
+
+        let taiko = map
+            .convert_ref(GameMode::Taiko, &GameMods::default())
+            .unwrap();
+        assert!(taiko
+            .statistics(GameMode::Mania, &GameMods::default())
+            .is_err());
+    }
+}
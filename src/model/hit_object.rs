@@ -1,12 +1,11 @@
 use std::cmp::Ordering;
 
-use rosu_map::section::{
-    general::GameMode,
-    hit_objects::{BorrowedCurve, CurveBuffers},
-};
+use rosu_map::section::{general::GameMode, hit_objects::BorrowedCurve};
 
 pub use rosu_map::{
-    section::hit_objects::{hit_samples::HitSoundType, PathControlPoint, PathType, SplineType},
+    section::hit_objects::{
+        hit_samples::HitSoundType, CurveBuffers, PathControlPoint, PathType, SplineType,
+    },
     util::Pos,
 };
 
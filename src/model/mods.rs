@@ -224,6 +224,19 @@ impl GameMods {
         }
     }
 
+    /// The seed of the osu!taiko Random mod.
+    pub(crate) fn random_seed(&self) -> Option<i32> {
+        let Self::Lazer(mods) = self else { return None };
+
+        mods.iter()
+            .find_map(|m| match m {
+                GameMod::RandomTaiko(random) => Some(random.seed),
+                _ => None,
+            })
+            .flatten()
+            .map(|seed| seed as i32)
+    }
+
     pub(crate) fn scroll_speed(&self) -> Option<f64> {
         let Self::Lazer(mods) = self else { return None };
 
@@ -320,6 +333,16 @@ impl_has_mod! {
     dt: + DoubleTime ["DoubleTime"],
     ht: + HalfTime ["HalfTime"],
     has_score_v2: + ScoreV2 ["ScoreV2"],
+    // health の計算で必要な mod flag
+    sd: + SuddenDeath ["SuddenDeath"],
+    pf: + Perfect ["Perfect"],
+    // osu!taiko の reading で必要な mod flag
+    constant_speed: - ConstantSpeed ["ConstantSpeed"],
+    // osu!taiko のノーツの色を変える mod flag
+    swap: - Swap ["Swap"],
+    random: + Random ["Random"],
+    // osu!standard の speed で必要な mod flag
+    single_tap: - SingleTap ["SingleTap"],
 }
 
 impl Default for GameMods {
@@ -363,6 +386,52 @@ impl From<u32> for GameMods {
     }
 }
 
+/// Serialized through the lazer representation i.e. a list of
+/// `{ "acronym": ..., "settings": ... }` objects.
+///
+/// Deserializing always results in [`GameMods::Lazer`].
+#[cfg(feature = "serde")]
+impl serde::Serialize for GameMods {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        #[derive(serde::Serialize)]
+        struct Acronym<'a> {
+            acronym: &'a str,
+        }
+
+        let legacy;
+
+        let mods = match self {
+            Self::Lazer(ref mods) => return serde::Serialize::serialize(mods, s),
+            Self::Intermode(ref mods) => mods,
+            Self::Legacy(mods) => {
+                legacy = GameModsIntermode::from_bits(mods.bits());
+
+                &legacy
+            }
+        };
+
+        // Mods without settings only need their acronym
+        let mut seq = s.serialize_seq(Some(mods.len()))?;
+
+        for gamemod in mods.iter() {
+            seq.serialize_element(&Acronym {
+                acronym: gamemod.acronym().as_str(),
+            })?;
+        }
+
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GameMods {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        <GameModsLazer as serde::Deserialize>::deserialize(d).map(Self::Lazer)
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub(crate) enum Reflection {
     None,
@@ -1,6 +1,9 @@
 use rosu_map::section::hit_objects::CurveBuffers;
 
-use crate::model::{beatmap::Beatmap, mods::Reflection};
+use crate::{
+    any::difficulty::section::SectionWindow,
+    model::{beatmap::Beatmap, mods::Reflection},
+};
 
 use super::{
     attributes::OsuDifficultyAttributes,
@@ -14,6 +17,7 @@ pub fn convert_objects(
     reflection: Reflection,
     time_preempt: f64,
     mut take: usize,
+    section: &SectionWindow,
     attrs: &mut OsuDifficultyAttributes,
 ) -> Box<[OsuObject]> {
     let mut curve_bufs = CurveBuffers::default();
@@ -30,6 +34,11 @@ pub fn convert_objects(
             }
 
             take -= 1;
+
+            if !section.contains(h.start_time) {
+                return;
+            }
+
             attrs.max_combo += 1;
 
             match h.kind {
@@ -3,7 +3,7 @@ use std::{cmp, mem};
 use rosu_map::section::general::GameMode;
 
 use crate::{
-    any::difficulty::skills::StrainSkill,
+    any::difficulty::{section::SectionWindow, skills::StrainSkill},
     model::mode::ConvertError,
     osu::{
         convert::convert_objects,
@@ -88,6 +88,7 @@ impl OsuGradualDifficulty {
             mods.reflection(),
             time_preempt,
             map.hit_objects.len(),
+            &SectionWindow::default(),
             &mut attrs,
         );
 
@@ -126,6 +127,8 @@ impl OsuGradualDifficulty {
             time_preempt,
             map.hit_objects.len(),
             attrs.great_hit_window,
+            difficulty.get_algorithm(),
+            difficulty.get_osu_parameters(),
         );
         let diff_objects = extend_lifetime(diff_objects.into_boxed_slice());
 
@@ -202,7 +205,11 @@ impl Iterator for OsuGradualDifficulty {
 
         let mut attrs = self.attrs.clone();
 
-        DifficultyValues::eval(&mut attrs, self.difficulty.get_mods(), &self.skills);
+        DifficultyValues::eval(
+            &mut attrs,
+            self.difficulty.get_osu_parameters(),
+            &self.skills,
+        );
 
         Some(attrs)
     }
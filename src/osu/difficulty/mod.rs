@@ -5,12 +5,12 @@ use skills::{aim::Aim, flashlight::Flashlight, speed::Speed};
 
 use crate::{
     any::difficulty::Difficulty,
-    model::{beatmap::BeatmapAttributes, mode::ConvertError, mods::GameMods},
+    model::{beatmap::BeatmapAttributes, mode::ConvertError},
     osu::{
         convert::convert_objects,
         difficulty::{object::OsuDifficultyObject, scaling_factor::ScalingFactor},
         object::OsuObject,
-        performance::PERFORMANCE_BASE_MULTIPLIER,
+        OsuParameters,
     },
     Beatmap,
 };
@@ -25,8 +25,6 @@ mod object;
 pub mod scaling_factor;
 pub mod skills;
 
-const DIFFICULTY_MULTIPLIER: f64 = 0.0675;
-
 const HD_FADE_IN_DURATION_MULTIPLIER: f64 = 0.4;
 const HD_FADE_OUT_DURATION_MULTIPLIER: f64 = 0.3;
 
@@ -38,18 +36,16 @@ pub fn difficulty(
 
     let DifficultyValues { skills, mut attrs } = DifficultyValues::calculate(difficulty, &map);
 
-    let mods = difficulty.get_mods();
-
-    DifficultyValues::eval(&mut attrs, mods, &skills);
+    DifficultyValues::eval(&mut attrs, difficulty.get_osu_parameters(), &skills);
 
     Ok(attrs)
 }
 
 pub struct OsuDifficultySetup {
-    scaling_factor: ScalingFactor,
-    map_attrs: BeatmapAttributes,
-    attrs: OsuDifficultyAttributes,
-    time_preempt: f64,
+    pub scaling_factor: ScalingFactor,
+    pub map_attrs: BeatmapAttributes,
+    pub attrs: OsuDifficultyAttributes,
+    pub time_preempt: f64,
 }
 
 impl OsuDifficultySetup {
@@ -110,12 +106,15 @@ impl DifficultyValues {
             time_preempt,
         } = OsuDifficultySetup::new(difficulty, map);
 
+        let mut section = difficulty.get_section_window(&map.hit_objects);
+
         let mut osu_objects = convert_objects(
             map,
             &scaling_factor,
             mods.reflection(),
             time_preempt,
             take,
+            &section,
             &mut attrs,
         );
 
@@ -124,7 +123,8 @@ impl DifficultyValues {
         // これらは convert 後の osu_objects と base map から計算するので、difficulty
         // skills を回す前後どちらでも良いが、attrs の他フィールドと近い位置に置く。
         let n_objects = attrs.n_circles + attrs.n_sliders + attrs.n_spinners;
-        let progressive_objects = &osu_objects[..cmp::min(take, osu_objects.len())];
+        let passed_objects = &osu_objects[..cmp::min(take, osu_objects.len())];
+        let progressive_objects = section.slice(passed_objects, |h| h.start_time);
         let peppy_stars = legacy_score::utils::calculate_difficulty_peppy_stars(map);
         attrs.legacy_score_base_multiplier = peppy_stars;
         attrs.nested_score_per_object =
@@ -151,12 +151,22 @@ impl DifficultyValues {
             time_preempt,
             map.hit_objects.len(),
             attrs.great_hit_window,
+            difficulty.get_algorithm(),
+            difficulty.get_osu_parameters(),
         );
 
         // The first hit object has no difficulty object
         let take_diff_objects = cmp::min(map.hit_objects.len(), take).saturating_sub(1);
 
         for hit_object in diff_objects.iter().take(take_diff_objects) {
+            if section.is_past(hit_object.start_time) {
+                break;
+            }
+
+            if section.enters(hit_object.start_time) {
+                skills.discard_history();
+            }
+
             skills.process(hit_object, &diff_objects);
         }
 
@@ -164,7 +174,7 @@ impl DifficultyValues {
     }
 
     /// Process the difficulty values and store the results in `attrs`.
-    pub fn eval(attrs: &mut OsuDifficultyAttributes, _mods: &GameMods, skills: &OsuSkills) {
+    pub fn eval(attrs: &mut OsuDifficultyAttributes, params: &OsuParameters, skills: &OsuSkills) {
         let OsuSkills {
             aim,
             aim_no_sliders,
@@ -184,11 +194,13 @@ impl DifficultyValues {
             (value, count)
         };
 
-        let aim_rating = aim_difficulty_value.powf(0.63) * 0.02275;
-        let aim_no_sliders_rating = aim_no_sliders_difficulty_value.powf(0.63) * 0.02275;
-        let speed_rating = speed_difficulty_value.sqrt() * DIFFICULTY_MULTIPLIER;
-        let reading_rating = reading_difficulty_value.sqrt() * DIFFICULTY_MULTIPLIER;
-        let flashlight_rating = flashlight.lazer_difficulty_value().sqrt() * DIFFICULTY_MULTIPLIER;
+        let aim_rating = aim_difficulty_value.powf(0.63) * params.aim_difficulty_multiplier;
+        let aim_no_sliders_rating =
+            aim_no_sliders_difficulty_value.powf(0.63) * params.aim_difficulty_multiplier;
+        let speed_rating = speed_difficulty_value.sqrt() * params.difficulty_multiplier;
+        let reading_rating = reading_difficulty_value.sqrt() * params.difficulty_multiplier;
+        let flashlight_rating =
+            flashlight.lazer_difficulty_value().sqrt() * params.difficulty_multiplier;
 
         let aim_difficult_strain_count = aim.count_top_weighted_strains(aim_difficulty_value);
         let difficult_sliders = aim.get_difficult_sliders();
@@ -208,7 +220,7 @@ impl DifficultyValues {
             base_reading_performance
         } else {
             crate::util::difficulty::norm(
-                1.1,
+                params.performance_norm_exponent,
                 [
                     base_reading_performance,
                     base_flashlight_performance
@@ -217,14 +229,14 @@ impl DifficultyValues {
             )
         };
         let base_performance = crate::util::difficulty::norm(
-            1.1,
+            params.performance_norm_exponent,
             [
                 base_aim_performance,
                 base_speed_performance,
                 base_cognition_performance,
             ],
         );
-        let star_rating = (base_performance * PERFORMANCE_BASE_MULTIPLIER).cbrt();
+        let star_rating = (base_performance * params.performance_base_multiplier).cbrt();
 
         attrs.aim = aim_rating;
         attrs.aim_difficult_slider_count = difficult_sliders;
@@ -1,7 +1,7 @@
 use crate::{
     any::difficulty::object::IDifficultyObject,
     model::mods::GameMods,
-    osu::difficulty::object::OsuDifficultyObject,
+    osu::{difficulty::object::OsuDifficultyObject, OsuParameters},
     util::{
         difficulty::{
             logistic, logistic_exp, milliseconds_to_bpm, norm, reverse_lerp, smootherstep,
@@ -18,6 +18,7 @@ pub struct Aim {
     has_autopilot_mod: bool,
     has_touch_device_mod: bool,
     has_relax_mod: bool,
+    strain_decay_base: f64,
 
     current_strain: f64,
     current_section_peak: f64,
@@ -55,12 +56,13 @@ impl Aim {
     const REDUCED_SECTION_TIME: f64 = 4000.0;
     const REDUCED_STRAIN_BASELINE: f64 = 0.727;
 
-    pub fn new(mods: &GameMods, include_sliders: bool) -> Self {
+    pub fn new(mods: &GameMods, include_sliders: bool, params: &OsuParameters) -> Self {
         Self {
             include_sliders,
             has_autopilot_mod: mods.ap(),
             has_touch_device_mod: mods.td(),
             has_relax_mod: mods.rx(),
+            strain_decay_base: params.aim_strain_decay_base,
             current_strain: 0.0,
             current_section_peak: 0.0,
             current_section_begin: 0.0,
@@ -79,6 +81,22 @@ impl Aim {
         self.object_difficulties.push(difficulty);
     }
 
+    /// upstream `GetObjectDifficulties()` 相当。
+    pub fn object_strains(&self) -> &[f64] {
+        &self.object_difficulties
+    }
+
+    /// See `StrainSkill::discard_history`.
+    pub fn discard_history(&mut self) {
+        self.current_section_peak = 0.0;
+        self.strain_peaks.clear();
+        self.total_length = 0.0;
+        self.queued_strains.clear();
+        self.final_peak = None;
+        self.object_difficulties.clear();
+        self.slider_strains.clear();
+    }
+
     fn process_internal(
         &mut self,
         curr: &OsuDifficultyObject<'_>,
@@ -148,7 +166,7 @@ impl Aim {
             .previous(0, objects)
             .map_or(0.0, |previous| previous.start_time);
         self.current_section_peak =
-            self.current_strain * Self::strain_decay(time - previous_start_time);
+            self.current_strain * self.strain_decay(time - previous_start_time);
     }
 
     fn save_current_peak(&mut self, section_length: f64) {
@@ -195,8 +213,8 @@ impl Aim {
         &self.strain_peaks
     }
 
-    fn strain_decay(ms: f64) -> f64 {
-        0.2_f64.powf(ms / 1000.0)
+    fn strain_decay(&self, ms: f64) -> f64 {
+        self.strain_decay_base.powf(ms / 1000.0)
     }
 
     fn strain_value_at(
@@ -208,7 +226,7 @@ impl Aim {
             return 0.0;
         }
 
-        let decay = Self::strain_decay(curr.strain_time);
+        let decay = self.strain_decay(curr.strain_time);
         self.current_strain *= decay;
         self.current_strain += self.calculate_adjusted_difficulty(curr, objects) * (1.0 - decay);
 
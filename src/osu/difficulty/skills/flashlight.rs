@@ -5,7 +5,7 @@ use crate::{
         object::{HasStartTime, IDifficultyObject},
         skills::{strain_decay, StrainSkill},
     },
-    osu::{difficulty::object::OsuDifficultyObject, object::OsuObjectKind},
+    osu::{difficulty::object::OsuDifficultyObject, object::OsuObjectKind, OsuParameters},
     util::strains_vec::StrainsVec,
     GameMods,
 };
@@ -19,6 +19,8 @@ define_skill! {
         has_relax_mod: bool,
         has_autopilot_mod: bool,
         total_objects: usize,
+        skill_multiplier: f64,
+        strain_decay_base: f64,
         evaluator: FlashlightEvaluator = todo!(),
     }
 
@@ -27,7 +29,8 @@ define_skill! {
         radius: f64,
         time_preempt: f64,
         time_fade_in: f64,
-        total_objects: usize
+        total_objects: usize,
+        params: &OsuParameters
     ) -> Self {
         {
             let scaling_factor = 52.0 / radius;
@@ -41,15 +44,14 @@ define_skill! {
             has_relax_mod: mods.rx(),
             has_autopilot_mod: mods.ap(),
             total_objects: total_objects,
+            skill_multiplier: params.flashlight_skill_multiplier,
+            strain_decay_base: params.flashlight_strain_decay_base,
             evaluator: FlashlightEvaluator::new(scaling_factor, time_preempt, time_fade_in),
         }
     }
 }
 
 impl Flashlight {
-    const SKILL_MULTIPLIER: f64 = 0.058;
-    const STRAIN_DECAY_BASE: f64 = 0.15;
-
     fn calculate_initial_strain(
         &mut self,
         time: f64,
@@ -60,7 +62,7 @@ impl Flashlight {
             .previous(0, objects)
             .map_or(0.0, HasStartTime::start_time);
 
-        self.current_strain * strain_decay(time - prev_start_time, Self::STRAIN_DECAY_BASE)
+        self.current_strain * strain_decay(time - prev_start_time, self.strain_decay_base)
     }
 
     fn strain_value_at(
@@ -72,7 +74,7 @@ impl Flashlight {
             return 0.0;
         }
 
-        self.current_strain *= strain_decay(curr.delta_time, Self::STRAIN_DECAY_BASE);
+        self.current_strain *= strain_decay(curr.delta_time, self.strain_decay_base);
         let mut difficulty = self
             .evaluator
             .evaluate_diff_of(curr, objects, self.has_hidden_mod);
@@ -90,7 +92,7 @@ impl Flashlight {
         }
 
         difficulty *= 0.985 + curr.overall_difficulty.max(0.0).powi(2) / 4000.0;
-        self.current_strain += difficulty * Self::SKILL_MULTIPLIER;
+        self.current_strain += difficulty * self.skill_multiplier;
 
         self.current_strain
     }
@@ -1,7 +1,7 @@
 use crate::{
-    any::difficulty::skills::StrainSkill,
+    any::{difficulty::skills::StrainSkill, AlgorithmVersion},
     model::{beatmap::BeatmapAttributes, mods::GameMods},
-    osu::object::OsuObject,
+    osu::{object::OsuObject, OsuParameters},
 };
 
 use self::{aim::Aim, flashlight::Flashlight, reading::Reading, speed::Speed};
@@ -32,6 +32,8 @@ impl OsuSkills {
         time_preempt: f64,
         total_objects: usize,
         great_hit_window: f64,
+        algorithm: AlgorithmVersion,
+        params: &OsuParameters,
     ) -> Self {
         let hit_window = 2.0 * great_hit_window;
 
@@ -49,17 +51,18 @@ impl OsuSkills {
             400.0 * (time_preempt / OsuObject::PREEMPT_MIN).min(1.0)
         };
 
-        let aim = Aim::new(mods, true);
-        let aim_no_sliders = Aim::new(mods, false);
-        let speed = Speed::new(hit_window, mods);
+        let aim = Aim::new(mods, true, params);
+        let aim_no_sliders = Aim::new(mods, false, params);
+        let speed = Speed::new(hit_window, mods, algorithm, params);
         let flashlight = Flashlight::new(
             mods,
             scaling_factor.radius,
             time_preempt,
             time_fade_in,
             total_objects,
+            params,
         );
-        let reading = Reading::new(mods, time_preempt, time_fade_in);
+        let reading = Reading::new(mods, time_preempt, time_fade_in, params);
 
         Self {
             aim,
@@ -77,4 +80,12 @@ impl OsuSkills {
         self.flashlight.process(curr, objects);
         self.reading.process(curr, objects);
     }
+
+    pub fn discard_history(&mut self) {
+        self.aim.discard_history();
+        self.aim_no_sliders.discard_history();
+        self.speed.discard_history();
+        self.flashlight.discard_history();
+        self.reading.discard_history();
+    }
 }
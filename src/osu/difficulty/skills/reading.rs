@@ -13,7 +13,7 @@
 
 use crate::{
     any::difficulty::object::IDifficultyObject,
-    osu::difficulty::object::OsuDifficultyObject,
+    osu::{difficulty::object::OsuDifficultyObject, OsuParameters},
     util::difficulty::{logistic, reverse_lerp, smootherstep},
     GameMods,
 };
@@ -40,17 +40,17 @@ pub struct Reading {
     /// evaluator 経由で `opacity_at` に必要
     time_fade_in: f64,
 
+    /// upstream: `skill_multiplier`
+    skill_multiplier: f64,
+    /// upstream: `strainDecay` の base
+    strain_decay_base: f64,
+
     /// 遅延計算用の `ObjectWeightSum` (harmonic sum で使う)。
     /// `difficulty_value()` を呼んだ時点で更新される。
     object_weight_sum: f64,
 }
 
 impl Reading {
-    /// upstream: `skill_multiplier`
-    const SKILL_MULTIPLIER: f64 = 2.5;
-    /// upstream: `strainDecay` の base
-    const STRAIN_DECAY_BASE: f64 = 0.8;
-
     /// HarmonicSkill defaults
     const HARMONIC_SCALE: f64 = 1.0;
     const DECAY_EXPONENT: f64 = 0.9;
@@ -58,7 +58,7 @@ impl Reading {
     /// upstream: `reduced_difficulty_duration = 60 * 1000` (60 秒間 memorize と仮定)
     const REDUCED_DIFFICULTY_DURATION: f64 = 60_000.0;
 
-    pub fn new(mods: &GameMods, preempt: f64, time_fade_in: f64) -> Self {
+    pub fn new(mods: &GameMods, preempt: f64, time_fade_in: f64, params: &OsuParameters) -> Self {
         Self {
             object_difficulties: Vec::new(),
             object_start_times: Vec::new(),
@@ -70,6 +70,8 @@ impl Reading {
             magnetised_strength: 0.0, // 未サポート mod、後で拡張可能
             time_preempt: preempt,
             time_fade_in,
+            skill_multiplier: params.reading_skill_multiplier,
+            strain_decay_base: params.reading_strain_decay_base,
             object_weight_sum: 0.0,
         }
     }
@@ -77,16 +79,24 @@ impl Reading {
     /// upstream: `Skill.Process` → `ProcessInternal` → `ObjectDifficultyOf`
     pub fn process(&mut self, curr: &OsuDifficultyObject<'_>, objects: &[OsuDifficultyObject<'_>]) {
         // upstream: strainDecay(ms) = pow(0.8, ms/1000)
-        let decay = Self::STRAIN_DECAY_BASE.powf(curr.delta_time / 1000.0);
+        let decay = self.strain_decay_base.powf(curr.delta_time / 1000.0);
         self.current_strain *= decay;
         self.current_strain += self.calculate_adjusted_difficulty(curr, objects)
             * (1.0 - decay)
-            * Self::SKILL_MULTIPLIER;
+            * self.skill_multiplier;
 
         self.object_difficulties.push(self.current_strain);
         self.object_start_times.push(curr.start_time);
     }
 
+    /// fork 独自: section 計算用。`current_strain` は残して以降の object に
+    /// 引き継ぐ (`StrainSkill::discard_history` 参照)。
+    pub fn discard_history(&mut self) {
+        self.object_difficulties.clear();
+        self.object_start_times.clear();
+        self.object_weight_sum = 0.0;
+    }
+
     fn calculate_adjusted_difficulty(
         &self,
         curr: &OsuDifficultyObject<'_>,
@@ -221,6 +231,8 @@ impl Reading {
             magnetised_strength: self.magnetised_strength,
             time_preempt: self.time_preempt,
             time_fade_in: self.time_fade_in,
+            skill_multiplier: self.skill_multiplier,
+            strain_decay_base: self.strain_decay_base,
             object_weight_sum: 0.0,
         }
     }
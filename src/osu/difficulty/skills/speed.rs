@@ -1,9 +1,9 @@
 use std::cmp;
 
 use crate::{
-    any::difficulty::object::IDifficultyObject,
+    any::{difficulty::object::IDifficultyObject, AlgorithmVersion},
     model::mods::GameMods,
-    osu::difficulty::object::OsuDifficultyObject,
+    osu::{difficulty::object::OsuDifficultyObject, OsuParameters},
     util::{
         difficulty::{
             bpm_to_milliseconds, logistic, milliseconds_to_bpm, reverse_lerp, smoothstep_bell_curve,
@@ -19,20 +19,28 @@ pub struct Speed {
     hit_window: f64,
     has_relax_mod: bool,
     has_autopilot_mod: bool,
+    tapping_style: TappingStyle,
+    harmonic_scale: f64,
+    decay_exponent: f64,
     object_difficulties: Vec<f64>,
     slider_strains: Vec<f64>,
 }
 
 impl Speed {
-    const HARMONIC_SCALE: f64 = 20.0;
-    const DECAY_EXPONENT: f64 = 0.9;
-
-    pub fn new(hit_window: f64, mods: &GameMods) -> Self {
+    pub fn new(
+        hit_window: f64,
+        mods: &GameMods,
+        algorithm: AlgorithmVersion,
+        params: &OsuParameters,
+    ) -> Self {
         Self {
             current_strain: 0.0,
             hit_window,
             has_relax_mod: mods.rx(),
             has_autopilot_mod: mods.ap(),
+            tapping_style: TappingStyle::new(mods, algorithm, params),
+            harmonic_scale: params.speed_harmonic_scale,
+            decay_exponent: params.speed_decay_exponent,
             object_difficulties: Vec::with_capacity(256),
             slider_strains: Vec::with_capacity(64),
         }
@@ -43,6 +51,12 @@ impl Speed {
         self.object_difficulties.push(difficulty);
     }
 
+    /// See `StrainSkill::discard_history`.
+    pub fn discard_history(&mut self) {
+        self.object_difficulties.clear();
+        self.slider_strains.clear();
+    }
+
     fn object_difficulty_of(
         &mut self,
         curr: &OsuDifficultyObject<'_>,
@@ -56,7 +70,7 @@ impl Speed {
         self.current_strain *= decay;
 
         let mut adjusted_difficulty =
-            SpeedEvaluator::evaluate_diff_of(curr, objects, self.hit_window);
+            SpeedEvaluator::evaluate_diff_of(curr, objects, self.hit_window, self.tapping_style);
 
         if self.has_autopilot_mod {
             adjusted_difficulty *= 0.5;
@@ -88,8 +102,8 @@ impl Speed {
 
         for (index, object) in difficulties.into_iter().enumerate() {
             let index = index as f64;
-            let harmonic = Self::HARMONIC_SCALE / (1.0 + index);
-            let weight = (1.0 + harmonic) / (index.powf(Self::DECAY_EXPONENT) + 1.0 + harmonic);
+            let harmonic = self.harmonic_scale / (1.0 + index);
+            let weight = (1.0 + harmonic) / (index.powf(self.decay_exponent) + 1.0 + harmonic);
 
             object_weight_sum += weight;
             difficulty += object * weight;
@@ -180,6 +194,50 @@ impl Speed {
     }
 }
 
+/// How the player is assumed to tap.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum TappingStyle {
+    /// Alternating between two keys, possibly double-tapping.
+    ///
+    /// The Alternate mod only forbids pressing the same key twice in a row
+    /// which is what upstream assumes already.
+    Alternate,
+    /// A single key for all objects.
+    ///
+    /// See [`OsuParameters::single_tap_bonus_bpm`] and
+    /// [`OsuParameters::single_tap_doubletapness`].
+    SingleTap { bonus_bpm: f64, doubletapness: f64 },
+}
+
+impl TappingStyle {
+    fn new(mods: &GameMods, algorithm: AlgorithmVersion, params: &OsuParameters) -> Self {
+        if mods.single_tap() && algorithm.fork_adjustments() {
+            Self::SingleTap {
+                bonus_bpm: params.single_tap_bonus_bpm,
+                doubletapness: params.single_tap_doubletapness,
+            }
+        } else {
+            Self::Alternate
+        }
+    }
+
+    /// BPM above which the speed bonus is applied.
+    const fn bonus_bpm(self) -> f64 {
+        match self {
+            Self::Alternate => 200.0,
+            Self::SingleTap { bonus_bpm, .. } => bonus_bpm,
+        }
+    }
+
+    /// How much the doubletapness reduces the speed difficulty.
+    const fn doubletapness(self) -> f64 {
+        match self {
+            Self::Alternate => 1.0,
+            Self::SingleTap { doubletapness, .. } => doubletapness,
+        }
+    }
+}
+
 struct SpeedEvaluator;
 
 impl SpeedEvaluator {
@@ -187,19 +245,25 @@ impl SpeedEvaluator {
         curr: &OsuDifficultyObject<'_>,
         objects: &[OsuDifficultyObject<'_>],
         hit_window: f64,
+        tapping_style: TappingStyle,
     ) -> f64 {
         if curr.base.is_spinner() {
             return 0.0;
         }
 
         let mut strain_time = curr.strain_time;
-        let double_tap_feasibility =
-            1.0 - curr.get_doubletapness(curr.next(0, objects), hit_window);
+
+        // fork 独自: キーが 1 つしか無いと 2 つのノーツを同時押しで処理できない
+        let double_tap_feasibility = 1.0
+            - tapping_style.doubletapness()
+                * curr.get_doubletapness(curr.next(0, objects), hit_window);
 
         strain_time /= ((strain_time / hit_window) / 0.93).clamp(0.92, 1.0);
 
-        let speed_bonus = if milliseconds_to_bpm(strain_time, None) > 200.0 {
-            0.75 * ((bpm_to_milliseconds(200.0, None) - strain_time) / 40.0).powi(2)
+        let bonus_bpm = tapping_style.bonus_bpm();
+
+        let speed_bonus = if milliseconds_to_bpm(strain_time, None) > bonus_bpm {
+            0.75 * ((bpm_to_milliseconds(bonus_bpm, None) - strain_time) / 40.0).powi(2)
         } else {
             0.0
         };
@@ -0,0 +1,328 @@
+use std::fmt;
+
+use rosu_map::{
+    section::{
+        general::GameMode,
+        hit_objects::{
+            BorrowedCurve, CurveBuffers, SliderEvent, SliderEventType, SliderEventsIter,
+        },
+    },
+    util::Pos,
+};
+
+use crate::{
+    model::{
+        control_point::{DifficultyPoint, TimingPoint},
+        hit_object::{HitObject, HitObjectKind, Slider},
+    },
+    util::{get_precision_adjusted_beat_len, sort},
+    Beatmap,
+};
+
+use super::object::{NestedSliderObject, NestedSliderObjectKind, OsuObject};
+
+/// The geometry and timing of a slider as used by osu!standard difficulty
+/// calculation.
+///
+/// Positions are not adjusted for stacking or mods.
+///
+/// # Example
+///
+/// ```
+/// use mames_pp::{model::hit_object::CurveBuffers, osu::SliderGeometry, Beatmap};
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// let mut bufs = CurveBuffers::default();
+///
+/// let h = map.hit_objects.iter().find(|h| h.is_slider()).unwrap();
+/// let slider = SliderGeometry::new(&map, h, &mut bufs).unwrap();
+///
+/// assert_eq!(slider.position_at(0.0), h.pos);
+/// assert_eq!(slider.position_at(1.0), slider.end_pos());
+///
+/// for pos in slider.sampled_path(32) {
+///     println!("({}, {})", pos.x, pos.y);
+/// }
+///
+/// for nested in slider.nested_objects.iter() {
+///     println!("{:?} at {}ms", nested.kind, nested.start_time);
+/// }
+/// ```
+pub struct SliderGeometry<'a> {
+    /// The position of the slider head.
+    pub pos: Pos,
+    pub start_time: f64,
+    pub end_time: f64,
+    /// The amount of times the slider's path is traversed.
+    pub span_count: usize,
+    /// The duration of a single span in milliseconds.
+    pub span_duration: f64,
+    /// The velocity in osu!pixels per millisecond.
+    pub velocity: f64,
+    /// Ticks, repeats, and the tail in the order that difficulty calculation
+    /// processes them.
+    ///
+    /// Note that the tail is not necessarily the last nested object, e.g. on
+    /// very short and fast sliders.
+    pub nested_objects: Vec<NestedSliderObject>,
+    path: BorrowedCurve<'a>,
+}
+
+impl<'a> SliderGeometry<'a> {
+    /// Calculate the geometry of a [`HitObject`] of the [`Beatmap`].
+    ///
+    /// Returns `None` if the hit object is not a slider.
+    pub fn new(map: &Beatmap, h: &HitObject, bufs: &'a mut CurveBuffers) -> Option<Self> {
+        let HitObjectKind::Slider(ref slider) = h.kind else {
+            return None;
+        };
+
+        Some(Self::calculate(
+            h.pos,
+            h.start_time,
+            slider,
+            map,
+            bufs,
+            &mut Vec::new(),
+        ))
+    }
+
+    /// Positions are relative to `pos`; `OsuSlider` passes the origin so
+    /// that `h.pos` can be added after stacking.
+    pub(crate) fn calculate(
+        pos: Pos,
+        start_time: f64,
+        slider: &Slider,
+        map: &Beatmap,
+        curve_bufs: &'a mut CurveBuffers,
+        ticks_buf: &mut Vec<SliderEvent>,
+    ) -> Self {
+        let slider_multiplier = map.slider_multiplier;
+        let slider_tick_rate = map.slider_tick_rate;
+
+        let beat_len = map
+            .timing_point_at(start_time)
+            .map_or(TimingPoint::DEFAULT_BEAT_LEN, |point| point.beat_len);
+
+        let (slider_velocity, generate_ticks) = map.difficulty_point_at(start_time).map_or(
+            (
+                DifficultyPoint::DEFAULT_SLIDER_VELOCITY,
+                DifficultyPoint::DEFAULT_GENERATE_TICKS,
+            ),
+            |point| (point.slider_velocity, point.generate_ticks),
+        );
+
+        let path = slider.curve(GameMode::Osu, curve_bufs);
+        let path_dist = path.dist();
+
+        let span_count = slider.span_count() as f64;
+
+        let velocity = f64::from(OsuObject::BASE_SCORING_DIST) * slider_multiplier
+            / get_precision_adjusted_beat_len(slider_velocity, beat_len);
+        let scoring_dist = velocity * beat_len;
+
+        let end_time = start_time + span_count * path_dist / velocity;
+
+        let duration = end_time - start_time;
+        let span_duration = duration / span_count;
+
+        let tick_dist_multiplier = if map.version < 8 {
+            slider_velocity.recip()
+        } else {
+            1.0
+        };
+
+        let tick_dist = if generate_ticks {
+            scoring_dist / slider_tick_rate * tick_dist_multiplier
+        } else {
+            f64::INFINITY
+        };
+
+        let mut geometry = Self {
+            pos,
+            start_time,
+            end_time,
+            span_count: slider.span_count(),
+            span_duration,
+            velocity,
+            nested_objects: Vec::new(),
+            path,
+        };
+
+        let events = SliderEventsIter::new(
+            start_time,
+            span_duration,
+            velocity,
+            tick_dist,
+            path_dist,
+            slider.span_count() as i32,
+            ticks_buf,
+        );
+
+        let end_pos = geometry.position_at(1.0);
+
+        let mut nested_objects: Vec<_> = events
+            .filter_map(|e| {
+                let obj = match e.kind {
+                    SliderEventType::Tick => NestedSliderObject {
+                        pos: geometry.path_position_at(e.path_progress),
+                        start_time: e.time,
+                        kind: NestedSliderObjectKind::Tick,
+                    },
+                    SliderEventType::Repeat => NestedSliderObject {
+                        pos: geometry.path_position_at(e.path_progress),
+                        start_time: start_time + f64::from(e.span_idx + 1) * span_duration,
+                        kind: NestedSliderObjectKind::Repeat,
+                    },
+                    SliderEventType::Tail => NestedSliderObject {
+                        pos: end_pos,
+                        start_time: e.time,
+                        kind: NestedSliderObjectKind::Tail,
+                    },
+                    SliderEventType::Head | SliderEventType::LastTick => return None,
+                };
+
+                Some(obj)
+            })
+            .collect();
+
+        sort::csharp(&mut nested_objects, |a, b| {
+            a.start_time.total_cmp(&b.start_time)
+        });
+
+        geometry.nested_objects = nested_objects;
+
+        geometry
+    }
+
+    /// The length of the slider's path in osu!pixels.
+    pub fn path_len(&self) -> f64 {
+        self.path.dist()
+    }
+
+    /// The duration of the slider in milliseconds.
+    pub fn duration(&self) -> f64 {
+        self.end_time - self.start_time
+    }
+
+    /// The start time of the span with the given index.
+    pub fn span_start_time(&self, span_idx: usize) -> f64 {
+        self.start_time + span_idx as f64 * self.span_duration
+    }
+
+    /// The position of the slider ball at the given progress throughout the
+    /// whole slider, including repeats.
+    ///
+    /// `0.0` is the slider's start and `1.0` its end.
+    pub fn position_at(&self, progress: f64) -> Pos {
+        self.path_position_at(self.path_progress_at(progress))
+    }
+
+    /// The position of the slider ball at the given time.
+    pub fn position_at_time(&self, time: f64) -> Pos {
+        let duration = self.duration();
+
+        let progress = if duration > 0.0 {
+            ((time - self.start_time) / duration).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        self.position_at(progress)
+    }
+
+    /// The position at the given progress along the path, disregarding
+    /// repeats.
+    pub fn path_position_at(&self, path_progress: f64) -> Pos {
+        self.pos + self.path.position_at(path_progress)
+    }
+
+    /// The position at which the slider ends.
+    pub fn end_pos(&self) -> Pos {
+        self.position_at(1.0)
+    }
+
+    /// `count` positions evenly spaced along the path, including both its
+    /// start and end.
+    pub fn sampled_path(&self, count: usize) -> impl Iterator<Item = Pos> + '_ {
+        let last = count.saturating_sub(1).max(1) as f64;
+
+        (0..count).map(move |i| self.path_position_at(i as f64 / last))
+    }
+
+    /// See `SliderEventGenerator.ProgressAt`
+    fn path_progress_at(&self, progress: f64) -> f64 {
+        let span_count = self.span_count as f64;
+        let p = progress * span_count % 1.0;
+
+        if (progress * span_count) as i32 % 2 == 1 {
+            1.0 - p
+        } else {
+            p
+        }
+    }
+}
+
+impl fmt::Debug for SliderGeometry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SliderGeometry")
+            .field("pos", &self.pos)
+            .field("start_time", &self.start_time)
+            .field("end_time", &self.end_time)
+            .field("span_count", &self.span_count)
+            .field("span_duration", &self.span_duration)
+            .field("velocity", &self.velocity)
+            .field("nested_objects", &self.nested_objects)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeat_turns_around() {
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+        let mut bufs = CurveBuffers::default();
+
+        let (h, repeats) = map
+            .hit_objects
+            .iter()
+            .find_map(|h| match h.kind {
+                HitObjectKind::Slider(ref slider) if slider.repeats > 0 => {
+                    Some((h, slider.repeats))
+                }
+                _ => None,
+            })
+            .unwrap();
+
+        let slider = SliderGeometry::new(&map, h, &mut bufs).unwrap();
+
+        assert_eq!(slider.span_count, repeats + 1);
+        assert!((slider.duration() - slider.span_duration * slider.span_count as f64).abs() < 1e-6);
+
+        let turn = slider.path_position_at(1.0);
+        let pos = slider.position_at_time(slider.span_start_time(1));
+        assert!((pos.x - turn.x).abs() < 1e-3 && (pos.y - turn.y).abs() < 1e-3);
+
+        let repeat = slider
+            .nested_objects
+            .iter()
+            .find(|nested| nested.is_repeat())
+            .unwrap();
+
+        assert_eq!(repeat.pos, turn);
+        assert!((repeat.start_time - slider.span_start_time(1)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn no_geometry_for_circles() {
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+        let mut bufs = CurveBuffers::default();
+
+        let h = map.hit_objects.iter().find(|h| h.is_circle()).unwrap();
+
+        assert!(SliderGeometry::new(&map, h, &mut bufs).is_none());
+    }
+}
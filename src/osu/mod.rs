@@ -11,17 +11,26 @@ use crate::{
 pub use self::{
     attributes::{OsuDifficultyAttributes, OsuPerformanceAttributes},
     difficulty::gradual::OsuGradualDifficulty,
+    geometry::SliderGeometry,
+    movement::{OsuHistogram, OsuMovement, OsuMovementObject, OsuOccupancyGrid},
+    object::{NestedSliderObject, NestedSliderObjectKind},
+    parameters::OsuParameters,
     performance::{gradual::OsuGradualPerformance, OsuPerformance},
     score_state::{OsuScoreOrigin, OsuScoreState},
+    stacking::{OsuStackedObject, OsuStacking},
     strains::OsuStrains,
 };
 
 mod attributes;
 mod convert;
 mod difficulty;
+mod geometry;
+mod movement;
 mod object;
+mod parameters;
 mod performance;
 mod score_state;
+mod stacking;
 mod strains;
 
 const PLAYFIELD_BASE_SIZE: Pos = Pos::new(512.0, 384.0);
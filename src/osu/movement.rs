@@ -0,0 +1,414 @@
+use std::{cmp, f64::consts::PI, pin::Pin};
+
+use rosu_map::{section::general::GameMode, util::Pos};
+
+use crate::{
+    any::difficulty::{object::IDifficultyObject, section::SectionWindow},
+    model::mode::ConvertError,
+    Beatmap, Difficulty,
+};
+
+use super::{
+    convert::convert_objects,
+    difficulty::{skills::aim::Aim, DifficultyValues, OsuDifficultySetup},
+    object::OsuObject,
+    PLAYFIELD_BASE_SIZE,
+};
+
+/// Movement statistics of an osu!standard map.
+///
+/// Everything is gathered from the same difficulty objects that the aim
+/// skill processes, i.e. the first hit object is not included and
+/// [`Difficulty::passed_objects`] is respected.
+///
+/// Distances are normalized so that the circle radius is 50 osu!pixels, same
+/// as in difficulty calculation.
+///
+/// See [`Difficulty::osu_movement`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct OsuMovement {
+    /// All difficulty objects in order.
+    pub objects: Vec<OsuMovementObject>,
+    /// Histogram of `jump_dist` of all objects that are not preceded by
+    /// or are a spinner.
+    pub jump_dists: OsuHistogram,
+    /// Histogram of angles in radians.
+    pub angles: OsuHistogram,
+    /// Histogram of `velocity` of all objects that are not preceded by or
+    /// are a spinner.
+    pub velocities: OsuHistogram,
+    /// How often circles and slider heads appear in which part of the
+    /// playfield.
+    pub occupancy: OsuOccupancyGrid,
+    /// Aim difficulty without sliders divided by aim difficulty with
+    /// sliders, same as [`OsuDifficultyAttributes::slider_factor`].
+    ///
+    /// [`OsuDifficultyAttributes::slider_factor`]: crate::osu::OsuDifficultyAttributes::slider_factor
+    pub slider_factor: f64,
+}
+
+impl OsuMovement {
+    /// Bin width of [`OsuMovement::jump_dists`] in normalized osu!pixels.
+    pub const JUMP_DIST_BIN_WIDTH: f64 = 25.0;
+    /// Maximum amount of bins of [`OsuMovement::jump_dists`]; longer jumps
+    /// count towards the last bin.
+    pub const JUMP_DIST_MAX_BINS: usize = 64;
+    /// Bin width of [`OsuMovement::angles`] in radians, i.e. 10 degrees.
+    pub const ANGLE_BIN_WIDTH: f64 = PI / 18.0;
+    /// Maximum amount of bins of [`OsuMovement::angles`], i.e. up to 180
+    /// degrees.
+    pub const ANGLE_MAX_BINS: usize = 18;
+    /// Bin width of [`OsuMovement::velocities`] in normalized osu!pixels per
+    /// millisecond.
+    pub const VELOCITY_BIN_WIDTH: f64 = 0.25;
+    /// Maximum amount of bins of [`OsuMovement::velocities`]; higher
+    /// velocities count towards the last bin.
+    pub const VELOCITY_MAX_BINS: usize = 64;
+    /// Side length of an [`OsuOccupancyGrid`] cell in osu!pixels.
+    pub const OCCUPANCY_CELL_SIZE: f32 = 32.0;
+
+    /// The share of aim difficulty that stems from sliders, between `0.0`
+    /// and `1.0`.
+    ///
+    /// Derived from [`OsuMovement::slider_factor`], i.e. how much the aim
+    /// difficulty drops when slider travel is ignored.
+    pub fn slider_aim_share(&self) -> f64 {
+        (1.0 - self.slider_factor).clamp(0.0, 1.0)
+    }
+}
+
+/// A difficulty object of an osu!standard map as seen by the aim skill.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OsuMovementObject {
+    /// The start time in milliseconds, not adjusted to the clock rate.
+    pub start_time: f64,
+    /// The position of the hit object as shown during gameplay.
+    pub stacked_pos: Pos,
+    /// Whether the hit object is a slider.
+    pub is_slider: bool,
+    /// Whether the hit object is a spinner.
+    pub is_spinner: bool,
+    /// Distance from the previous object's lazy cursor end position.
+    pub jump_dist: f64,
+    /// Shortest distance from the previous object, considering its slider
+    /// tail.
+    pub min_jump_dist: f64,
+    /// Distance travelled within this object if it's a slider.
+    pub travel_dist: f64,
+    /// Angle in radians between this, the previous, and the object before
+    /// that.
+    pub angle: Option<f64>,
+    /// Milliseconds since the previous object, adjusted to the clock rate
+    /// and capped at a minimum of 25ms.
+    pub strain_time: f64,
+    /// Cursor velocity in normalized osu!pixels per millisecond, including
+    /// the travel distance of a preceding slider.
+    pub velocity: f64,
+    /// The aim strain after processing this object.
+    pub aim_strain: f64,
+}
+
+/// Value counts within bins of equal width, starting at zero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OsuHistogram {
+    /// The width of each bin.
+    pub bin_width: f64,
+    /// The amount of values in each bin; bin `i` covers values in
+    /// `[i * bin_width, (i + 1) * bin_width)`.
+    pub counts: Vec<u32>,
+}
+
+impl OsuHistogram {
+    const fn new(bin_width: f64) -> Self {
+        Self {
+            bin_width,
+            counts: Vec::new(),
+        }
+    }
+
+    /// The total amount of values.
+    pub fn total(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+
+    /// Adds a value, putting it into the last bin if it would exceed
+    /// `max_bins`.
+    fn add(&mut self, value: f64, max_bins: usize) {
+        let bin = cmp::min((value.max(0.0) / self.bin_width) as usize, max_bins - 1);
+
+        if bin >= self.counts.len() {
+            self.counts.resize(bin + 1, 0);
+        }
+
+        self.counts[bin] += 1;
+    }
+}
+
+/// Hit object counts of a grid laid over the playfield.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OsuOccupancyGrid {
+    /// Side length of a cell in osu!pixels.
+    pub cell_size: f32,
+    /// Amount of cells along the x-axis.
+    pub columns: usize,
+    /// Amount of cells along the y-axis.
+    pub rows: usize,
+    /// Row-major counts of hit objects per cell.
+    pub counts: Vec<u32>,
+}
+
+impl OsuOccupancyGrid {
+    fn new(cell_size: f32) -> Self {
+        let columns = (PLAYFIELD_BASE_SIZE.x / cell_size).ceil() as usize;
+        let rows = (PLAYFIELD_BASE_SIZE.y / cell_size).ceil() as usize;
+
+        Self {
+            cell_size,
+            columns,
+            rows,
+            counts: vec![0; columns * rows],
+        }
+    }
+
+    /// The count of the given cell.
+    pub fn get(&self, column: usize, row: usize) -> Option<u32> {
+        if column >= self.columns {
+            return None;
+        }
+
+        self.counts.get(row * self.columns + column).copied()
+    }
+
+    /// Adds a position; positions outside of the playfield count towards
+    /// the nearest cell.
+    fn add(&mut self, pos: Pos) {
+        let cell =
+            |value: f32, len: usize| cmp::min((value.max(0.0) / self.cell_size) as usize, len - 1);
+
+        let idx = cell(pos.y, self.rows) * self.columns + cell(pos.x, self.columns);
+        self.counts[idx] += 1;
+    }
+}
+
+impl Difficulty {
+    /// Gather movement statistics of an osu!standard map with respect to
+    /// mods.
+    ///
+    /// Maps of other modes cannot be converted and return an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mames_pp::{Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let movement = Difficulty::new().mods(64).osu_movement(&map).unwrap(); // DT
+    ///
+    /// println!("{:.1}% of aim difficulty stems from sliders", 100.0 * movement.slider_aim_share());
+    ///
+    /// for (i, count) in movement.velocities.counts.iter().enumerate() {
+    ///     let velocity = i as f64 * movement.velocities.bin_width;
+    ///     println!("{velocity:.2}px/ms: {count}");
+    /// }
+    /// ```
+    pub fn osu_movement(&self, map: &Beatmap) -> Result<OsuMovement, ConvertError> {
+        let map = map.convert_ref(GameMode::Osu, self.get_mods())?;
+        let take = self.get_passed_objects();
+
+        let OsuDifficultySetup {
+            scaling_factor,
+            mut attrs,
+            time_preempt,
+            ..
+        } = OsuDifficultySetup::new(self, &map);
+
+        let mut osu_objects = convert_objects(
+            &map,
+            &scaling_factor,
+            self.get_mods().reflection(),
+            time_preempt,
+            take,
+            &SectionWindow::default(),
+            &mut attrs,
+        );
+
+        let first_is_spinner = osu_objects.first().is_some_and(OsuObject::is_spinner);
+
+        let diff_objects = DifficultyValues::create_difficulty_objects(
+            self,
+            &scaling_factor,
+            osu_objects.iter_mut().map(Pin::new),
+            time_preempt,
+            (79.5 - attrs.great_hit_window) / 6.0,
+        );
+
+        // Mirrors `DifficultyValues::calculate` so that strains match
+        let params = self.get_osu_parameters();
+        let mut aim = Aim::new(self.get_mods(), true, params);
+        let mut aim_no_sliders = Aim::new(self.get_mods(), false, params);
+        let take_diff_objects = cmp::min(map.hit_objects.len(), take).saturating_sub(1);
+        let processed = &diff_objects[..take_diff_objects.min(diff_objects.len())];
+
+        for curr in processed {
+            aim.process(curr, &diff_objects);
+            aim_no_sliders.process(curr, &diff_objects);
+        }
+
+        // See `DifficultyValues::eval`
+        let aim_rating =
+            |aim: &Aim| aim.cloned_difficulty_value().powf(0.63) * params.aim_difficulty_multiplier;
+
+        let aim_with_sliders = aim_rating(&aim);
+
+        let slider_factor = if aim_with_sliders > 0.0 {
+            aim_rating(&aim_no_sliders) / aim_with_sliders
+        } else {
+            1.0
+        };
+
+        let mut movement = OsuMovement {
+            objects: Vec::with_capacity(processed.len()),
+            jump_dists: OsuHistogram::new(OsuMovement::JUMP_DIST_BIN_WIDTH),
+            angles: OsuHistogram::new(OsuMovement::ANGLE_BIN_WIDTH),
+            velocities: OsuHistogram::new(OsuMovement::VELOCITY_BIN_WIDTH),
+            occupancy: OsuOccupancyGrid::new(OsuMovement::OCCUPANCY_CELL_SIZE),
+            slider_factor,
+        };
+
+        for (curr, &aim_strain) in processed.iter().zip(aim.object_strains()) {
+            let last = curr.previous(0, &diff_objects);
+
+            let mut velocity = curr.lazy_jump_dist / curr.strain_time;
+
+            // See `SnapAimEvaluator`
+            if let Some(last) = last.filter(|last| last.base.is_slider()) {
+                let slider_dist = last.lazy_travel_dist + curr.lazy_jump_dist;
+                velocity = velocity.max(slider_dist / curr.strain_time);
+            }
+
+            let object = OsuMovementObject {
+                start_time: curr.base.start_time,
+                stacked_pos: curr.base.stacked_pos(),
+                is_slider: curr.base.is_slider(),
+                is_spinner: curr.base.is_spinner(),
+                jump_dist: curr.lazy_jump_dist,
+                min_jump_dist: curr.min_jump_dist,
+                travel_dist: curr.travel_dist,
+                angle: curr.angle,
+                strain_time: curr.strain_time,
+                velocity,
+                aim_strain,
+            };
+
+            let after_spinner = last.map_or(first_is_spinner, |last| last.base.is_spinner());
+
+            if !object.is_spinner {
+                movement.occupancy.add(object.stacked_pos);
+
+                if !after_spinner {
+                    movement
+                        .jump_dists
+                        .add(object.jump_dist, OsuMovement::JUMP_DIST_MAX_BINS);
+                    movement
+                        .velocities
+                        .add(object.velocity, OsuMovement::VELOCITY_MAX_BINS);
+                }
+            }
+
+            if let Some(angle) = object.angle {
+                movement.angles.add(angle, OsuMovement::ANGLE_MAX_BINS);
+            }
+
+            movement.objects.push(object);
+        }
+
+        Ok(movement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{beatmap::BeatmapBuilder, hit_object::PathType},
+        osu::Osu,
+    };
+
+    use super::*;
+
+    #[test]
+    fn statistics() {
+        let map = BeatmapBuilder::new(GameMode::Osu)
+            .cs(4.0)
+            .timing_point(0.0, 500.0)
+            .circle(Pos::new(0.0, 0.0), 0.0)
+            .circle(Pos::new(200.0, 0.0), 250.0)
+            .circle(Pos::new(200.0, 200.0), 500.0)
+            .slider(
+                Pos::new(0.0, 200.0),
+                750.0,
+                PathType::LINEAR,
+                &[Pos::new(0.0, 350.0)],
+                0,
+                None,
+            )
+            .circle(Pos::new(500.0, 380.0), 1500.0)
+            .build()
+            .unwrap();
+
+        let movement = Difficulty::new().osu_movement(&map).unwrap();
+
+        assert_eq!(movement.objects.len(), 4);
+        assert!(movement.objects[2].is_slider);
+        assert!(movement.objects[..2].iter().all(|h| h.angle.is_none()));
+
+        // CS4 normalizes distances by 50 / 36.49
+        let jump = movement.objects[0].jump_dist;
+        assert!((jump - 200.0 * 50.0 / 36.49).abs() < 0.5, "{jump}");
+        assert!((movement.objects[0].velocity - jump / 250.0).abs() < 1e-9);
+
+        // The slider's travel distance counts towards the following jump
+        let after_slider = movement.objects[3];
+        assert!(after_slider.velocity > after_slider.jump_dist / after_slider.strain_time);
+
+        assert_eq!(movement.jump_dists.total(), 4);
+        assert_eq!(movement.velocities.total(), 4);
+        assert_eq!(movement.angles.total(), 2);
+        assert!(movement.angles.counts.len() <= OsuMovement::ANGLE_MAX_BINS);
+
+        assert_eq!(movement.occupancy.counts.len(), 16 * 12);
+        assert_eq!(movement.occupancy.counts.iter().sum::<u32>(), 4);
+        assert_eq!(movement.occupancy.get(15, 11), Some(1));
+        assert_eq!(movement.occupancy.get(16, 0), None);
+
+        let share = movement.slider_aim_share();
+        assert!((0.0..1.0).contains(&share), "{share}");
+
+        let attrs = Difficulty::new().calculate_for_mode::<Osu>(&map).unwrap();
+        assert!((movement.slider_factor - attrs.slider_factor).abs() < 1e-12);
+    }
+
+    #[test]
+    fn capped_bins() {
+        let map = BeatmapBuilder::new(GameMode::Osu)
+            .cs(10.0)
+            .timing_point(0.0, 500.0)
+            .circle(Pos::new(-100_000.0, 0.0), 0.0)
+            .circle(Pos::new(100_000.0, 0.0), 10.0)
+            .circle(Pos::new(-100_000.0, 0.0), 20.0)
+            .build()
+            .unwrap();
+
+        let movement = Difficulty::new().osu_movement(&map).unwrap();
+
+        assert_eq!(
+            movement.jump_dists.counts.len(),
+            OsuMovement::JUMP_DIST_MAX_BINS
+        );
+        assert_eq!(
+            movement.velocities.counts.len(),
+            OsuMovement::VELOCITY_MAX_BINS
+        );
+        assert_eq!(movement.jump_dists.total(), 2);
+    }
+}
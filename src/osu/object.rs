@@ -1,23 +1,16 @@
 use std::borrow::Cow;
 
 use rosu_map::{
-    section::{
-        general::GameMode,
-        hit_objects::{CurveBuffers, SliderEvent, SliderEventType, SliderEventsIter},
-    },
+    section::hit_objects::{CurveBuffers, SliderEvent},
     util::Pos,
 };
 
 use crate::{
-    model::{
-        control_point::{DifficultyPoint, TimingPoint},
-        hit_object::{HitObject, HitObjectKind, HoldNote, Slider, Spinner},
-    },
-    util::{get_precision_adjusted_beat_len, sort},
+    model::hit_object::{HitObject, HitObjectKind, HoldNote, Slider, Spinner},
     Beatmap,
 };
 
-use super::PLAYFIELD_BASE_SIZE;
+use super::{geometry::SliderGeometry, PLAYFIELD_BASE_SIZE};
 
 pub struct OsuObject {
     pub pos: Pos,
@@ -31,7 +24,7 @@ impl OsuObject {
     pub const OBJECT_RADIUS: f32 = 64.0;
     pub const PREEMPT_MIN: f64 = 450.0;
 
-    const BASE_SCORING_DIST: f32 = 100.0;
+    pub const BASE_SCORING_DIST: f32 = 100.0;
 
     pub fn new(
         h: &HitObject,
@@ -189,100 +182,21 @@ impl OsuSlider {
         ticks_buf: &mut Vec<SliderEvent>,
     ) -> Self {
         let start_time = h.start_time;
-        let slider_multiplier = map.slider_multiplier;
-        let slider_tick_rate = map.slider_tick_rate;
-
-        let beat_len = map
-            .timing_point_at(start_time)
-            .map_or(TimingPoint::DEFAULT_BEAT_LEN, |point| point.beat_len);
-
-        let (slider_velocity, generate_ticks) = map.difficulty_point_at(start_time).map_or(
-            (
-                DifficultyPoint::DEFAULT_SLIDER_VELOCITY,
-                DifficultyPoint::DEFAULT_GENERATE_TICKS,
-            ),
-            |point| (point.slider_velocity, point.generate_ticks),
-        );
-
-        let path = slider.curve(GameMode::Osu, curve_bufs);
-
-        let span_count = slider.span_count() as f64;
-
-        let velocity = f64::from(OsuObject::BASE_SCORING_DIST) * slider_multiplier
-            / get_precision_adjusted_beat_len(slider_velocity, beat_len);
-        let scoring_dist = velocity * beat_len;
-
-        let end_time = start_time + span_count * path.dist() / velocity;
 
-        let duration = end_time - start_time;
-        let span_duration = duration / span_count;
-
-        let tick_dist_multiplier = if map.version < 8 {
-            slider_velocity.recip()
-        } else {
-            1.0
-        };
-
-        let tick_dist = if generate_ticks {
-            scoring_dist / slider_tick_rate * tick_dist_multiplier
-        } else {
-            f64::INFINITY
-        };
-
-        let events = SliderEventsIter::new(
+        let geometry = SliderGeometry::calculate(
+            Pos::default(), // no `h.pos` yet to keep order of float operations
             start_time,
-            span_duration,
-            velocity,
-            tick_dist,
-            path.dist(),
-            slider.span_count() as i32,
+            slider,
+            map,
+            curve_bufs,
             ticks_buf,
         );
 
-        let span_at = |progress: f64| (progress * span_count) as i32;
-
-        let obj_progress_at = |progress: f64| {
-            let p = progress * span_count % 1.0;
-
-            if span_at(progress) % 2 == 1 {
-                1.0 - p
-            } else {
-                p
-            }
-        };
-
-        let end_path_pos = path.position_at(obj_progress_at(1.0));
-
-        let mut nested_objects: Vec<_> = events
-            .filter_map(|e| {
-                let obj = match e.kind {
-                    SliderEventType::Tick => NestedSliderObject {
-                        pos: path.position_at(e.path_progress),
-                        start_time: e.time,
-                        kind: NestedSliderObjectKind::Tick,
-                    },
-                    SliderEventType::Repeat => NestedSliderObject {
-                        pos: path.position_at(e.path_progress),
-                        start_time: start_time + f64::from(e.span_idx + 1) * span_duration,
-                        kind: NestedSliderObjectKind::Repeat,
-                    },
-                    SliderEventType::Tail => NestedSliderObject {
-                        pos: end_path_pos, // no `h.pos` yet to keep order of float operations
-                        start_time: e.time,
-                        kind: NestedSliderObjectKind::Tail,
-                    },
-                    SliderEventType::Head | SliderEventType::LastTick => return None,
-                };
-
-                Some(obj)
-            })
-            .collect();
-
-        sort::csharp(&mut nested_objects, |a, b| {
-            a.start_time.total_cmp(&b.start_time)
-        });
+        let end_time = geometry.end_time;
+        let duration = geometry.duration();
+        let span_duration = geometry.span_duration;
 
-        let mut nested = Cow::Borrowed(nested_objects.as_slice());
+        let mut nested = Cow::Borrowed(geometry.nested_objects.as_slice());
         let lazy_travel_time = OsuSlider::lazy_travel_time(start_time, duration, &mut nested);
 
         let mut end_time_min = lazy_travel_time / span_duration;
@@ -293,14 +207,14 @@ impl OsuSlider {
             end_time_min %= 1.0;
         }
 
-        let lazy_end_pos = path.position_at(end_time_min);
+        let lazy_end_pos = geometry.path_position_at(end_time_min);
 
         Self {
             end_time,
             lazy_end_pos,
             lazy_travel_dist: 0.0,
             lazy_travel_time,
-            nested_objects,
+            nested_objects: geometry.nested_objects,
         }
     }
 
@@ -366,7 +280,8 @@ impl OsuSlider {
     }
 }
 
-#[derive(Clone, Debug)]
+/// A tick, repeat, or tail of a slider.
+#[derive(Clone, Debug, PartialEq)]
 pub struct NestedSliderObject {
     pub pos: Pos,
     pub start_time: f64,
@@ -383,7 +298,8 @@ impl NestedSliderObject {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+/// The kind of a [`NestedSliderObject`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum NestedSliderObjectKind {
     Repeat,
     Tail,
@@ -0,0 +1,153 @@
+use super::performance::{PERFORMANCE_BASE_MULTIPLIER, PERFORMANCE_NORM_EXPONENT};
+
+/// Tunable constants of the osu!standard difficulty and performance
+/// calculation.
+///
+/// [`OsuParameters::default`] holds the compiled values so only the fields of
+/// interest need to be overridden. With the `serde` feature, missing fields
+/// are deserialized as their default value.
+///
+/// Specified through [`Difficulty::osu_parameters`].
+///
+/// # Example
+///
+/// ```
+/// use mames_pp::{osu::OsuParameters, Beatmap, Difficulty};
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+///
+/// let rework = OsuParameters {
+///     performance_base_multiplier: 1.1,
+///     miss_penalty_multiplier: 0.9,
+///     ..OsuParameters::default()
+/// };
+///
+/// let live = Difficulty::new().calculate(&map);
+/// let proposed = Difficulty::new().osu_parameters(rework).calculate(&map);
+///
+/// assert!(proposed.stars() < live.stars());
+/// ```
+///
+/// [`Difficulty::osu_parameters`]: crate::Difficulty::osu_parameters
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct OsuParameters {
+    /// Scales the speed, reading, and flashlight ratings.
+    pub difficulty_multiplier: f64,
+    /// Scales the aim rating.
+    pub aim_difficulty_multiplier: f64,
+    /// Decay per second of the aim strain.
+    pub aim_strain_decay_base: f64,
+    /// Scales each object's speed difficulty in the harmonic sum.
+    pub speed_harmonic_scale: f64,
+    /// Decay of the weights of the speed harmonic sum.
+    pub speed_decay_exponent: f64,
+    /// BPM above which the speed bonus is applied with the SingleTap mod.
+    ///
+    /// Alternating players receive the bonus above 200 BPM; tapping with a
+    /// single key approaches its limit at a lower BPM.
+    ///
+    /// Only relevant for [`AlgorithmVersion::Mamestagram`].
+    ///
+    /// [`AlgorithmVersion::Mamestagram`]: crate::any::AlgorithmVersion::Mamestagram
+    pub single_tap_bonus_bpm: f64,
+    /// How much the doubletapness of an object reduces its speed difficulty
+    /// with the SingleTap mod.
+    ///
+    /// Alternating players may hit two close objects with both keys at once
+    /// which upstream fully accounts for, i.e. a value of `1.0`. With a
+    /// single key that is not possible so by default the doubletapness is
+    /// ignored.
+    ///
+    /// Only relevant for [`AlgorithmVersion::Mamestagram`].
+    ///
+    /// [`AlgorithmVersion::Mamestagram`]: crate::any::AlgorithmVersion::Mamestagram
+    pub single_tap_doubletapness: f64,
+    /// Scales each object's reading strain.
+    pub reading_skill_multiplier: f64,
+    /// Decay per second of the reading strain.
+    pub reading_strain_decay_base: f64,
+    /// Scales each object's flashlight strain.
+    pub flashlight_skill_multiplier: f64,
+    /// Decay per second of the flashlight strain.
+    pub flashlight_strain_decay_base: f64,
+    /// Scales the pp and the star rating.
+    ///
+    /// Only relevant for [`AlgorithmVersion::Lazer2026`] and for
+    /// [`AlgorithmVersion::Mamestagram`] without relax.
+    ///
+    /// [`AlgorithmVersion::Lazer2026`]: crate::any::AlgorithmVersion::Lazer2026
+    /// [`AlgorithmVersion::Mamestagram`]: crate::any::AlgorithmVersion::Mamestagram
+    pub performance_base_multiplier: f64,
+    /// Exponent of the norm combining the pp of each skill.
+    ///
+    /// The star rating always uses it. The relax performance calculation of
+    /// [`AlgorithmVersion::Mamestagram`] sums its skills with its own
+    /// exponent.
+    ///
+    /// [`AlgorithmVersion::Mamestagram`]: crate::any::AlgorithmVersion::Mamestagram
+    pub performance_norm_exponent: f64,
+    /// Multiplier of the miss penalty
+    /// `multiplier / (misses / (strain_factor * ln(difficult_strains)) + 1)`.
+    ///
+    /// The relax performance calculation of
+    /// [`AlgorithmVersion::Mamestagram`] only uses it for aim; its speed pp
+    /// has a relax specific miss penalty.
+    ///
+    /// [`AlgorithmVersion::Mamestagram`]: crate::any::AlgorithmVersion::Mamestagram
+    pub miss_penalty_multiplier: f64,
+    /// Strain factor of the miss penalty; see
+    /// [`miss_penalty_multiplier`](OsuParameters::miss_penalty_multiplier).
+    pub miss_penalty_strain_factor: f64,
+    /// Length bonus of the aim pp for a map without objects.
+    ///
+    /// Not used by the relax performance calculation of
+    /// [`AlgorithmVersion::Mamestagram`] which has its own length bonus.
+    ///
+    /// [`AlgorithmVersion::Mamestagram`]: crate::any::AlgorithmVersion::Mamestagram
+    pub length_bonus_base: f64,
+    /// Length bonus of the aim pp gained linearly up to 2000 objects.
+    ///
+    /// Not used by the relax performance calculation; see
+    /// [`length_bonus_base`](OsuParameters::length_bonus_base).
+    pub length_bonus_linear: f64,
+    /// Length bonus of the aim pp gained logarithmically beyond 2000 objects.
+    ///
+    /// Not used by the relax performance calculation; see
+    /// [`length_bonus_base`](OsuParameters::length_bonus_base).
+    pub length_bonus_log: f64,
+}
+
+impl OsuParameters {
+    /// The compiled parameters.
+    pub const DEFAULT: Self = Self {
+        difficulty_multiplier: 0.0675,
+        aim_difficulty_multiplier: 0.02275,
+        aim_strain_decay_base: 0.2,
+        speed_harmonic_scale: 20.0,
+        speed_decay_exponent: 0.9,
+        single_tap_bonus_bpm: 150.0,
+        single_tap_doubletapness: 0.0,
+        reading_skill_multiplier: 2.5,
+        reading_strain_decay_base: 0.8,
+        flashlight_skill_multiplier: 0.058,
+        flashlight_strain_decay_base: 0.15,
+        performance_base_multiplier: PERFORMANCE_BASE_MULTIPLIER,
+        performance_norm_exponent: PERFORMANCE_NORM_EXPONENT,
+        miss_penalty_multiplier: 0.93,
+        miss_penalty_strain_factor: 4.0,
+        length_bonus_base: 0.95,
+        length_bonus_linear: 0.35,
+        length_bonus_log: 0.5,
+    };
+}
+
+impl Default for OsuParameters {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
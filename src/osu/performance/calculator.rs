@@ -1,14 +1,13 @@
-use std::f64::consts::PI;
-
 use crate::{
+    any::{deviation, trace::Trace, AlgorithmVersion},
     osu::{
         difficulty::skills::{aim::Aim, speed::Speed},
-        OsuDifficultyAttributes, OsuPerformanceAttributes, OsuScoreState,
+        OsuDifficultyAttributes, OsuParameters, OsuPerformanceAttributes, OsuScoreState,
     },
     util::{
         difficulty::reverse_lerp,
         float_ext::FloatExt,
-        special_functions::{erf, erf_inv},
+        special_functions::erf,
     },
     GameMods,
 };
@@ -22,11 +21,6 @@ pub const PERFORMANCE_NORM_EXPONENT: f64 = 1.1;
 // relax は本 fork の独自計算なので既存の値を保持
 pub const PERFORMANCE_BASE_MULTIPLIER_RELAX: f64 = 1.15;
 
-// DiffUtils.SQRT2 is deliberately one ULP below std::f64::consts::SQRT_2.
-// Retain that exact value because this feeds into lazer's speed deviation.
-#[allow(clippy::approx_constant, clippy::excessive_precision)]
-const LAZER_SQRT_2: f64 = 1.414_213_562_373_095_0;
-
 pub(super) struct OsuPerformanceCalculator<'mods> {
     attrs: OsuDifficultyAttributes,
     mods: &'mods GameMods,
@@ -36,6 +30,9 @@ pub(super) struct OsuPerformanceCalculator<'mods> {
     combo_based_estimated_miss_count: f64,
     score_based_estimated_miss_count: Option<f64>,
     using_classic_slider_acc: bool,
+    algorithm: AlgorithmVersion,
+    params: &'mods OsuParameters,
+    trace: Trace<'mods>,
 }
 
 impl<'a> OsuPerformanceCalculator<'a> {
@@ -48,6 +45,9 @@ impl<'a> OsuPerformanceCalculator<'a> {
         combo_based_estimated_miss_count: f64,
         score_based_estimated_miss_count: Option<f64>,
         using_classic_slider_acc: bool,
+        algorithm: AlgorithmVersion,
+        params: &'a OsuParameters,
+        trace: Trace<'a>,
     ) -> Self {
         Self {
             attrs,
@@ -58,6 +58,9 @@ impl<'a> OsuPerformanceCalculator<'a> {
             combo_based_estimated_miss_count,
             score_based_estimated_miss_count,
             using_classic_slider_acc,
+            algorithm,
+            params,
+            trace,
         }
     }
 }
@@ -73,27 +76,38 @@ impl OsuPerformanceCalculator<'_> {
             };
         }
 
-        // RX は fork の独自計算に routing
-        if self.mods.rx() {
-            return self.calculate_relax();
+        match self.algorithm {
+            // RX は fork の独自計算に routing
+            AlgorithmVersion::Mamestagram if self.mods.rx() => self.calculate_relax(),
+            // vanilla path (mode 0) は osu-master 2 完全一致で計算する
+            AlgorithmVersion::Lazer2026 | AlgorithmVersion::Mamestagram => self.calculate_vanilla(),
         }
-
-        // vanilla path (mode 0) は osu-master 2 完全一致で計算する
-        self.calculate_vanilla()
     }
 
     // upstream OsuPerformanceCalculator.CreatePerformanceAttributes を完全移植
     fn calculate_vanilla(self) -> OsuPerformanceAttributes {
         let total_hits = f64::from(self.state.total_hits());
 
-        let mut multiplier = PERFORMANCE_BASE_MULTIPLIER;
+        let mut multiplier = self.trace.factor(
+            "total",
+            "base multiplier",
+            self.params.performance_base_multiplier,
+        );
 
         if self.mods.nf() {
-            multiplier *= (1.0 - 0.02 * self.effective_miss_count).max(0.9);
+            multiplier *= self.trace.factor(
+                "total",
+                "no fail",
+                (1.0 - 0.02 * self.effective_miss_count).max(0.9),
+            );
         }
 
         if self.mods.so() && total_hits > 0.0 {
-            multiplier *= 1.0 - (f64::from(self.attrs.n_spinners) / total_hits).powf(0.85);
+            multiplier *= self.trace.factor(
+                "total",
+                "spun out",
+                1.0 - (f64::from(self.attrs.n_spinners) / total_hits).powf(0.85),
+            );
         }
 
         // Slider break estimation
@@ -118,14 +132,23 @@ impl OsuPerformanceCalculator<'_> {
 
         let reading_value = self.compute_reading_value_vanilla(aim_estimated_slider_breaks);
         let flashlight_value = self.compute_flashlight_value_vanilla();
-        let cognition_value = sum_cognition_difficulty(reading_value, flashlight_value);
+        let cognition_value = self.trace.value(
+            "cognition",
+            "reading and flashlight",
+            sum_cognition_difficulty(
+                self.params.performance_norm_exponent,
+                reading_value,
+                flashlight_value,
+            ),
+        );
 
         // upstream: totalValue = Norm(1.1, aim, speed, acc, cognition) * multiplier
         let total_norm = norm_pnorm(
-            PERFORMANCE_NORM_EXPONENT,
+            self.params.performance_norm_exponent,
             &[aim_value, speed_value, acc_value, cognition_value],
         );
-        let pp = total_norm * multiplier;
+        let pp = self.trace.value("total", "components", total_norm) * multiplier;
+        self.trace.value("total", "pp", pp);
 
         OsuPerformanceAttributes {
             difficulty: self.attrs,
@@ -155,11 +178,19 @@ impl OsuPerformanceCalculator<'_> {
         }
 
         let total_hits = f64::from(total_hits);
-        let mut multiplier = PERFORMANCE_BASE_MULTIPLIER_RELAX;
+        let mut multiplier = self.trace.factor(
+            "total",
+            "base multiplier",
+            PERFORMANCE_BASE_MULTIPLIER_RELAX,
+        );
 
         // SO penalty
         if self.mods.so() && total_hits > 0.0 {
-            multiplier *= 1.0 - (f64::from(self.attrs.n_spinners) / total_hits).powf(0.85);
+            multiplier *= self.trace.factor(
+                "total",
+                "spun out",
+                1.0 - (f64::from(self.attrs.n_spinners) / total_hits).powf(0.85),
+            );
         }
 
         let speed_deviation = self.calculate_speed_deviation();
@@ -171,14 +202,22 @@ impl OsuPerformanceCalculator<'_> {
         let aim_speed_ratio = aim_value / speed_value;
 
         if aim_speed_ratio < 1.0 {
-            speed_value = speed_value.powf(self.acc * aim_speed_ratio);
-            aim_value *= aim_speed_ratio;
+            speed_value = self.trace.value(
+                "speed",
+                "aim speed ratio",
+                speed_value.powf(self.acc * aim_speed_ratio),
+            );
+            aim_value *= self.trace.factor("aim", "aim speed ratio", aim_speed_ratio);
         }
 
-        aim_value = aim_value.powf(1.1);
-        acc_value = acc_value.powf(1.1);
+        aim_value = self.trace.value("aim", "exponent", aim_value.powf(1.1));
+        acc_value = self
+            .trace
+            .value("accuracy", "exponent", acc_value.powf(1.1));
 
-        let pp = (aim_value + speed_value + acc_value).powf(1.0 / 1.1) * multiplier;
+        let total = (aim_value + speed_value + acc_value).powf(1.0 / 1.1);
+        let pp = self.trace.value("total", "components", total) * multiplier;
+        self.trace.value("total", "pp", pp);
 
         OsuPerformanceAttributes {
             difficulty: self.attrs,
@@ -202,7 +241,9 @@ impl OsuPerformanceCalculator<'_> {
             return 0.0;
         }
 
-        let mut aim_difficulty = self.attrs.aim;
+        let mut aim_difficulty = self
+            .trace
+            .value("aim difficulty", "difficulty", self.attrs.aim);
 
         if self.attrs.n_sliders > 0 && self.attrs.aim_difficult_slider_count > 0.0 {
             let estimate_improperly_followed_difficult_sliders = if self.using_classic_slider_acc {
@@ -239,10 +280,16 @@ impl OsuPerformanceCalculator<'_> {
                     3.0,
                 )
                 + self.attrs.slider_factor;
-            aim_difficulty *= slider_nerf_factor;
+            aim_difficulty *=
+                self.trace
+                    .factor("aim difficulty", "slider nerf", slider_nerf_factor);
         }
 
-        let mut aim_value = Aim::difficulty_to_performance(aim_difficulty);
+        let mut aim_value = self.trace.value(
+            "aim",
+            "difficulty to performance",
+            Aim::difficulty_to_performance(aim_difficulty),
+        );
 
         let total_hits = self.total_hits();
 
@@ -250,27 +297,26 @@ impl OsuPerformanceCalculator<'_> {
             + 0.4 * (total_hits / 2000.0).min(1.0)
             + f64::from(u8::from(total_hits > 2000.0)) * (total_hits / 2000.0).log10() * 0.5;
 
-        aim_value *= len_bonus;
+        aim_value *= self.trace.factor("aim", "length bonus", len_bonus);
 
         if self.effective_miss_count > 0.0 {
-            aim_value *= Self::calculate_miss_penalty(
-                self.effective_miss_count,
-                self.attrs.aim_difficult_strain_count,
+            aim_value *= self.trace.factor(
+                "aim",
+                "miss penalty",
+                self.calculate_miss_penalty(
+                    self.effective_miss_count,
+                    self.attrs.aim_difficult_strain_count,
+                ),
             );
         }
 
+        // fork 独自: relax は通常の miss penalty に加えて独自の penalty を掛ける
         if self.effective_miss_count > 0.0 {
-            if self.mods.rx() {
-                aim_value *= Self::calculate_relax_miss_penalty(
-                    self.total_hits(),
-                    self.effective_miss_count,
-                );
-            } else {
-                aim_value *= Self::calculate_miss_penalty(
-                    self.effective_miss_count,
-                    self.attrs.aim_difficult_strain_count,
-                );
-            }
+            aim_value *= self.trace.factor(
+                "aim",
+                "relax miss penalty",
+                Self::calculate_relax_miss_penalty(self.total_hits(), self.effective_miss_count),
+            );
         }
 
         let ar_factor = if self.attrs.ar > 10.33 {
@@ -282,22 +328,33 @@ impl OsuPerformanceCalculator<'_> {
         };
 
         // * Buff for longer maps with high AR.
-        aim_value *= 1.0 + ar_factor * len_bonus;
+        aim_value *= self
+            .trace
+            .factor("aim", "AR bonus", 1.0 + ar_factor * len_bonus);
 
         if self.mods.bl() {
-            aim_value *= 1.3
-                + (total_hits
+            aim_value *= self.trace.factor(
+                "aim",
+                "blinds bonus",
+                1.3 + (total_hits
                     * (0.0016 / (1.0 + 2.0 * self.effective_miss_count))
                     * self.acc.powf(16.0))
-                    * (1.0 - 0.003 * self.attrs.hp * self.attrs.hp);
+                    * (1.0 - 0.003 * self.attrs.hp * self.attrs.hp),
+            );
         } else if self.mods.hd() || self.mods.tc() {
             // * We want to give more reward for lower AR when it comes to aim and HD. This nerfs high AR and buffs lower AR.
-            aim_value *= 1.0 + 0.04 * (12.0 - self.attrs.ar);
+            aim_value *=
+                self.trace
+                    .factor("aim", "hidden bonus", 1.0 + 0.04 * (12.0 - self.attrs.ar));
         }
 
-        aim_value *= self.acc;
+        aim_value *= self.trace.factor("aim", "accuracy", self.acc);
         // * It is important to consider accuracy difficulty when scaling with accuracy.
-        aim_value *= 0.98 + f64::powf(f64::max(0.0, self.attrs.od()), 2.0) / 2500.0;
+        aim_value *= self.trace.factor(
+            "aim",
+            "OD scaling",
+            0.98 + f64::powf(f64::max(0.0, self.attrs.od()), 2.0) / 2500.0,
+        );
 
         aim_value
     }
@@ -307,7 +364,11 @@ impl OsuPerformanceCalculator<'_> {
             return 0.0;
         };
 
-        let mut speed_value = Speed::difficulty_to_performance(self.attrs.speed);
+        let mut speed_value = self.trace.value(
+            "speed",
+            "difficulty to performance",
+            Speed::difficulty_to_performance(self.attrs.speed),
+        );
 
         let total_hits = self.total_hits();
 
@@ -315,20 +376,14 @@ impl OsuPerformanceCalculator<'_> {
             + 0.4 * (total_hits / 2000.0).min(1.0)
             + f64::from(u8::from(total_hits > 2000.0)) * (total_hits / 2000.0).log10() * 0.5;
 
-        speed_value *= len_bonus;
+        speed_value *= self.trace.factor("speed", "length bonus", len_bonus);
 
         if self.effective_miss_count > 0.0 {
-            if self.mods.rx() {
-                speed_value *= Self::calculate_relax_miss_penalty(
-                    self.total_hits(),
-                    self.effective_miss_count,
-                );
-            } else {
-                speed_value *= Self::calculate_miss_penalty(
-                    self.effective_miss_count,
-                    self.attrs.speed_difficult_strain_count,
-                );
-            }
+            speed_value *= self.trace.factor(
+                "speed",
+                "relax miss penalty",
+                Self::calculate_relax_miss_penalty(self.total_hits(), self.effective_miss_count),
+            );
         }
 
         let ar_factor = if self.attrs.ar > 10.33 {
@@ -338,20 +393,26 @@ impl OsuPerformanceCalculator<'_> {
         };
 
         // * Buff for longer maps with high AR.
-        speed_value *= 1.0 + ar_factor * len_bonus;
+        speed_value *= self
+            .trace
+            .factor("speed", "AR bonus", 1.0 + ar_factor * len_bonus);
 
         if self.mods.bl() {
             // * Increasing the speed value by object count for Blinds isn't
             // * ideal, so the minimum buff is given.
-            speed_value *= 1.12;
+            speed_value *= self.trace.factor("speed", "blinds bonus", 1.12);
         } else if self.mods.hd() || self.mods.tc() {
             // * We want to give more reward for lower AR when it comes to aim and HD.
             // * This nerfs high AR and buffs lower AR.
-            speed_value *= 1.0 + 0.04 * (12.0 - self.attrs.ar);
+            speed_value *=
+                self.trace
+                    .factor("speed", "hidden bonus", 1.0 + 0.04 * (12.0 - self.attrs.ar));
         }
 
         let speed_high_deviation_mult = self.calculate_speed_high_deviation_nerf(speed_deviation);
-        speed_value *= speed_high_deviation_mult;
+        speed_value *= self
+            .trace
+            .factor("speed", "high deviation nerf", speed_high_deviation_mult);
 
         // * Calculate accuracy assuming the worst case scenario
         let relevant_total_diff = f64::max(0.0, total_hits - self.attrs.speed_note_count);
@@ -373,8 +434,16 @@ impl OsuPerformanceCalculator<'_> {
         let od = self.attrs.od();
 
         // * Scale the speed value with accuracy and OD.
-        speed_value *= (0.95 + f64::powf(f64::max(0.0, od), 2.0) / 750.0)
-            * f64::powf((self.acc + relevant_acc) / 2.0, (14.5 - od) / 2.0);
+        speed_value *= self.trace.factor(
+            "speed",
+            "OD scaling",
+            0.95 + f64::powf(f64::max(0.0, od), 2.0) / 750.0,
+        );
+        speed_value *= self.trace.factor(
+            "speed",
+            "accuracy",
+            f64::powf((self.acc + relevant_acc) / 2.0, (14.5 - od) / 2.0),
+        );
 
         speed_value
     }
@@ -410,24 +479,31 @@ impl OsuPerformanceCalculator<'_> {
 
         // * Lots of arbitrary values from testing.
         // * Considering to use derivation from perfect accuracy in a probabilistic manner - assume normal distribution.
-        let mut acc_value =
-            1.52163_f64.powf(self.attrs.od()) * better_acc_percentage.powf(24.0) * 2.83;
+        let mut acc_value = self.trace.value(
+            "accuracy",
+            "accuracy and OD",
+            1.52163_f64.powf(self.attrs.od()) * better_acc_percentage.powf(24.0) * 2.83,
+        );
 
         // * Bonus for many hitcircles - it's harder to keep good accuracy up for longer.
-        acc_value *= (f64::from(amount_hit_objects_with_acc) / 1000.0)
-            .powf(0.3)
-            .min(1.15);
+        acc_value *= self.trace.factor(
+            "accuracy",
+            "length bonus",
+            (f64::from(amount_hit_objects_with_acc) / 1000.0)
+                .powf(0.3)
+                .min(1.15),
+        );
 
         // * Increasing the accuracy value by object count for Blinds isn't
         // * ideal, so the minimum buff is given.
         if self.mods.bl() {
-            acc_value *= 1.14;
+            acc_value *= self.trace.factor("accuracy", "blinds bonus", 1.14);
         } else if self.mods.hd() || self.mods.tc() {
-            acc_value *= 1.08;
+            acc_value *= self.trace.factor("accuracy", "hidden bonus", 1.08);
         }
 
         if self.mods.fl() {
-            acc_value *= 1.02;
+            acc_value *= self.trace.factor("accuracy", "flashlight bonus", 1.02);
         }
 
         acc_value
@@ -467,60 +543,23 @@ impl OsuPerformanceCalculator<'_> {
         relevant_count_ok: f64,
         relevant_count_meh: f64,
     ) -> Option<f64> {
-        if relevant_count_great + relevant_count_ok + relevant_count_meh <= 0.0 {
-            return None;
-        }
-
         // * The sample proportion of successful hits.
         let n = f64::max(1.0, relevant_count_great + relevant_count_ok);
         let p = relevant_count_great / n;
 
-        #[allow(clippy::items_after_statements, clippy::unreadable_literal)]
-        const Z: f64 = 2.32634787404; // * 99% critical value for the normal distribution (one-tailed).
-
         // * We can be 99% confident that the population proportion is at
         // * least this value.
-        let p_lower_bound = p.min(
-            (n * p + Z * Z / 2.0) / (n + Z * Z)
-                - Z / (n + Z * Z) * f64::sqrt(n * p * (1.0 - p) + Z * Z / 4.0),
-        );
-
-        let great_hit_window: f64 = self.attrs.great_hit_window;
-        let ok_hit_window: f64 = self.attrs.ok_hit_window;
-        let meh_hit_window: f64 = self.attrs.meh_hit_window;
-
-        // * Tested max precision for the deviation calculation.
-        let deviation = if p_lower_bound > 0.01 {
-            // * Compute deviation assuming greats and oks are normally distributed.
-            let mut deviation = great_hit_window / (LAZER_SQRT_2 * erf_inv(p_lower_bound));
-
-            // * Subtract the variance provided by tails outside the ok window.
-            let ratio = ok_hit_window / deviation;
-            let ok_hit_window_tail_amount =
-                f64::sqrt(2.0 / PI) * ok_hit_window * f64::exp(-0.5 * ratio * ratio)
-                    / (deviation * erf(ok_hit_window / (LAZER_SQRT_2 * deviation)));
-
-            deviation *= f64::sqrt(1.0 - ok_hit_window_tail_amount);
-            deviation
-        } else {
-            // * Tested limit for a score containing only oks.
-            ok_hit_window / f64::sqrt(3.0)
-        };
-
-        // * Then compute the variance for mehs.
-        let meh_variance = (meh_hit_window * meh_hit_window
-            + ok_hit_window * meh_hit_window
-            + ok_hit_window * ok_hit_window)
-            / 3.0;
-
-        // * Find the total deviation.
-        let deviation = f64::sqrt(
-            ((relevant_count_great + relevant_count_ok) * deviation * deviation
-                + relevant_count_meh * meh_variance)
-                / (relevant_count_great + relevant_count_ok + relevant_count_meh),
-        );
-
-        Some(deviation)
+        let p_lower_bound = p.min(deviation::wilson_lower_bound(n, p));
+
+        deviation::deviation_with_tails(
+            p_lower_bound,
+            relevant_count_great,
+            relevant_count_ok,
+            relevant_count_meh,
+            self.attrs.great_hit_window,
+            self.attrs.ok_hit_window,
+            self.attrs.meh_hit_window,
+        )
     }
 
     fn calculate_speed_high_deviation_nerf(&self, speed_deviation: f64) -> f64 {
@@ -552,8 +591,9 @@ impl OsuPerformanceCalculator<'_> {
     //   `0.93 / (missCount / (4 * Math.Log(difficultStrainCount)) + 1)`
     // 旧 mames は `0.96 / ((miss/(4*ln^0.94)) + 1)` で fork-specific 値、
     // 本家に合わせて `0.93 / ((miss/(4*ln)) + 1)` に戻す。
-    fn calculate_miss_penalty(miss_count: f64, diff_strain_count: f64) -> f64 {
-        0.93 / (miss_count / (4.0 * diff_strain_count.ln()) + 1.0)
+    fn calculate_miss_penalty(&self, miss_count: f64, diff_strain_count: f64) -> f64 {
+        self.params.miss_penalty_multiplier
+            / (miss_count / (self.params.miss_penalty_strain_factor * diff_strain_count.ln()) + 1.0)
     }
 
     fn calculate_relax_miss_penalty(total_hits: f64, effective_miss_count: f64) -> f64 {
@@ -582,7 +622,9 @@ impl OsuPerformanceCalculator<'_> {
             return 0.0;
         }
 
-        let mut aim_difficulty = self.attrs.aim;
+        let mut aim_difficulty = self
+            .trace
+            .value("aim difficulty", "difficulty", self.attrs.aim);
 
         if self.attrs.n_sliders > 0 && self.attrs.aim_difficult_slider_count > 0.0 {
             let estimate_improperly_followed_difficult_sliders = if self.using_classic_slider_acc {
@@ -612,23 +654,29 @@ impl OsuPerformanceCalculator<'_> {
                     3.0,
                 )
                 + self.attrs.slider_factor;
-            aim_difficulty *= slider_nerf_factor;
+            aim_difficulty *=
+                self.trace
+                    .factor("aim difficulty", "slider nerf", slider_nerf_factor);
         }
 
         // upstream: aimValue = DifficultyToPerformance(aimDifficulty) = 4 * pow(aim, 3)
-        let mut aim_value = 4.0 * aim_difficulty.powi(3);
+        let mut aim_value = self.trace.value(
+            "aim",
+            "difficulty to performance",
+            4.0 * aim_difficulty.powi(3),
+        );
 
         let total_hits = self.total_hits();
 
         // upstream: 0.95 + 0.35 * min(1, total/2000) + (total>2000 ? log10(...)*0.5 : 0)
-        let length_bonus = 0.95
-            + 0.35 * (total_hits / 2000.0).min(1.0)
+        let length_bonus = self.params.length_bonus_base
+            + self.params.length_bonus_linear * (total_hits / 2000.0).min(1.0)
             + if total_hits > 2000.0 {
-                (total_hits / 2000.0).log10() * 0.5
+                (total_hits / 2000.0).log10() * self.params.length_bonus_log
             } else {
                 0.0
             };
-        aim_value *= length_bonus;
+        aim_value *= self.trace.factor("aim", "length bonus", length_bonus);
 
         if self.effective_miss_count > 0.0 {
             let relevant_miss_count = f64::min(
@@ -636,24 +684,35 @@ impl OsuPerformanceCalculator<'_> {
                 total_imperfect_hits(&self.state)
                     + f64::from(n_large_tick_miss(&self.attrs, &self.state)),
             );
-            aim_value *= Self::calculate_miss_penalty(
-                relevant_miss_count,
-                self.attrs.aim_difficult_strain_count,
+            aim_value *= self.trace.factor(
+                "aim",
+                "miss penalty",
+                self.calculate_miss_penalty(
+                    relevant_miss_count,
+                    self.attrs.aim_difficult_strain_count,
+                ),
             );
         }
 
         // Blinds bonus, else Traceable bonus (旧 HD/TC の一律 boost は削除)
         if self.mods.bl() {
-            aim_value *= 1.3
-                + (total_hits
+            aim_value *= self.trace.factor(
+                "aim",
+                "blinds bonus",
+                1.3 + (total_hits
                     * (0.0016 / (1.0 + 2.0 * self.effective_miss_count))
                     * self.acc.powf(16.0))
-                    * (1.0 - 0.003 * self.attrs.hp * self.attrs.hp);
+                    * (1.0 - 0.003 * self.attrs.hp * self.attrs.hp),
+            );
         } else if self.mods.tc() {
-            aim_value *= 1.0 + self.calculate_traceable_bonus(self.attrs.slider_factor);
+            aim_value *= self.trace.factor(
+                "aim",
+                "traceable bonus",
+                1.0 + self.calculate_traceable_bonus(self.attrs.slider_factor),
+            );
         }
 
-        aim_value *= self.acc;
+        aim_value *= self.trace.factor("aim", "accuracy", self.acc);
         aim_value
     }
 
@@ -667,7 +726,11 @@ impl OsuPerformanceCalculator<'_> {
             return 0.0;
         };
         // upstream: HarmonicSkill.DifficultyToPerformance(speed) = 4 * pow(speed, 3)
-        let mut speed_value = 4.0 * self.attrs.speed.powi(3);
+        let mut speed_value = self.trace.value(
+            "speed",
+            "difficulty to performance",
+            4.0 * self.attrs.speed.powi(3),
+        );
 
         if self.effective_miss_count > 0.0 {
             let relevant_miss_count = f64::min(
@@ -675,19 +738,25 @@ impl OsuPerformanceCalculator<'_> {
                 total_imperfect_hits(&self.state)
                     + f64::from(n_large_tick_miss(&self.attrs, &self.state)),
             );
-            speed_value *= Self::calculate_miss_penalty(
-                relevant_miss_count,
-                self.attrs.speed_difficult_strain_count,
+            speed_value *= self.trace.factor(
+                "speed",
+                "miss penalty",
+                self.calculate_miss_penalty(
+                    relevant_miss_count,
+                    self.attrs.speed_difficult_strain_count,
+                ),
             );
         }
 
         if self.mods.bl() {
-            speed_value *= 1.12;
+            speed_value *= self.trace.factor("speed", "blinds bonus", 1.12);
         }
 
         let speed_high_deviation_mult =
             self.calculate_speed_high_deviation_nerf_vanilla(speed_deviation);
-        speed_value *= speed_high_deviation_mult;
+        speed_value *= self
+            .trace
+            .factor("speed", "high deviation nerf", speed_high_deviation_mult);
 
         // upstream: effectiveHitWindow = 20 * pow(4/speed, 0.35)
         let effective_hit_window = 20.0 * (4.0 / self.attrs.speed).powf(0.35);
@@ -696,7 +765,9 @@ impl OsuPerformanceCalculator<'_> {
         let effective_accuracy = erf(effective_hit_window / speed_deviation);
 
         // upstream: speedValue *= pow(effectiveAccuracy, 2)
-        speed_value *= effective_accuracy.powi(2);
+        speed_value *= self
+            .trace
+            .factor("speed", "effective accuracy", effective_accuracy.powi(2));
 
         speed_value
     }
@@ -725,24 +796,32 @@ impl OsuPerformanceCalculator<'_> {
         let overall_difficulty = (79.5 - self.attrs.great_hit_window) / 6.0;
 
         // upstream: pow(1.52163, OD) * pow(betterAccPercentage, 24) * 2.83
-        let mut acc_value =
-            1.52163_f64.powf(overall_difficulty) * better_acc_percentage.powf(24.0) * 2.83;
+        let mut acc_value = self.trace.value(
+            "accuracy",
+            "accuracy and OD",
+            1.52163_f64.powf(overall_difficulty) * better_acc_percentage.powf(24.0) * 2.83,
+        );
 
         // upstream: length bonus (< 1000 → pow(x, 0.3), ≥ 1000 → pow(x, 0.1))
         let ratio = f64::from(amount_hit_objects_with_acc) / 1000.0;
-        acc_value *= if amount_hit_objects_with_acc < 1000 {
+        let length_bonus = if amount_hit_objects_with_acc < 1000 {
             ratio.powf(0.3)
         } else {
             ratio.powf(0.1)
         };
+        acc_value *= self.trace.factor("accuracy", "length bonus", length_bonus);
 
         // upstream: Blinds + Traceable の bonus のみ (HD/FL boost は削除)
         if self.mods.bl() {
-            acc_value *= 1.14;
+            acc_value *= self.trace.factor("accuracy", "blinds bonus", 1.14);
         } else if self.mods.tc() {
             // AR > 10 で bonus を減衰
             let approach_rate = self.attrs.ar;
-            acc_value *= 1.0 + 0.08 * reverse_lerp(approach_rate, 11.5, 10.0);
+            acc_value *= self.trace.factor(
+                "accuracy",
+                "traceable bonus",
+                1.0 + 0.08 * reverse_lerp(approach_rate, 11.5, 10.0),
+            );
         }
 
         acc_value
@@ -755,20 +834,33 @@ impl OsuPerformanceCalculator<'_> {
         }
 
         // upstream: Flashlight.DifficultyToPerformance = 25 * pow(difficulty, 2)
-        let mut flashlight_value = 25.0 * self.attrs.flashlight.powi(2);
+        let mut flashlight_value = self.trace.value(
+            "flashlight",
+            "difficulty to performance",
+            25.0 * self.attrs.flashlight.powi(2),
+        );
 
         let total_hits = self.total_hits();
 
         if self.effective_miss_count > 0.0 {
-            flashlight_value *= 0.97
-                * (1.0 - (self.effective_miss_count / total_hits).powf(0.775))
-                    .powf(self.effective_miss_count.powf(0.875));
+            flashlight_value *= self.trace.factor(
+                "flashlight",
+                "miss penalty",
+                0.97 * (1.0 - (self.effective_miss_count / total_hits).powf(0.775))
+                    .powf(self.effective_miss_count.powf(0.875)),
+            );
         }
 
-        flashlight_value *= self.get_combo_scaling_factor();
+        flashlight_value *= self.trace.factor(
+            "flashlight",
+            "combo scaling",
+            self.get_combo_scaling_factor(),
+        );
 
         // upstream: 0.5 + accuracy / 2.0 のみ (旧 length bonus と OD 補正は削除)
-        flashlight_value *= 0.5 + self.acc / 2.0;
+        flashlight_value *= self
+            .trace
+            .factor("flashlight", "accuracy", 0.5 + self.acc / 2.0);
 
         flashlight_value
     }
@@ -776,17 +868,25 @@ impl OsuPerformanceCalculator<'_> {
     // upstream: computeReadingValue
     fn compute_reading_value_vanilla(&self, aim_slider_breaks: f64) -> f64 {
         // upstream: HarmonicSkill.DifficultyToPerformance(reading)
-        let mut reading_value = 4.0 * self.attrs.reading.powi(3);
+        let mut reading_value = self.trace.value(
+            "reading",
+            "difficulty to performance",
+            4.0 * self.attrs.reading.powi(3),
+        );
 
         if self.effective_miss_count > 0.0 {
-            reading_value *= Self::calculate_miss_penalty(
-                self.effective_miss_count + aim_slider_breaks,
-                self.attrs.reading_difficult_note_count,
+            reading_value *= self.trace.factor(
+                "reading",
+                "miss penalty",
+                self.calculate_miss_penalty(
+                    self.effective_miss_count + aim_slider_breaks,
+                    self.attrs.reading_difficult_note_count,
+                ),
             );
         }
 
         // upstream: reading value scales HARSHLY with accuracy
-        reading_value *= self.acc.powi(3);
+        reading_value *= self.trace.factor("reading", "accuracy", self.acc.powi(3));
 
         reading_value
     }
@@ -866,7 +966,7 @@ fn norm_pnorm(p: f64, values: &[f64]) -> f64 {
 }
 
 // upstream: OsuDifficultyCalculator.SumCognitionDifficulty
-fn sum_cognition_difficulty(reading: f64, flashlight: f64) -> f64 {
+fn sum_cognition_difficulty(norm_exponent: f64, reading: f64, flashlight: f64) -> f64 {
     if reading <= 0.0 {
         return flashlight;
     }
@@ -875,7 +975,7 @@ fn sum_cognition_difficulty(reading: f64, flashlight: f64) -> f64 {
     }
     // upstream: Norm(1.1, reading, flashlight * clamp(flashlight/reading, 0.25, 1.0))
     let flashlight_scaled = flashlight * (flashlight / reading).clamp(0.25, 1.0);
-    norm_pnorm(PERFORMANCE_NORM_EXPONENT, &[reading, flashlight_scaled])
+    norm_pnorm(norm_exponent, &[reading, flashlight_scaled])
 }
 
 const fn total_successful_hits(state: &OsuScoreState) -> u32 {
@@ -3,10 +3,14 @@ use std::{borrow::Cow, cmp};
 use rosu_map::section::general::GameMode;
 
 use self::calculator::OsuPerformanceCalculator;
-pub use self::calculator::PERFORMANCE_BASE_MULTIPLIER;
+pub use self::calculator::{PERFORMANCE_BASE_MULTIPLIER, PERFORMANCE_NORM_EXPONENT};
 
 use crate::{
-    any::{Difficulty, HitResultPriority, IntoModePerformance, IntoPerformance, Performance},
+    any::{
+        trace::{self, Trace},
+        AlgorithmVersion, Difficulty, HitResultPriority, IntoModePerformance, IntoPerformance,
+        Performance, PerformanceTrace,
+    },
     catch::CatchPerformance,
     mania::ManiaPerformance,
     model::{mode::ConvertError, mods::GameMods},
@@ -18,7 +22,7 @@ use crate::{
 use super::{
     attributes::{OsuDifficultyAttributes, OsuPerformanceAttributes},
     score_state::{OsuScoreOrigin, OsuScoreState},
-    Osu,
+    Osu, OsuParameters,
 };
 
 mod calculator;
@@ -173,6 +177,24 @@ impl<'map> OsuPerformance<'map> {
         self
     }
 
+    /// Specify the [`AlgorithmVersion`] of the calculation.
+    ///
+    /// Defaults to [`AlgorithmVersion::Mamestagram`].
+    pub fn algorithm(mut self, algorithm: AlgorithmVersion) -> Self {
+        self.difficulty = self.difficulty.algorithm(algorithm);
+
+        self
+    }
+
+    /// Override the constants of the calculation.
+    ///
+    /// Defaults to [`OsuParameters::DEFAULT`].
+    pub fn osu_parameters(mut self, params: OsuParameters) -> Self {
+        self.difficulty = self.difficulty.osu_parameters(params);
+
+        self
+    }
+
     /// Specify the amount of "large tick" hits.
     ///
     /// The meaning depends on the kind of score:
@@ -670,7 +692,24 @@ impl<'map> OsuPerformance<'map> {
     }
 
     /// Calculate all performance related values, including pp and stars.
-    pub fn calculate(mut self) -> Result<OsuPerformanceAttributes, ConvertError> {
+    pub fn calculate(self) -> Result<OsuPerformanceAttributes, ConvertError> {
+        self.calculate_inner(Trace::disabled())
+    }
+
+    /// Same as [`OsuPerformance::calculate`] but also records each value and
+    /// factor of the pp formula in a [`PerformanceTrace`].
+    pub fn calculate_with_trace(
+        self,
+    ) -> Result<(OsuPerformanceAttributes, PerformanceTrace), ConvertError> {
+        let (res, trace) = trace::traced(|trace| self.calculate_inner(trace));
+
+        res.map(|attrs| (attrs, trace))
+    }
+
+    fn calculate_inner(
+        mut self,
+        trace: Trace<'_>,
+    ) -> Result<OsuPerformanceAttributes, ConvertError> {
         let state = self.generate_state()?;
 
         let attrs = match self.map_or_attrs {
@@ -789,6 +828,9 @@ impl<'map> OsuPerformance<'map> {
             combo_based_estimated_miss_count,
             score_based_estimated_miss_count,
             using_classic_slider_acc,
+            self.difficulty.get_algorithm(),
+            self.difficulty.get_osu_parameters(),
+            trace,
         );
 
         Ok(inner.calculate())
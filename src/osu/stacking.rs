@@ -0,0 +1,132 @@
+use rosu_map::{section::general::GameMode, util::Pos};
+
+use crate::{
+    any::difficulty::section::SectionWindow, model::mode::ConvertError, Beatmap, Difficulty,
+};
+
+use super::{convert::convert_objects, difficulty::OsuDifficultySetup};
+
+/// The hit objects of an osu!standard map as they appear during gameplay,
+/// i.e. after applying mods and stacking.
+///
+/// See [`Difficulty::osu_stacking`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct OsuStacking {
+    /// The radius of hit objects in osu!pixels with respect to mods.
+    pub radius: f64,
+    /// All hit objects in the same order as the map's hit objects.
+    pub objects: Vec<OsuStackedObject>,
+}
+
+/// An osu!standard hit object after applying mods and stacking.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OsuStackedObject {
+    /// The start time in milliseconds, not adjusted to the clock rate.
+    pub start_time: f64,
+    /// The end time in milliseconds, not adjusted to the clock rate.
+    pub end_time: f64,
+    /// The position after reflection but before stacking.
+    pub pos: Pos,
+    /// The index within the stack; `0` is the topmost object.
+    pub stack_height: i32,
+    /// The position of the hit object as shown during gameplay.
+    pub stacked_pos: Pos,
+    /// The position of the slider tail as shown during gameplay.
+    ///
+    /// Same as `stacked_pos` for circles and spinners.
+    pub stacked_end_pos: Pos,
+}
+
+impl Difficulty {
+    /// Calculate the positions of an osu!standard map's hit objects after
+    /// applying mods and stacking.
+    ///
+    /// Maps of other modes cannot be converted and return an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mames_pp::{Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let stacking = Difficulty::new().mods(16).osu_stacking(&map).unwrap(); // HR
+    ///
+    /// for h in stacking.objects.iter().filter(|h| h.stack_height > 0) {
+    ///     println!("{}ms: ({}, {})", h.start_time, h.stacked_pos.x, h.stacked_pos.y);
+    /// }
+    /// ```
+    pub fn osu_stacking(&self, map: &Beatmap) -> Result<OsuStacking, ConvertError> {
+        let map = map.convert_ref(GameMode::Osu, self.get_mods())?;
+
+        let OsuDifficultySetup {
+            scaling_factor,
+            mut attrs,
+            time_preempt,
+            ..
+        } = OsuDifficultySetup::new(self, &map);
+
+        let osu_objects = convert_objects(
+            &map,
+            &scaling_factor,
+            self.get_mods().reflection(),
+            time_preempt,
+            0,
+            &SectionWindow::default(),
+            &mut attrs,
+        );
+
+        let objects = osu_objects
+            .iter()
+            .map(|h| OsuStackedObject {
+                start_time: h.start_time,
+                end_time: h.end_time(),
+                pos: h.pos,
+                stack_height: h.stack_height,
+                stacked_pos: h.stacked_pos(),
+                stacked_end_pos: h.stacked_end_pos(),
+            })
+            .collect();
+
+        Ok(OsuStacking {
+            radius: scaling_factor.radius,
+            objects,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::beatmap::BeatmapBuilder;
+
+    use super::*;
+
+    #[test]
+    fn stacked_circles() {
+        let pos = Pos::new(100.0, 100.0);
+
+        let map = BeatmapBuilder::new(GameMode::Osu)
+            .ar(9.0)
+            .timing_point(0.0, 300.0)
+            .circle(pos, 0.0)
+            .circle(pos, 100.0)
+            .circle(pos, 200.0)
+            .circle(Pos::new(400.0, 300.0), 300.0)
+            .build()
+            .unwrap();
+
+        let stacking = Difficulty::new().osu_stacking(&map).unwrap();
+
+        let heights: Vec<_> = stacking.objects.iter().map(|h| h.stack_height).collect();
+        assert_eq!(heights, [2, 1, 0, 0]);
+
+        let top = stacking.objects[2];
+        assert_eq!(top.stacked_pos, pos);
+        assert!(stacking.objects[0].stacked_pos.x < pos.x);
+
+        let hr = Difficulty::new().mods(16).osu_stacking(&map).unwrap();
+
+        assert_eq!(hr.objects[2].stacked_pos, Pos::new(100.0, 284.0));
+        assert!(hr.radius < stacking.radius);
+    }
+}
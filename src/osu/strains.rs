@@ -8,6 +8,7 @@ use super::difficulty::{skills::OsuSkills, DifficultyValues};
 ///
 /// Suitable to plot the difficulty of a map over time.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OsuStrains {
     /// Strain peaks of the aim skill.
     pub aim: Vec<f64>,
@@ -0,0 +1,136 @@
+use std::fmt::Write;
+
+pub use self::{
+    notes::{NoteStrip, NoteStripError},
+    playfield::PlayfieldSnapshot,
+    strains::StrainGraph,
+};
+
+mod notes;
+mod playfield;
+mod strains;
+
+/// Colors of consecutive polylines, legends, etc.
+const PALETTE: [&str; 6] = [
+    "#66ccff", "#ff66aa", "#ffcc22", "#88dd66", "#bb88ff", "#ff8844",
+];
+
+const BACKGROUND: &str = "#1c1c24";
+const FOREGROUND: &str = "#e8e8f0";
+
+/// Assembles an SVG document.
+///
+/// Writing into a `String` cannot fail so all `fmt::Result`s are ignored.
+struct SvgWriter {
+    buf: String,
+}
+
+impl SvgWriter {
+    fn new(width: f64, height: f64, view_box: [f64; 4]) -> Self {
+        let mut buf = String::with_capacity(4096);
+        let [x, y, w, h] = view_box;
+
+        let _ = write!(
+            buf,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width:.0}" height="{height:.0}" viewBox="{x:.1} {y:.1} {w:.1} {h:.1}">"#
+        );
+
+        let mut this = Self { buf };
+        this.rect(x, y, w, h, BACKGROUND, 1.0);
+
+        this
+    }
+
+    fn rect(&mut self, x: f64, y: f64, width: f64, height: f64, fill: &str, opacity: f64) {
+        let _ = write!(
+            self.buf,
+            r#"<rect x="{x:.1}" y="{y:.1}" width="{width:.1}" height="{height:.1}" fill="{fill}" fill-opacity="{opacity:.2}"/>"#
+        );
+    }
+
+    fn circle(&mut self, cx: f64, cy: f64, r: f64, fill: &str, opacity: f64) {
+        let _ = write!(
+            self.buf,
+            r#"<circle cx="{cx:.1}" cy="{cy:.1}" r="{r:.1}" fill="{fill}" fill-opacity="{opacity:.2}"/>"#
+        );
+    }
+
+    fn ring(&mut self, cx: f64, cy: f64, r: f64, stroke: &str, width: f64, opacity: f64) {
+        let _ = write!(
+            self.buf,
+            r#"<circle cx="{cx:.1}" cy="{cy:.1}" r="{r:.1}" fill="none" stroke="{stroke}" stroke-width="{width:.1}" stroke-opacity="{opacity:.2}"/>"#
+        );
+    }
+
+    fn polyline<I>(&mut self, points: I, stroke: &str, width: f64, opacity: f64)
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        self.buf.push_str(r#"<polyline points=""#);
+
+        for (i, (x, y)) in points.into_iter().enumerate() {
+            if i > 0 {
+                self.buf.push(' ');
+            }
+
+            let _ = write!(self.buf, "{x:.1},{y:.1}");
+        }
+
+        let _ = write!(
+            self.buf,
+            r#"" fill="none" stroke="{stroke}" stroke-width="{width:.1}" stroke-opacity="{opacity:.2}" stroke-linecap="round" stroke-linejoin="round"/>"#
+        );
+    }
+
+    fn text(&mut self, x: f64, y: f64, size: f64, fill: &str, text: &str) {
+        let _ = write!(
+            self.buf,
+            r#"<text x="{x:.1}" y="{y:.1}" font-family="sans-serif" font-size="{size:.0}" fill="{fill}">{text}</text>"#
+        );
+    }
+
+    fn finish(mut self) -> String {
+        self.buf.push_str("</svg>");
+
+        self.buf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{model::mode::GameMode, Beatmap, Difficulty};
+
+    use super::*;
+
+    #[test]
+    fn renders_documents() {
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+        let difficulty = Difficulty::new();
+
+        let graph = StrainGraph::default().render(&map, &difficulty);
+        assert!(graph.starts_with("<svg") && graph.ends_with("</svg>"));
+        // four skills and the axes
+        assert_eq!(graph.matches("<polyline").count(), 5);
+
+        let time = map.hit_objects[50].start_time;
+        let snapshot = PlayfieldSnapshot::default()
+            .render(&map, &difficulty, time)
+            .unwrap();
+        assert!(snapshot.contains("<circle"));
+
+        let strip = NoteStrip::default()
+            .render(&map, GameMode::Mania, &difficulty)
+            .unwrap();
+        assert!(strip.contains("<rect"));
+
+        let taiko = NoteStrip::default()
+            .render(&map, GameMode::Taiko, &difficulty)
+            .unwrap();
+        assert!(taiko.contains("<circle"));
+
+        assert!(matches!(
+            NoteStrip::default().render(&map, GameMode::Catch, &difficulty),
+            Err(NoteStripError::UnsupportedMode(GameMode::Catch))
+        ));
+    }
+}
@@ -0,0 +1,204 @@
+use std::{error, fmt};
+
+use rosu_map::section::general::GameMode;
+
+use crate::{
+    mania::ManiaObject,
+    model::{
+        hit_object::{HitObjectKind, HitSoundType, HoldNote, Spinner},
+        mode::ConvertError,
+    },
+    taiko, Beatmap, Difficulty,
+};
+
+use super::{SvgWriter, FOREGROUND, PALETTE};
+
+const PADDING: f64 = 16.0;
+const DON_COLOR: &str = "#eb452b";
+const KAT_COLOR: &str = "#448dab";
+const DRUM_ROLL_COLOR: &str = "#fcb806";
+const SWELL_COLOR: &str = "#f77f1e";
+
+/// A preview of an osu!taiko or osu!mania map with time running from left to
+/// right.
+///
+/// osu!mania columns are drawn as separate lanes.
+///
+/// # Example
+///
+/// ```
+/// use mames_pp::{model::mode::GameMode, svg::NoteStrip, Beatmap, Difficulty};
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+///
+/// let svg = NoteStrip::default()
+///     .render(&map, GameMode::Mania, &Difficulty::new())
+///     .unwrap();
+///
+/// assert!(svg.starts_with("<svg"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct NoteStrip {
+    /// The width of the image in pixels.
+    pub width: u32,
+    /// The height of a single lane in pixels.
+    pub lane_height: u32,
+}
+
+impl Default for NoteStrip {
+    fn default() -> Self {
+        Self {
+            width: 2000,
+            lane_height: 16,
+        }
+    }
+}
+
+impl NoteStrip {
+    /// Render the [`Beatmap`] after converting it to the given mode.
+    ///
+    /// Only [`GameMode::Taiko`] and [`GameMode::Mania`] are supported, other
+    /// modes return [`NoteStripError::UnsupportedMode`].
+    pub fn render(
+        &self,
+        map: &Beatmap,
+        mode: GameMode,
+        difficulty: &Difficulty,
+    ) -> Result<String, NoteStripError> {
+        if !matches!(mode, GameMode::Taiko | GameMode::Mania) {
+            return Err(NoteStripError::UnsupportedMode(mode));
+        }
+
+        let map = map.convert_ref(mode, difficulty.get_mods())?;
+
+        let total_columns = if mode == GameMode::Mania {
+            map.cs.round_ties_even().max(1.0)
+        } else {
+            1.0
+        };
+
+        let end_time_of = |kind: &HitObjectKind, start_time: f64| match kind {
+            HitObjectKind::Circle => start_time,
+            HitObjectKind::Slider(slider) => {
+                start_time + f64::from(taiko::drum_roll_duration(&map, start_time, slider))
+            }
+            HitObjectKind::Spinner(Spinner { duration })
+            | HitObjectKind::Hold(HoldNote { duration }) => start_time + duration,
+        };
+
+        let start_time = map.hit_objects.first().map_or(0.0, |h| h.start_time);
+
+        let end_time = map
+            .hit_objects
+            .iter()
+            .map(|h| end_time_of(&h.kind, h.start_time))
+            .fold(start_time + 1.0, f64::max);
+
+        let lane_height = f64::from(self.lane_height);
+        let width = f64::from(self.width);
+        let height = f64::from(total_columns) * lane_height + 2.0 * PADDING;
+        let plot_width = width - 2.0 * PADDING;
+
+        let mut svg = SvgWriter::new(width, height, [0.0, 0.0, width, height]);
+
+        let x_at = |time: f64| PADDING + (time - start_time) / (end_time - start_time) * plot_width;
+
+        for column in (0..total_columns as usize).step_by(2) {
+            let y = PADDING + column as f64 * lane_height;
+            svg.rect(PADDING, y, plot_width, lane_height, FOREGROUND, 0.05);
+        }
+
+        for (h, sound) in map.hit_objects.iter().zip(map.hit_sounds.iter()) {
+            let x = x_at(h.start_time);
+            let end_x = x_at(end_time_of(&h.kind, h.start_time));
+
+            if mode == GameMode::Mania {
+                let column = ManiaObject::column(h.pos.x, total_columns);
+                let y = PADDING + column as f64 * lane_height;
+                let color = PALETTE[column % 2];
+
+                if end_x > x {
+                    svg.rect(x, y + 2.0, end_x - x, lane_height - 4.0, color, 0.5);
+                }
+
+                svg.rect(x - 1.0, y + 1.0, 2.0, lane_height - 2.0, color, 1.0);
+
+                continue;
+            }
+
+            let y = PADDING + lane_height / 2.0;
+
+            match h.kind {
+                HitObjectKind::Circle => {
+                    let color = if sound.has_flag(HitSoundType::CLAP | HitSoundType::WHISTLE) {
+                        KAT_COLOR
+                    } else {
+                        DON_COLOR
+                    };
+
+                    let radius = if sound.has_flag(HitSoundType::FINISH) {
+                        0.45 * lane_height
+                    } else {
+                        0.3 * lane_height
+                    };
+
+                    svg.circle(x, y, radius, color, 1.0);
+                }
+                HitObjectKind::Slider(_) => {
+                    svg.rect(
+                        x,
+                        y - 0.25 * lane_height,
+                        end_x - x,
+                        0.5 * lane_height,
+                        DRUM_ROLL_COLOR,
+                        0.8,
+                    );
+                }
+                HitObjectKind::Spinner(_) | HitObjectKind::Hold(_) => {
+                    svg.rect(
+                        x,
+                        y - 0.35 * lane_height,
+                        end_x - x,
+                        0.7 * lane_height,
+                        SWELL_COLOR,
+                        0.8,
+                    );
+                }
+            }
+        }
+
+        Ok(svg.finish())
+    }
+}
+
+/// Error when rendering a [`NoteStrip`].
+#[derive(Copy, Clone, Debug)]
+pub enum NoteStripError {
+    /// Only osu!taiko and osu!mania maps can be rendered.
+    UnsupportedMode(GameMode),
+    Convert(ConvertError),
+}
+
+impl error::Error for NoteStripError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::UnsupportedMode(_) => None,
+            Self::Convert(err) => Some(err),
+        }
+    }
+}
+
+impl fmt::Display for NoteStripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedMode(mode) => write!(f, "cannot render {mode:?} as note strip"),
+            Self::Convert(_) => f.write_str("failed to convert beatmap"),
+        }
+    }
+}
+
+impl From<ConvertError> for NoteStripError {
+    fn from(err: ConvertError) -> Self {
+        Self::Convert(err)
+    }
+}
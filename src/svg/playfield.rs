@@ -0,0 +1,173 @@
+use rosu_map::section::general::GameMode;
+
+use crate::{
+    model::{
+        hit_object::{CurveBuffers, Pos},
+        mode::ConvertError,
+        mods::Reflection,
+    },
+    osu::SliderGeometry,
+    Beatmap, Difficulty,
+};
+
+use super::{SvgWriter, FOREGROUND, PALETTE};
+
+const PLAYFIELD_WIDTH: f32 = 512.0;
+const PLAYFIELD_HEIGHT: f32 = 384.0;
+const PADDING: f64 = 64.0;
+const SPINNER_RADIUS: f64 = 160.0;
+
+/// An image of the osu!standard playfield at a given time.
+///
+/// Shows circles, slider paths, and approach circles of all visible objects
+/// at their stacked positions with respect to mods.
+///
+/// # Example
+///
+/// ```
+/// use mames_pp::{svg::PlayfieldSnapshot, Beatmap, Difficulty};
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// let difficulty = Difficulty::new().mods(16); // HR
+///
+/// let svg = PlayfieldSnapshot::default()
+///     .render(&map, &difficulty, 30_000.0)
+///     .unwrap();
+///
+/// assert!(svg.starts_with("<svg"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlayfieldSnapshot {
+    /// Pixels per osu!pixel.
+    pub scale: f64,
+    /// The amount of points that a slider path is sampled at.
+    pub slider_resolution: usize,
+}
+
+impl Default for PlayfieldSnapshot {
+    fn default() -> Self {
+        Self {
+            scale: 1.5,
+            slider_resolution: 64,
+        }
+    }
+}
+
+impl PlayfieldSnapshot {
+    /// Render the playfield of the [`Beatmap`] at the given time in
+    /// milliseconds, not adjusted to the clock rate.
+    ///
+    /// Maps of other modes than osu!standard cannot be converted and return
+    /// an error.
+    pub fn render(
+        &self,
+        map: &Beatmap,
+        difficulty: &Difficulty,
+        time: f64,
+    ) -> Result<String, ConvertError> {
+        let stacking = difficulty.osu_stacking(map)?;
+        let map = map.convert_ref(GameMode::Osu, difficulty.get_mods())?;
+
+        let map_attrs = map.attributes().difficulty(difficulty).build();
+        let preempt = map_attrs.hit_windows.ar * map_attrs.clock_rate;
+        // See `OsuSkills::new`
+        let fade_in = 400.0 * (preempt / 450.0).min(1.0);
+
+        let reflection = difficulty.get_mods().reflection();
+        let radius = stacking.radius;
+
+        let width = f64::from(PLAYFIELD_WIDTH) + 2.0 * PADDING;
+        let height = f64::from(PLAYFIELD_HEIGHT) + 2.0 * PADDING;
+
+        let mut svg = SvgWriter::new(
+            width * self.scale,
+            height * self.scale,
+            [-PADDING, -PADDING, width, height],
+        );
+
+        svg.polyline(
+            [
+                (0.0, 0.0),
+                (512.0, 0.0),
+                (512.0, 384.0),
+                (0.0, 384.0),
+                (0.0, 0.0),
+            ],
+            FOREGROUND,
+            1.0,
+            0.2,
+        );
+
+        let mut bufs = CurveBuffers::default();
+
+        let visible = map
+            .hit_objects
+            .iter()
+            .zip(stacking.objects.iter())
+            .enumerate()
+            .filter(|(_, (_, h))| h.start_time - preempt <= time && time <= h.end_time);
+
+        // Earlier objects are drawn on top
+        for (i, (h, stacked)) in visible.rev() {
+            let color = PALETTE[i % PALETTE.len()];
+            let opacity = ((time - (stacked.start_time - preempt)) / fade_in).clamp(0.0, 1.0);
+            let (x, y) = xy(stacked.stacked_pos);
+
+            if h.is_spinner() {
+                let (cx, cy) = (
+                    f64::from(PLAYFIELD_WIDTH) / 2.0,
+                    f64::from(PLAYFIELD_HEIGHT) / 2.0,
+                );
+                svg.ring(cx, cy, SPINNER_RADIUS, FOREGROUND, 4.0, opacity);
+
+                continue;
+            }
+
+            let offset = stacked.stacked_pos - stacked.pos;
+            let place = |pos: Pos| reflect(pos, reflection) + offset;
+
+            if let Some(slider) = SliderGeometry::new(&map, h, &mut bufs) {
+                let path: Vec<_> = slider
+                    .sampled_path(self.slider_resolution)
+                    .map(|pos| xy(place(pos)))
+                    .collect();
+
+                svg.polyline(
+                    path.iter().copied(),
+                    FOREGROUND,
+                    2.0 * radius,
+                    0.8 * opacity,
+                );
+                svg.polyline(path, color, 2.0 * radius - 8.0, 0.6 * opacity);
+
+                if time >= stacked.start_time {
+                    let (bx, by) = xy(place(slider.position_at_time(time)));
+                    svg.ring(bx, by, radius, FOREGROUND, 4.0, 1.0);
+                }
+            }
+
+            if time <= stacked.start_time {
+                svg.circle(x, y, radius, color, opacity);
+                svg.ring(x, y, radius, FOREGROUND, 0.1 * radius, opacity);
+
+                let progress = ((time - (stacked.start_time - preempt)) / preempt).clamp(0.0, 1.0);
+                svg.ring(x, y, radius * (4.0 - 3.0 * progress), color, 3.0, opacity);
+            }
+        }
+
+        Ok(svg.finish())
+    }
+}
+
+fn reflect(pos: Pos, reflection: Reflection) -> Pos {
+    match reflection {
+        Reflection::None => pos,
+        Reflection::Vertical => Pos::new(pos.x, PLAYFIELD_HEIGHT - pos.y),
+        Reflection::Horizontal => Pos::new(PLAYFIELD_WIDTH - pos.x, pos.y),
+        Reflection::Both => Pos::new(PLAYFIELD_WIDTH - pos.x, PLAYFIELD_HEIGHT - pos.y),
+    }
+}
+
+fn xy(pos: Pos) -> (f64, f64) {
+    (f64::from(pos.x), f64::from(pos.y))
+}
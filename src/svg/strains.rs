@@ -0,0 +1,186 @@
+use crate::{any::Strains, Beatmap, Difficulty};
+
+use super::{SvgWriter, FOREGROUND, PALETTE};
+
+const PADDING: f64 = 24.0;
+const BREAK_COLOR: &str = "#808090";
+const KIAI_COLOR: &str = "#ff9933";
+
+/// A chart of [`Strains`] over time.
+///
+/// Every skill is drawn as its own line; breaks and kiai sections of the map
+/// are shaded.
+///
+/// # Example
+///
+/// ```
+/// use mames_pp::{svg::StrainGraph, Beatmap, Difficulty};
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// let difficulty = Difficulty::new().mods(64); // DT
+///
+/// let svg = StrainGraph::default().render(&map, &difficulty);
+///
+/// assert!(svg.starts_with("<svg"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct StrainGraph {
+    /// The width of the image in pixels.
+    pub width: u32,
+    /// The height of the image in pixels.
+    pub height: u32,
+}
+
+impl Default for StrainGraph {
+    fn default() -> Self {
+        Self {
+            width: 1200,
+            height: 300,
+        }
+    }
+}
+
+impl StrainGraph {
+    /// Calculate the strains of the [`Beatmap`] and render them as SVG.
+    ///
+    /// Strain peaks are placed at the end of their section which is aligned
+    /// the same way as in difficulty calculation.
+    pub fn render(&self, map: &Beatmap, difficulty: &Difficulty) -> String {
+        let strains = difficulty.strains(map);
+        let width = f64::from(self.width);
+        let height = f64::from(self.height);
+        let mut svg = SvgWriter::new(width, height, [0.0, 0.0, width, height]);
+
+        let skills = skills(&strains);
+        let clock_rate = difficulty.get_clock_rate();
+        let section_len = strains.section_len();
+
+        // * The first object doesn't generate a strain, so we begin with an incremented section end
+        let first_section_end = map.hit_objects.get(1).map_or(0.0, |h| {
+            (h.start_time / clock_rate / section_len).ceil() * section_len
+        });
+
+        let peak_time = |i: usize| (first_section_end + i as f64 * section_len) * clock_rate;
+
+        let n_peaks = skills
+            .iter()
+            .map(|(_, peaks)| peaks.len())
+            .max()
+            .unwrap_or(0);
+
+        let end_time = map
+            .hit_objects
+            .last()
+            .map_or(0.0, |h| h.start_time)
+            .max(peak_time(n_peaks.saturating_sub(1)))
+            .max(1.0);
+
+        let max_strain = skills
+            .iter()
+            .flat_map(|(_, peaks)| peaks.iter().copied())
+            .fold(0.0, f64::max);
+
+        let plot_width = width - 2.0 * PADDING;
+        let plot_height = height - 2.0 * PADDING;
+
+        let x_at = |time: f64| PADDING + time.clamp(0.0, end_time) / end_time * plot_width;
+
+        let y_at = |strain: f64| {
+            let ratio = if max_strain > 0.0 {
+                strain / max_strain
+            } else {
+                0.0
+            };
+
+            PADDING + (1.0 - ratio) * plot_height
+        };
+
+        let mut shade = |start: f64, end: f64, color: &str| {
+            let x = x_at(start);
+            svg.rect(x, PADDING, x_at(end) - x, plot_height, color, 0.25);
+        };
+
+        for period in map.breaks.iter() {
+            shade(period.start_time, period.end_time, BREAK_COLOR);
+        }
+
+        for (start, end) in kiai_sections(map, end_time) {
+            shade(start, end, KIAI_COLOR);
+        }
+
+        for ((name, peaks), (i, color)) in skills.iter().zip(PALETTE.iter().enumerate()) {
+            let points = peaks
+                .iter()
+                .enumerate()
+                .map(|(j, &strain)| (x_at(peak_time(j)), y_at(strain)));
+
+            svg.polyline(points, color, 1.5, 1.0);
+            svg.text(
+                PADDING + 8.0,
+                PADDING + 14.0 * (i + 1) as f64,
+                12.0,
+                color,
+                name,
+            );
+        }
+
+        svg.polyline(
+            [
+                (PADDING, PADDING),
+                (PADDING, PADDING + plot_height),
+                (PADDING + plot_width, PADDING + plot_height),
+            ],
+            FOREGROUND,
+            1.0,
+            0.6,
+        );
+
+        svg.finish()
+    }
+}
+
+fn skills(strains: &Strains) -> Vec<(&'static str, &[f64])> {
+    match strains {
+        Strains::Osu(strains) => vec![
+            ("aim", strains.aim.as_slice()),
+            ("aim (no sliders)", strains.aim_no_sliders.as_slice()),
+            ("speed", strains.speed.as_slice()),
+            ("flashlight", strains.flashlight.as_slice()),
+        ],
+        Strains::Taiko(strains) => vec![
+            ("color", strains.color.as_slice()),
+            ("reading", strains.reading.as_slice()),
+            ("rhythm", strains.rhythm.as_slice()),
+            ("stamina", strains.stamina.as_slice()),
+            (
+                "single color stamina",
+                strains.single_color_stamina.as_slice(),
+            ),
+        ],
+        Strains::Catch(strains) => vec![("movement", strains.movement.as_slice())],
+        Strains::Mania(strains) => vec![("strain", strains.strains.as_slice())],
+    }
+}
+
+/// Start and end times of the map's kiai sections.
+fn kiai_sections(map: &Beatmap, end_time: f64) -> Vec<(f64, f64)> {
+    let mut sections = Vec::new();
+    let mut kiai_start = None;
+
+    for point in map.effect_points.iter() {
+        match (kiai_start, point.kiai) {
+            (None, true) => kiai_start = Some(point.time),
+            (Some(start), false) => {
+                sections.push((start, point.time));
+                kiai_start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(start) = kiai_start {
+        sections.push((start, end_time));
+    }
+
+    sections
+}
@@ -2,6 +2,7 @@ use crate::taiko::performance::TaikoPerformance;
 
 /// The result of a difficulty calculation on an osu!taiko map.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TaikoDifficultyAttributes {
     /// The difficulty of the stamina skill.
     pub stamina: f64,
@@ -29,6 +30,19 @@ pub struct TaikoDifficultyAttributes {
     pub stars: f64,
     /// The maximum combo.
     pub max_combo: u32,
+    /// The amount of drum rolls.
+    pub n_drum_rolls: u32,
+    /// The amount of drum roll ticks.
+    pub n_drum_roll_ticks: u32,
+    /// The amount of swells.
+    pub n_swells: u32,
+    /// The total amount of hits required to complete all swells.
+    ///
+    /// Not to be confused with [`TaikoScoreState::swells_completed`] which
+    /// counts whole swells.
+    ///
+    /// [`TaikoScoreState::swells_completed`]: crate::taiko::TaikoScoreState::swells_completed
+    pub n_swell_hits: u32,
     /// Whether the [`Beatmap`] was a convert i.e. an osu!standard map.
     ///
     /// [`Beatmap`]: crate::model::beatmap::Beatmap
@@ -56,6 +70,7 @@ impl TaikoDifficultyAttributes {
 
 /// The result of a performance calculation on an osu!taiko map.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TaikoPerformanceAttributes {
     /// The difficulty attributes that were used for the performance calculation
     pub difficulty: TaikoDifficultyAttributes,
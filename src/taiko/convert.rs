@@ -118,6 +118,23 @@ pub fn convert(map: &mut Beatmap) {
     map.is_convert = true;
 }
 
+/// The duration of the drum roll that the given slider turns into.
+///
+/// Also applicable to native osu!taiko maps whose drum rolls are stored as
+/// sliders.
+pub fn drum_roll_duration(map: &Beatmap, start_time: f64, slider: &Slider) -> u32 {
+    let slider_velocity = map
+        .difficulty_point_at(start_time)
+        .map_or(DifficultyPoint::DEFAULT_SLIDER_VELOCITY, |point| {
+            point.slider_velocity
+        });
+
+    let mut params = SliderParams::new(start_time, slider, slider_velocity);
+    should_convert_slider_to_taiko_hits(map, &mut params);
+
+    params.duration
+}
+
 fn should_convert_slider_to_taiko_hits(map: &Beatmap, params: &mut SliderParams<'_>) -> bool {
     let SliderParams {
         slider,
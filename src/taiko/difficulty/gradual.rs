@@ -3,8 +3,9 @@ use std::{cmp, mem, slice::Iter};
 use rosu_map::section::general::GameMode;
 
 use crate::{
-    any::difficulty::skills::StrainSkill,
+    any::difficulty::{section::SectionWindow, skills::StrainSkill},
     model::{beatmap::HitWindows, hit_object::HitObject, mode::ConvertError},
+    taiko::object::TaikoNestedCounts,
     util::sync::RefCount,
     Beatmap, Difficulty,
 };
@@ -56,6 +57,8 @@ pub struct TaikoGradualDifficulty {
     skills: TaikoSkills,
     total_hits: usize,
     first_combos: FirstTwoCombos,
+    /// Nested object counts before the hit that follows the n-th hit.
+    nested_counts: Vec<TaikoNestedCounts>,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -84,12 +87,14 @@ impl TaikoGradualDifficulty {
             (Some(true), Some(true)) => FirstTwoCombos::Both,
         };
 
+        let map_attrs = map.attributes().difficulty(&difficulty);
+
         let HitWindows {
             od_great,
             od_ok,
             od_meh: _,
             ar: _,
-        } = map.attributes().difficulty(&difficulty).hit_windows();
+        } = map_attrs.hit_windows();
 
         let mut n_diff_objects = 0;
         let mut max_combo = 0;
@@ -97,14 +102,21 @@ impl TaikoGradualDifficulty {
         let diff_objects = DifficultyValues::create_difficulty_objects(
             &map,
             take as u32,
+            &SectionWindow::default(),
             clock_rate,
             &mut max_combo,
             &mut n_diff_objects,
             difficulty.get_mods(),
+            difficulty.get_algorithm(),
         );
 
         // `DifficultyHitObject.HitWindowGreat` in lazer is the full hit window.
-        let skills = TaikoSkills::new(od_great * 2.0, map.is_convert);
+        let skills = TaikoSkills::new(
+            od_great * 2.0,
+            map.is_convert,
+            difficulty.get_mods(),
+            difficulty.get_algorithm(),
+        );
 
         let attrs = TaikoDifficultyAttributes {
             great_hit_window: od_great,
@@ -115,6 +127,20 @@ impl TaikoGradualDifficulty {
 
         let total_hits = map.hit_objects.iter().filter(|h| h.is_circle()).count();
 
+        let od = map_attrs.mods_adjusted_od();
+        let mut nested_counts = Vec::with_capacity(total_hits + 1);
+        let mut curr_counts = TaikoNestedCounts::default();
+
+        for h in map.hit_objects.iter() {
+            if h.is_circle() {
+                nested_counts.push(curr_counts);
+            }
+
+            curr_counts.add(&map, h, od);
+        }
+
+        nested_counts.push(curr_counts);
+
         let diff_objects_iter = extend_lifetime(diff_objects.iter());
 
         Ok(Self {
@@ -126,6 +152,7 @@ impl TaikoGradualDifficulty {
             attrs,
             total_hits,
             first_combos,
+            nested_counts,
         })
     }
 }
@@ -178,6 +205,13 @@ impl Iterator for TaikoGradualDifficulty {
 
         self.idx += 1;
 
+        if let Some(counts) = self.nested_counts.get(self.attrs.max_combo as usize) {
+            self.attrs.n_drum_rolls = counts.n_drum_rolls;
+            self.attrs.n_drum_roll_ticks = counts.n_drum_roll_ticks;
+            self.attrs.n_swells = counts.n_swells;
+            self.attrs.n_swell_hits = counts.n_swell_hits;
+        }
+
         let mut attrs = self.attrs.clone();
         let is_relax = self.difficulty.get_mods().rx();
 
@@ -288,6 +322,10 @@ mod tests {
         }
 
         assert_eq!(actual.max_combo, expected.max_combo);
+        assert_eq!(actual.n_drum_rolls, expected.n_drum_rolls);
+        assert_eq!(actual.n_drum_roll_ticks, expected.n_drum_roll_ticks);
+        assert_eq!(actual.n_swells, expected.n_swells);
+        assert_eq!(actual.n_swell_hits, expected.n_swell_hits);
         assert_eq!(actual.is_convert, expected.is_convert);
     }
 
@@ -5,14 +5,17 @@ use rosu_map::section::general::GameMode;
 use skills::{color::Color, reading::Reading, rhythm::Rhythm, stamina::Stamina};
 
 use crate::{
-    any::difficulty::skills::StrainSkill,
+    any::{
+        difficulty::{section::SectionWindow, skills::StrainSkill},
+        AlgorithmVersion,
+    },
     model::{beatmap::HitWindows, mode::ConvertError},
     taiko::{
         difficulty::{
             color::preprocessor::ColorDifficultyPreprocessor,
-            object::{TaikoDifficultyObject, TaikoDifficultyObjects},
+            object::{ScrollVelocity, TaikoDifficultyObject, TaikoDifficultyObjects},
         },
-        object::TaikoObject,
+        object::{HitTypeRemap, TaikoNestedCounts, TaikoObject},
     },
     util::difficulty::norm,
     Beatmap, Difficulty, GameMods,
@@ -42,20 +45,40 @@ pub fn difficulty(
 ) -> Result<TaikoDifficultyAttributes, ConvertError> {
     let map = map.convert_ref(GameMode::Taiko, difficulty.get_mods())?;
 
+    let map_attrs = map.attributes().difficulty(difficulty);
+
     let HitWindows {
         od_great,
         od_ok,
         od_meh: _,
         ar: _,
-    } = map.attributes().difficulty(difficulty).hit_windows();
+    } = map_attrs.hit_windows();
 
     let DifficultyValues { skills, max_combo } =
         DifficultyValues::calculate(difficulty, &map, od_great);
 
+    let section = difficulty.get_section_window(&map.hit_objects);
+
+    let TaikoNestedCounts {
+        n_drum_rolls,
+        n_drum_roll_ticks,
+        n_swells,
+        n_swell_hits,
+    } = TaikoNestedCounts::new(
+        &map,
+        difficulty.get_passed_objects() as u32,
+        &section,
+        map_attrs.mods_adjusted_od(),
+    );
+
     let mut attrs = TaikoDifficultyAttributes {
         great_hit_window: od_great,
         ok_hit_window: od_ok.unwrap_or(0.0),
         max_combo,
+        n_drum_rolls,
+        n_drum_roll_ticks,
+        n_swells,
+        n_swell_hits,
         is_convert: map.is_convert,
         ..Default::default()
     };
@@ -225,14 +248,17 @@ impl DifficultyValues {
 
         let mut n_diff_objects = 0;
         let mut max_combo = 0;
+        let mut section = difficulty.get_section_window(&converted.hit_objects);
 
         let diff_objects = Self::create_difficulty_objects(
             converted,
             take as u32,
+            &section,
             clock_rate,
             &mut max_combo,
             &mut n_diff_objects,
             difficulty.get_mods(),
+            difficulty.get_algorithm(),
         );
 
         // The first hit object has no difficulty object
@@ -240,9 +266,24 @@ impl DifficultyValues {
 
         // lazer's `DifficultyHitObject.HitWindowGreat` is the full hit window while
         // difficulty attributes expose the one-sided hit window used by performance.
-        let mut skills = TaikoSkills::new(great_hit_window * 2.0, converted.is_convert);
+        let mut skills = TaikoSkills::new(
+            great_hit_window * 2.0,
+            converted.is_convert,
+            difficulty.get_mods(),
+            difficulty.get_algorithm(),
+        );
 
         for hit_object in diff_objects.iter().take(n_diff_objects) {
+            let start_time = hit_object.get().start_time;
+
+            if section.is_past(start_time) {
+                break;
+            }
+
+            if section.enters(start_time) {
+                skills.discard_history();
+            }
+
             skills.rhythm.process(&hit_object.get(), &diff_objects);
             skills.reading.process(&hit_object.get(), &diff_objects);
             skills.color.process(&hit_object.get(), &diff_objects);
@@ -325,20 +366,34 @@ impl DifficultyValues {
     pub fn create_difficulty_objects(
         converted: &Beatmap,
         take: u32,
+        section: &SectionWindow,
         clock_rate: f64,
         max_combo: &mut u32,
         n_diff_objects: &mut usize,
         mods: &GameMods,
+        algorithm: AlgorithmVersion,
     ) -> TaikoDifficultyObjects {
+        let mut remap = HitTypeRemap::new(mods);
+        let mut n_hits = 0;
+
         let mut hit_objects_iter = converted
             .hit_objects
             .iter()
             .zip(converted.hit_sounds.iter())
-            .map(|(h, s)| TaikoObject::new(h, *s))
+            .map(|(h, s)| {
+                let mut h = TaikoObject::new(h, *s);
+                h.hit_type = remap.apply(h.hit_type);
+
+                h
+            })
             .inspect(|h| {
-                if *max_combo < take {
+                if n_hits < take {
                     *n_diff_objects += 1;
-                    *max_combo += u32::from(h.is_hit());
+                    n_hits += u32::from(h.is_hit());
+
+                    if section.contains(h.start_time) {
+                        *max_combo += u32::from(h.is_hit());
+                    }
                 }
             })
             .skip(1);
@@ -350,17 +405,7 @@ impl DifficultyValues {
         let mut diff_objects =
             TaikoDifficultyObjects::with_capacity(converted.hit_objects.len() - 2);
 
-        let mut global_slider_velocity = converted.slider_multiplier;
-
-        if mods.hr() {
-            const SLIDER_MULTIPLIER: f64 = 1.4 * 4.0 / 3.0;
-
-            global_slider_velocity *= SLIDER_MULTIPLIER;
-        }
-
-        if let Some(scroll_speed) = mods.scroll_speed() {
-            global_slider_velocity *= scroll_speed;
-        }
+        let scroll_velocity = ScrollVelocity::new(converted, mods, clock_rate, algorithm);
 
         for (i, curr) in hit_objects_iter.enumerate() {
             let diff_object = TaikoDifficultyObject::new(
@@ -369,7 +414,7 @@ impl DifficultyValues {
                 clock_rate,
                 i,
                 converted,
-                global_slider_velocity,
+                scroll_velocity,
                 &mut diff_objects,
             );
 
@@ -1,11 +1,14 @@
 use std::slice::Iter;
 
 use crate::{
-    any::difficulty::object::{HasStartTime, IDifficultyObject, IDifficultyObjects},
+    any::{
+        difficulty::object::{HasStartTime, IDifficultyObject, IDifficultyObjects},
+        AlgorithmVersion,
+    },
     model::control_point::{EffectPoint, TimingPoint},
     taiko::object::{HitType, TaikoObject},
     util::{interval_grouping::HasInterval, sync::RefCount},
-    Beatmap,
+    Beatmap, GameMods,
 };
 
 use super::{color::color_data::ColorData, rhythm::rhythm_data::RhythmData};
@@ -30,7 +33,7 @@ impl TaikoDifficultyObject {
         clock_rate: f64,
         idx: usize,
         map: &Beatmap,
-        global_slider_velocity: f64,
+        scroll_velocity: ScrollVelocity,
         objects: &mut TaikoDifficultyObjects,
     ) -> RefCount<Self> {
         let delta_time = (hit_object.start_time - last_object.start_time) / clock_rate;
@@ -63,20 +66,27 @@ impl TaikoDifficultyObject {
         // * Using `hitObject.StartTime` causes floating point error differences
         let normalized_start_time = start_time * clock_rate;
 
-        // * Retrieve the timing point at the note's start time
-        let curr_control_point_bpm = map
-            .timing_point_at(normalized_start_time)
-            .map_or(TimingPoint::DEFAULT_BPM, TimingPoint::bpm);
-
-        // * Calculate the slider velocity at the note's start time.
-        let curr_slider_velocity = calculate_slider_velocity(
-            map,
-            normalized_start_time,
-            clock_rate,
-            global_slider_velocity,
-        );
-
-        let effective_bpm = curr_control_point_bpm * curr_slider_velocity;
+        let effective_bpm = match scroll_velocity {
+            ScrollVelocity::Variable {
+                global_slider_velocity,
+            } => {
+                // * Retrieve the timing point at the note's start time
+                let curr_control_point_bpm = map
+                    .timing_point_at(normalized_start_time)
+                    .map_or(TimingPoint::DEFAULT_BPM, TimingPoint::bpm);
+
+                // * Calculate the slider velocity at the note's start time.
+                let curr_slider_velocity = calculate_slider_velocity(
+                    map,
+                    normalized_start_time,
+                    clock_rate,
+                    global_slider_velocity,
+                );
+
+                curr_control_point_bpm * curr_slider_velocity
+            }
+            ScrollVelocity::Constant { effective_bpm } => effective_bpm,
+        };
 
         let this = RefCount::new(Self {
             idx,
@@ -106,6 +116,56 @@ impl TaikoDifficultyObject {
     }
 }
 
+/// How notes scroll across the playfield.
+#[derive(Copy, Clone, Debug)]
+pub enum ScrollVelocity {
+    /// Notes follow the map's timing and effect points.
+    Variable { global_slider_velocity: f64 },
+    /// Constant Speed: every note scrolls with the same velocity.
+    Constant { effective_bpm: f64 },
+}
+
+impl ScrollVelocity {
+    pub fn new(
+        map: &Beatmap,
+        mods: &GameMods,
+        clock_rate: f64,
+        algorithm: AlgorithmVersion,
+    ) -> Self {
+        let mut global_slider_velocity = map.slider_multiplier;
+
+        if mods.hr() {
+            const SLIDER_MULTIPLIER: f64 = 1.4 * 4.0 / 3.0;
+
+            global_slider_velocity *= SLIDER_MULTIPLIER;
+        }
+
+        if let Some(scroll_speed) = mods.scroll_speed() {
+            global_slider_velocity *= scroll_speed;
+        }
+
+        // fork 独自: lazer は Constant Speed を難易度計算で考慮しない
+        if !(mods.constant_speed() && algorithm.fork_adjustments()) {
+            return Self::Variable {
+                global_slider_velocity,
+            };
+        }
+
+        // Constant Speed では SV 変化と BPM 変化が無視されるため、
+        // 最も長く続く BPM で全ノーツが流れるものとして扱う
+        let bpm = map.bpm();
+        let bpm = if bpm.is_finite() && bpm > 0.0 {
+            bpm
+        } else {
+            TimingPoint::DEFAULT_BPM
+        };
+
+        Self::Constant {
+            effective_bpm: bpm * global_slider_velocity * clock_rate,
+        }
+    }
+}
+
 fn calculate_slider_velocity(
     map: &Beatmap,
     start_time: f64,
@@ -1,5 +1,10 @@
 use reading::Reading;
 
+use crate::{
+    any::{difficulty::skills::StrainSkill, AlgorithmVersion},
+    GameMods,
+};
+
 use self::{color::Color, rhythm::Rhythm, stamina::Stamina};
 
 pub mod color;
@@ -17,13 +22,32 @@ pub struct TaikoSkills {
 }
 
 impl TaikoSkills {
-    pub fn new(great_hit_window: f64, is_convert: bool) -> Self {
+    pub fn new(
+        great_hit_window: f64,
+        is_convert: bool,
+        mods: &GameMods,
+        algorithm: AlgorithmVersion,
+    ) -> Self {
+        let visible_playfield = if algorithm.fork_adjustments() {
+            Reading::visible_playfield(mods)
+        } else {
+            1.0
+        };
+
         Self {
             rhythm: Rhythm::new(great_hit_window),
-            reading: Reading::new(),
+            reading: Reading::new(visible_playfield),
             color: Color::new(),
             stamina: Stamina::new(false, is_convert),
             single_color_stamina: Stamina::new(true, is_convert),
         }
     }
+
+    pub fn discard_history(&mut self) {
+        self.rhythm.discard_history();
+        self.reading.discard_history();
+        self.color.discard_history();
+        self.stamina.discard_history();
+        self.single_color_stamina.discard_history();
+    }
 }
@@ -1,12 +1,14 @@
 use crate::{
     taiko::difficulty::object::{TaikoDifficultyObject, TaikoDifficultyObjects},
     util::{difficulty::logistic, sync::Weak},
+    GameMods,
 };
 
 define_skill! {
     #[derive(Clone)]
     pub struct Reading: StrainDecaySkill => TaikoDifficultyObjects[TaikoDifficultyObject] {
         current_strain: f64 = 0.0,
+        visible_playfield: f64,
     }
 }
 
@@ -14,6 +16,29 @@ impl Reading {
     const SKILL_MULTIPLIER: f64 = 1.0;
     const STRAIN_DECAY_BASE: f64 = 0.4;
 
+    // fork 独自: HD はノーツがプレイフィールドの途中でフェードアウトし、
+    // FL は判定枠周辺しか見えないため、読める範囲をこの割合とみなす。
+    // lazer 由来の値ではなく、fork のプレイテストで調整した推定値
+    // (lazer の osu!taiko 難易度計算は HD/FL の視認範囲を考慮しない)。
+    // `AlgorithmVersion::Mamestagram` でのみ使用される。
+    const HIDDEN_VISIBLE_PLAYFIELD: f64 = 0.7;
+    const FLASHLIGHT_VISIBLE_PLAYFIELD: f64 = 0.6;
+
+    /// The portion of the playfield in which notes can be read.
+    pub fn visible_playfield(mods: &GameMods) -> f64 {
+        let mut visible_playfield = 1.0;
+
+        if mods.hd() {
+            visible_playfield *= Self::HIDDEN_VISIBLE_PLAYFIELD;
+        }
+
+        if mods.fl() {
+            visible_playfield *= Self::FLASHLIGHT_VISIBLE_PLAYFIELD;
+        }
+
+        visible_playfield
+    }
+
     /// upstream `GetObjectDifficulties()` 相当。
     pub fn object_strains(&self) -> &[f64] {
         &self.strain_skill_object_strains
@@ -42,7 +67,8 @@ impl Reading {
 
         self.current_strain *= logistic(index as f64, 4.0, -1.0 / 25.0, Some(0.5)) + 0.5;
         self.current_strain *= Self::STRAIN_DECAY_BASE;
-        self.current_strain += ReadingEvaluator::evaluate_diff_of(curr) * Self::SKILL_MULTIPLIER;
+        self.current_strain += ReadingEvaluator::evaluate_diff_of(curr, self.visible_playfield)
+            * Self::SKILL_MULTIPLIER;
 
         self.current_strain
     }
@@ -51,12 +77,13 @@ impl Reading {
 struct ReadingEvaluator;
 
 impl ReadingEvaluator {
-    fn evaluate_diff_of(note_object: &TaikoDifficultyObject) -> f64 {
+    fn evaluate_diff_of(note_object: &TaikoDifficultyObject, visible_playfield: f64) -> f64 {
         let high_velocity = VelocityRange::new(480.0, 640.0);
         let mid_velocity = VelocityRange::new(360.0, 480.0);
 
         // * Apply a cap to prevent outlier values on maps that exceed the editor's parameters.
-        let effective_bpm = f64::max(1.0, note_object.effective_bpm);
+        // 見える範囲が狭いほど、同じ時間でより長い距離を読むことになる
+        let effective_bpm = f64::max(1.0, note_object.effective_bpm) / visible_playfield;
 
         let mid_velocity_diff = 0.5
             * logistic(
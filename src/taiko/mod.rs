@@ -16,6 +16,8 @@ pub use self::{
     strains::TaikoStrains,
 };
 
+pub(crate) use self::convert::drum_roll_duration;
+
 mod attributes;
 mod convert;
 mod difficulty;
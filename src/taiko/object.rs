@@ -1,6 +1,18 @@
 use rosu_map::section::hit_objects::hit_samples::HitSoundType;
 
-use crate::model::hit_object::HitObject;
+use crate::{
+    any::difficulty::section::SectionWindow,
+    model::{
+        control_point::TimingPoint,
+        hit_object::{HitObject, HitObjectKind, HoldNote, Slider, Spinner},
+    },
+    util::{float_ext::FloatExt, system_random::SystemRandom},
+    Beatmap, GameMods,
+};
+
+use super::convert;
+
+const SWELL_HIT_MULTIPLIER: f64 = 1.65;
 
 pub struct TaikoObject {
     pub start_time: f64,
@@ -38,3 +50,152 @@ impl HitType {
         !matches!(self, Self::NonHit)
     }
 }
+
+/// Adjusts the [`HitType`] of hits according to the Swap or Random mod.
+///
+/// The Classic mod is not considered because lazer's `TaikoModClassic` only
+/// adjusts the playfield and drawables, not the converted objects.
+pub enum HitTypeRemap {
+    None,
+    Swap,
+    Random(SystemRandom),
+}
+
+impl HitTypeRemap {
+    /// If the Random mod is specified without a seed, `0` is used so that
+    /// results remain deterministic. Lazer always stores the seed of a play
+    /// so this only happens for legacy mods or manually created mods.
+    pub fn new(mods: &GameMods) -> Self {
+        if mods.random() {
+            Self::Random(SystemRandom::new(mods.random_seed().unwrap_or(0)))
+        } else if mods.swap() {
+            Self::Swap
+        } else {
+            Self::None
+        }
+    }
+
+    /// Must be called for all objects in order so that the seed produces the
+    /// same sequence as osu!lazer.
+    pub fn apply(&mut self, hit_type: HitType) -> HitType {
+        match (self, hit_type) {
+            (Self::None, _) | (_, HitType::NonHit) => hit_type,
+            (Self::Swap, HitType::Center) => HitType::Rim,
+            (Self::Swap, HitType::Rim) => HitType::Center,
+            (Self::Random(rng), _) => {
+                // upstream: `TaikoModRandom.ApplyToBeatmap`
+                if rng.next_max(2) == 0 {
+                    HitType::Center
+                } else {
+                    HitType::Rim
+                }
+            }
+        }
+    }
+}
+
+/// Counts of the non-combo objects of an osu!taiko map.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TaikoNestedCounts {
+    pub n_drum_rolls: u32,
+    pub n_drum_roll_ticks: u32,
+    pub n_swells: u32,
+    pub n_swell_hits: u32,
+}
+
+impl TaikoNestedCounts {
+    /// Count all objects within the section before the hit that follows the
+    /// `take`th hit.
+    ///
+    /// `od` must already be adjusted by HR or EZ.
+    pub fn new(converted: &Beatmap, take: u32, section: &SectionWindow, od: f32) -> Self {
+        let mut counts = Self::default();
+        let mut n_hits = 0;
+
+        for h in converted.hit_objects.iter() {
+            if h.is_circle() {
+                if n_hits >= take {
+                    break;
+                }
+
+                n_hits += 1;
+            }
+
+            if section.contains(h.start_time) {
+                counts.add(converted, h, od);
+            }
+        }
+
+        counts
+    }
+
+    /// Add the nested objects of the given hit object.
+    pub fn add(&mut self, converted: &Beatmap, h: &HitObject, od: f32) {
+        match h.kind {
+            HitObjectKind::Circle => {}
+            HitObjectKind::Slider(ref slider) => {
+                self.n_drum_rolls += 1;
+                self.n_drum_roll_ticks += drum_roll_ticks(converted, h.start_time, slider);
+            }
+            HitObjectKind::Spinner(Spinner { duration })
+            | HitObjectKind::Hold(HoldNote { duration }) => {
+                self.n_swells += 1;
+                self.n_swell_hits += swell_required_hits(duration, od);
+            }
+        }
+    }
+}
+
+/// The amount of ticks of a drum roll.
+///
+/// See `DrumRoll.createTicks` in lazer.
+fn drum_roll_ticks(converted: &Beatmap, start_time: f64, slider: &Slider) -> u32 {
+    let duration = f64::from(convert::drum_roll_duration(converted, start_time, slider));
+
+    let tick_rate = if FloatExt::eq(converted.slider_tick_rate, 3.0) {
+        3.0
+    } else {
+        4.0
+    };
+
+    let beat_len = converted
+        .timing_point_at(start_time)
+        .map_or(TimingPoint::DEFAULT_BEAT_LEN, |point| point.beat_len);
+
+    let tick_spacing = beat_len / tick_rate;
+
+    if tick_spacing <= 0.0 {
+        return 0;
+    }
+
+    let end_time = start_time + duration;
+    let mut count = 0;
+    let mut time = start_time;
+
+    while time < end_time + tick_spacing / 2.0 {
+        count += 1;
+        time += tick_spacing;
+    }
+
+    count
+}
+
+/// The amount of hits required to complete a swell.
+///
+/// See `Swell.ApplyDefaultsToSelf` in lazer.
+fn swell_required_hits(duration: f64, od: f32) -> u32 {
+    let od = f64::from(od);
+
+    // * `DifficultyRange(od, 3, 5, 7.5)`
+    let difficulty_range = if od > 5.0 {
+        5.0 + (7.5 - 5.0) * (od - 5.0) / 5.0
+    } else if od < 5.0 {
+        5.0 - (5.0 - 3.0) * (5.0 - od) / 5.0
+    } else {
+        5.0
+    };
+
+    let hit_multiplier = difficulty_range * SWELL_HIT_MULTIPLIER;
+
+    (duration / 1000.0 * hit_multiplier).max(1.0) as u32
+}
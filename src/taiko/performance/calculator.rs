@@ -1,8 +1,9 @@
 use crate::{
+    any::{deviation, trace::Trace, AlgorithmVersion},
     taiko::{TaikoDifficultyAttributes, TaikoPerformanceAttributes, TaikoScoreState},
     util::{
         difficulty::{logistic, reverse_lerp},
-        special_functions::{erf, erf_inv},
+        special_functions::erf,
     },
     GameMods,
 };
@@ -11,6 +12,8 @@ pub(super) struct TaikoPerformanceCalculator<'mods> {
     attrs: TaikoDifficultyAttributes,
     mods: &'mods GameMods,
     state: TaikoScoreState,
+    algorithm: AlgorithmVersion,
+    trace: Trace<'mods>,
 }
 
 impl<'a> TaikoPerformanceCalculator<'a> {
@@ -18,8 +21,16 @@ impl<'a> TaikoPerformanceCalculator<'a> {
         attrs: TaikoDifficultyAttributes,
         mods: &'a GameMods,
         state: TaikoScoreState,
+        algorithm: AlgorithmVersion,
+        trace: Trace<'a>,
     ) -> Self {
-        Self { attrs, mods, state }
+        Self {
+            attrs,
+            mods,
+            state,
+            algorithm,
+            trace,
+        }
     }
 }
 
@@ -46,14 +57,19 @@ impl TaikoPerformanceCalculator<'_> {
         let is_classic = self.mods.cl(); // Classic mod flag
 
         // upstream: difficulty * 1.08 + accuracy * 1.1
-        let difficulty_value = self
-            .compute_difficulty_value(estimated_unstable_rate, total_difficult_hits, is_convert, is_classic)
-            * 1.08;
-        let accuracy_value = self
-            .compute_accuracy_value(estimated_unstable_rate, total_difficult_hits, is_convert)
-            * 1.1;
-
-        let pp = difficulty_value + accuracy_value;
+        let difficulty_value = self.compute_difficulty_value(
+            estimated_unstable_rate,
+            total_difficult_hits,
+            is_convert,
+            is_classic,
+        ) * self.trace.factor("difficulty", "multiplier", 1.08);
+        let accuracy_value =
+            self.compute_accuracy_value(estimated_unstable_rate, total_difficult_hits, is_convert)
+                * self.trace.factor("accuracy", "multiplier", 1.1);
+
+        let pp = self
+            .trace
+            .value("total", "pp", difficulty_value + accuracy_value);
 
         // effective_miss_count は upstream には無いフィールドだが後方互換のため残す。
         // upstream の miss_penalty は effective_miss_count ではなく countMiss を直接使うので、
@@ -116,22 +132,36 @@ impl TaikoPerformanceCalculator<'_> {
         let base_difficulty = 5.0 * f64::max(1.0, self.attrs.stars * rhythm_penalty / 0.110) - 4.0;
 
         // upstream: min(pow(base, 3) / 69052.51, pow(base, 2.25) / 1250.0)
-        let mut difficulty_value = f64::min(
-            base_difficulty.powi(3) / 69052.51,
-            base_difficulty.powf(2.25) / 1250.0,
+        let mut difficulty_value = self.trace.value(
+            "difficulty",
+            "difficulty to performance",
+            f64::min(
+                base_difficulty.powi(3) / 69052.51,
+                base_difficulty.powf(2.25) / 1250.0,
+            ),
         );
 
         // upstream: *= 1 + 0.10 * max(0, StarRating - 10)
-        difficulty_value *= 1.0 + 0.10 * f64::max(0.0, self.attrs.stars - 10.0);
+        difficulty_value *= self.trace.factor(
+            "difficulty",
+            "high star bonus",
+            1.0 + 0.10 * f64::max(0.0, self.attrs.stars - 10.0),
+        );
 
         // upstream: lengthBonus = 1 + 0.25 * totalDifficultHits / (totalDifficultHits + 4000)
         let length_bonus = 1.0 + 0.25 * total_difficult_hits / (total_difficult_hits + 4000.0);
-        difficulty_value *= length_bonus;
+        difficulty_value *= self
+            .trace
+            .factor("difficulty", "length bonus", length_bonus);
 
         // upstream: missPenalty = 0.97 + 0.03 * totalDifficultHits / (totalDifficultHits + 1500)
         //           difficulty_value *= pow(missPenalty, countMiss)
         let miss_penalty = 0.97 + 0.03 * total_difficult_hits / (total_difficult_hits + 1500.0);
-        difficulty_value *= miss_penalty.powf(f64::from(self.state.misses));
+        difficulty_value *= self.trace.factor(
+            "difficulty",
+            "miss penalty",
+            miss_penalty.powf(f64::from(self.state.misses)),
+        );
 
         // upstream: Hidden bonus (complex)
         if self.mods.hd() {
@@ -149,14 +179,25 @@ impl TaikoPerformanceCalculator<'_> {
                 }
             }
 
-            difficulty_value *= 1.0 + hidden_bonus;
+            // fork 独自: Constant Speed では SV 変化を覚える必要が無いため半減
+            if self.mods.constant_speed() && self.algorithm.fork_adjustments() {
+                hidden_bonus *= 0.5;
+            }
+
+            difficulty_value *= self
+                .trace
+                .factor("difficulty", "hidden bonus", 1.0 + hidden_bonus);
         }
 
         // upstream: Flashlight bonus
         if self.mods.fl() {
-            difficulty_value *= f64::max(
-                1.0,
-                1.050 - f64::min(self.attrs.mono_stamina_factor / 50.0, 1.0) * length_bonus,
+            difficulty_value *= self.trace.factor(
+                "difficulty",
+                "flashlight bonus",
+                f64::max(
+                    1.0,
+                    1.050 - f64::min(self.attrs.mono_stamina_factor / 50.0, 1.0) * length_bonus,
+                ),
             );
         }
 
@@ -167,8 +208,12 @@ impl TaikoPerformanceCalculator<'_> {
         let mono_acc_scaling_shift = 500.0 - 100.0 * (self.attrs.mono_stamina_factor * 3.0);
 
         difficulty_value
-            * erf(mono_acc_scaling_shift / (f64::sqrt(2.0) * estimated_unstable_rate))
-                .powf(mono_acc_scaling_exp)
+            * self.trace.factor(
+                "difficulty",
+                "accuracy scaling",
+                erf(mono_acc_scaling_shift / (f64::sqrt(2.0) * estimated_unstable_rate))
+                    .powf(mono_acc_scaling_exp),
+            )
     }
 
     fn compute_accuracy_value(
@@ -186,27 +231,40 @@ impl TaikoPerformanceCalculator<'_> {
         };
 
         // upstream: 470 * pow(0.9885, EUR)
-        let mut accuracy_value = 470.0 * 0.9885_f64.powf(estimated_unstable_rate);
+        let mut accuracy_value = self.trace.value(
+            "accuracy",
+            "unstable rate",
+            470.0 * 0.9885_f64.powf(estimated_unstable_rate),
+        );
 
         // upstream: *= 1 + pow(50/EUR, 2) * pow(StarRating, 2.8) / 600
-        accuracy_value *= 1.0
-            + (50.0 / estimated_unstable_rate).powi(2)
-                * self.attrs.stars.powf(2.8)
-                / 600.0;
+        accuracy_value *= self.trace.factor(
+            "accuracy",
+            "stars bonus",
+            1.0 + (50.0 / estimated_unstable_rate).powi(2) * self.attrs.stars.powf(2.8) / 600.0,
+        );
 
         // upstream: Hidden bonus (only if not convert)
         if self.mods.hd() && !is_convert {
-            accuracy_value *= 1.075;
+            accuracy_value *= self.trace.factor("accuracy", "hidden bonus", 1.075);
         }
 
         // upstream: length bonus based on totalDifficultHits
-        accuracy_value *= 1.0 + 0.3 * total_difficult_hits / (total_difficult_hits + 4000.0);
+        accuracy_value *= self.trace.factor(
+            "accuracy",
+            "length bonus",
+            1.0 + 0.3 * total_difficult_hits / (total_difficult_hits + 4000.0),
+        );
 
         // upstream: HDFL memory bonus
         let memory_length_bonus = f64::min(1.15, (self.total_hits() / 1500.0).powf(0.3));
 
         if self.mods.fl() && self.mods.hd() && !is_convert {
-            accuracy_value *= f64::max(1.0, 1.05 * memory_length_bonus);
+            accuracy_value *= self.trace.factor(
+                "accuracy",
+                "hidden flashlight bonus",
+                f64::max(1.0, 1.05 * memory_length_bonus),
+            );
         }
 
         accuracy_value
@@ -214,15 +272,7 @@ impl TaikoPerformanceCalculator<'_> {
 
     // upstream: computeDeviationUpperBound(accuracy)
     fn compute_deviation_upper_bound(&self, accuracy: f64) -> f64 {
-        const Z: f64 = 2.32634787404; // 99% critical value (one-tailed)
-
-        let n = self.total_hits();
-        let p = accuracy;
-
-        let p_lower_bound = (n * p + Z * Z / 2.0) / (n + Z * Z)
-            - Z / (n + Z * Z) * f64::sqrt(n * p * (1.0 - p) + Z * Z / 4.0);
-
-        self.attrs.great_hit_window / (f64::sqrt(2.0) * erf_inv(p_lower_bound))
+        deviation::deviation_upper_bound(self.attrs.great_hit_window, self.total_hits(), accuracy)
     }
 
     const fn total_hits(&self) -> f64 {
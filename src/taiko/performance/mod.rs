@@ -5,7 +5,11 @@ use rosu_map::section::general::GameMode;
 use self::calculator::TaikoPerformanceCalculator;
 
 use crate::{
-    any::{Difficulty, HitResultPriority, IntoModePerformance, IntoPerformance},
+    any::{
+        trace::{self, Trace},
+        AlgorithmVersion, Difficulty, HitResultPriority, IntoModePerformance, IntoPerformance,
+        PerformanceTrace,
+    },
     model::{mode::ConvertError, mods::GameMods},
     osu::OsuPerformance,
     util::map_or_attrs::MapOrAttrs,
@@ -29,6 +33,8 @@ pub struct TaikoPerformance<'map> {
     n300: Option<u32>,
     n100: Option<u32>,
     misses: Option<u32>,
+    drum_roll_tick_hits: Option<u32>,
+    swells_completed: Option<u32>,
 }
 
 impl<'map> TaikoPerformance<'map> {
@@ -124,6 +130,34 @@ impl<'map> TaikoPerformance<'map> {
         self
     }
 
+    /// Specify the amount of hit drum roll ticks.
+    ///
+    /// Only relevant for osu!lazer scores where this value is listed as
+    /// `small_bonus`. Defaults to all ticks being hit.
+    ///
+    /// Bonus results affect neither the miss estimation nor the pp so the
+    /// value is only clamped and passed on to the resulting
+    /// [`TaikoScoreState`].
+    pub const fn drum_roll_tick_hits(mut self, drum_roll_tick_hits: u32) -> Self {
+        self.drum_roll_tick_hits = Some(drum_roll_tick_hits);
+
+        self
+    }
+
+    /// Specify the amount of completed swells.
+    ///
+    /// Only relevant for osu!lazer scores where this value is listed as
+    /// `large_bonus`. Defaults to all swells being completed.
+    ///
+    /// Bonus results affect neither the miss estimation nor the pp so the
+    /// value is only clamped and passed on to the resulting
+    /// [`TaikoScoreState`].
+    pub const fn swells_completed(mut self, swells_completed: u32) -> Self {
+        self.swells_completed = Some(swells_completed);
+
+        self
+    }
+
     /// Specify the accuracy of a play between `0.0` and `100.0`.
     /// This will be used to generate matching hitresults.
     pub fn accuracy(mut self, acc: f64) -> Self {
@@ -139,6 +173,15 @@ impl<'map> TaikoPerformance<'map> {
         self
     }
 
+    /// Specify the [`AlgorithmVersion`] of the calculation.
+    ///
+    /// Defaults to [`AlgorithmVersion::Mamestagram`].
+    pub fn algorithm(mut self, algorithm: AlgorithmVersion) -> Self {
+        self.difficulty = self.difficulty.algorithm(algorithm);
+
+        self
+    }
+
     /// Amount of passed objects for partial plays, e.g. a fail.
     ///
     /// If you want to calculate the performance after every few objects,
@@ -203,12 +246,16 @@ impl<'map> TaikoPerformance<'map> {
             n300,
             n100,
             misses,
+            drum_roll_tick_hits,
+            swells_completed,
         } = state;
 
         self.combo = Some(max_combo);
         self.n300 = Some(n300);
         self.n100 = Some(n100);
         self.misses = Some(misses);
+        self.drum_roll_tick_hits = Some(drum_roll_tick_hits);
+        self.swells_completed = Some(swells_completed);
 
         self
     }
@@ -286,6 +333,16 @@ impl<'map> TaikoPerformance<'map> {
             }
         }
 
+        let drum_roll_tick_hits = self
+            .drum_roll_tick_hits
+            .map_or(attrs.n_drum_roll_ticks, |n| {
+                cmp::min(n, attrs.n_drum_roll_ticks)
+            });
+
+        let swells_completed = self
+            .swells_completed
+            .map_or(attrs.n_swells, |n| cmp::min(n, attrs.n_swells));
+
         let max_possible_combo = max_combo.saturating_sub(misses);
 
         let max_combo = self.combo.map_or(max_possible_combo, |combo| {
@@ -296,17 +353,38 @@ impl<'map> TaikoPerformance<'map> {
         self.n300 = Some(n300);
         self.n100 = Some(n100);
         self.misses = Some(misses);
+        self.drum_roll_tick_hits = Some(drum_roll_tick_hits);
+        self.swells_completed = Some(swells_completed);
 
         Ok(TaikoScoreState {
             max_combo,
             n300,
             n100,
             misses,
+            drum_roll_tick_hits,
+            swells_completed,
         })
     }
 
     /// Calculate all performance related values, including pp and stars.
-    pub fn calculate(mut self) -> Result<TaikoPerformanceAttributes, ConvertError> {
+    pub fn calculate(self) -> Result<TaikoPerformanceAttributes, ConvertError> {
+        self.calculate_inner(Trace::disabled())
+    }
+
+    /// Same as [`TaikoPerformance::calculate`] but also records each value
+    /// and factor of the pp formula in a [`PerformanceTrace`].
+    pub fn calculate_with_trace(
+        self,
+    ) -> Result<(TaikoPerformanceAttributes, PerformanceTrace), ConvertError> {
+        let (res, trace) = trace::traced(|trace| self.calculate_inner(trace));
+
+        res.map(|attrs| (attrs, trace))
+    }
+
+    fn calculate_inner(
+        mut self,
+        trace: Trace<'_>,
+    ) -> Result<TaikoPerformanceAttributes, ConvertError> {
         let state = self.generate_state()?;
 
         let attrs = match self.map_or_attrs {
@@ -314,7 +392,14 @@ impl<'map> TaikoPerformance<'map> {
             MapOrAttrs::Map(ref map) => self.difficulty.calculate_for_mode::<Taiko>(map)?,
         };
 
-        Ok(TaikoPerformanceCalculator::new(attrs, self.difficulty.get_mods(), state).calculate())
+        Ok(TaikoPerformanceCalculator::new(
+            attrs,
+            self.difficulty.get_mods(),
+            state,
+            self.difficulty.get_algorithm(),
+            trace,
+        )
+        .calculate())
     }
 
     pub(crate) const fn from_map_or_attrs(map_or_attrs: MapOrAttrs<'map, Taiko>) -> Self {
@@ -326,6 +411,8 @@ impl<'map> TaikoPerformance<'map> {
             misses: None,
             n300: None,
             n100: None,
+            drum_roll_tick_hits: None,
+            swells_completed: None,
             hitresult_priority: HitResultPriority::DEFAULT,
         }
     }
@@ -376,6 +463,8 @@ impl<'map> TryFrom<OsuPerformance<'map>> for TaikoPerformance<'map> {
             n300,
             n100,
             misses,
+            drum_roll_tick_hits: None,
+            swells_completed: None,
         })
     }
 }
@@ -449,6 +538,8 @@ mod test {
 
         let mut best_state = TaikoScoreState {
             misses,
+            drum_roll_tick_hits: attrs().n_drum_roll_ticks,
+            swells_completed: attrs().n_swells,
             ..Default::default()
         };
 
@@ -560,6 +651,8 @@ mod test {
             n300: 150,
             n100: 137,
             misses: 2,
+            drum_roll_tick_hits: attrs().n_drum_roll_ticks,
+            swells_completed: attrs().n_swells,
         };
 
         assert_eq!(state, expected);
@@ -579,6 +672,8 @@ mod test {
             n300: 287,
             n100: 0,
             misses: 2,
+            drum_roll_tick_hits: attrs().n_drum_roll_ticks,
+            swells_completed: attrs().n_swells,
         };
 
         assert_eq!(state, expected);
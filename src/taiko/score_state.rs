@@ -1,5 +1,6 @@
 /// Aggregation for a score's current state.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TaikoScoreState {
     /// Maximum combo that the score has had so far.
     /// **Not** the maximum possible combo of the map so far.
@@ -10,6 +11,16 @@ pub struct TaikoScoreState {
     pub n100: u32,
     /// Amount of current misses.
     pub misses: u32,
+    /// Amount of hit drum roll ticks.
+    ///
+    /// Only relevant for osu!lazer where they're listed as `small_bonus`.
+    /// Bonus results do not affect pp so this value is informational.
+    pub drum_roll_tick_hits: u32,
+    /// Amount of completed swells.
+    ///
+    /// Only relevant for osu!lazer where they're listed as `large_bonus`.
+    /// Bonus results do not affect pp so this value is informational.
+    pub swells_completed: u32,
 }
 
 impl TaikoScoreState {
@@ -20,6 +31,8 @@ impl TaikoScoreState {
             n300: 0,
             n100: 0,
             misses: 0,
+            drum_roll_tick_hits: 0,
+            swells_completed: 0,
         }
     }
 
@@ -248,6 +248,12 @@ macro_rules! define_skill {
                 )
             }
 
+            fn discard_history(&mut self) {
+                self.strain_skill_strain_peaks.clear();
+                self.strain_skill_object_strains.clear();
+                self.strain_skill_current_section_peak = 0.0;
+            }
+
             fn save_current_peak(&mut self) {
                 self.strain_skill_strain_peaks.push(self.strain_skill_current_section_peak);
             }
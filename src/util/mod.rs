@@ -4,11 +4,14 @@ pub mod hint;
 pub mod interval_grouping;
 pub mod limited_queue;
 pub mod map_or_attrs;
+#[cfg(feature = "metadata")]
+pub mod md5;
 pub mod random;
 pub mod sort;
 pub mod special_functions;
 pub mod strains_vec;
 pub mod sync;
+pub mod system_random;
 
 #[macro_use]
 mod macros;
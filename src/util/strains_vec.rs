@@ -69,6 +69,18 @@ mod inner {
             self.len += 1;
         }
 
+        /// Removes all entries.
+        #[inline]
+        pub fn clear(&mut self) {
+            self.inner.clear();
+            self.len = 0;
+
+            #[cfg(debug_assertions)]
+            {
+                self.has_zero = false;
+            }
+        }
+
         /// Sorts the entries in descending order.
         #[inline]
         pub fn sort_desc(&mut self) {
@@ -405,6 +417,10 @@ mod inner {
             self.inner.push(value);
         }
 
+        pub fn clear(&mut self) {
+            self.inner.clear();
+        }
+
         pub fn sort_desc(&mut self) {
             self.inner.sort_by(|a, b| b.total_cmp(a));
         }
@@ -0,0 +1,106 @@
+const MBIG: i32 = i32::MAX;
+const MSEED: i32 = 161_803_398;
+
+/// Port of .NET's seeded `System.Random`.
+///
+/// See <https://github.com/dotnet/runtime/blob/main/src/libraries/System.Private.CoreLib/src/System/Random.Net5CompatImpl.cs>
+pub struct SystemRandom {
+    seed_array: [i32; 56],
+    inext: usize,
+    inextp: usize,
+}
+
+impl SystemRandom {
+    pub fn new(seed: i32) -> Self {
+        let mut seed_array = [0; 56];
+
+        let subtraction = if seed == i32::MIN {
+            i32::MAX
+        } else {
+            seed.abs()
+        };
+
+        let mut mj = MSEED - subtraction;
+        seed_array[55] = mj;
+        let mut mk = 1;
+        let mut ii = 0;
+
+        for _ in 1..55 {
+            ii += 21;
+
+            if ii >= 55 {
+                ii -= 55;
+            }
+
+            seed_array[ii] = mk;
+            mk = mj - mk;
+
+            if mk < 0 {
+                mk += MBIG;
+            }
+
+            mj = seed_array[ii];
+        }
+
+        for _ in 1..5 {
+            for i in 1..56 {
+                let mut n = i + 30;
+
+                if n >= 55 {
+                    n -= 55;
+                }
+
+                seed_array[i] -= seed_array[1 + n];
+
+                if seed_array[i] < 0 {
+                    seed_array[i] += MBIG;
+                }
+            }
+        }
+
+        Self {
+            seed_array,
+            inext: 0,
+            inextp: 21,
+        }
+    }
+
+    fn internal_sample(&mut self) -> i32 {
+        let mut loc_inext = self.inext + 1;
+
+        if loc_inext >= 56 {
+            loc_inext = 1;
+        }
+
+        let mut loc_inextp = self.inextp + 1;
+
+        if loc_inextp >= 56 {
+            loc_inextp = 1;
+        }
+
+        let mut ret_val = self.seed_array[loc_inext] - self.seed_array[loc_inextp];
+
+        if ret_val == MBIG {
+            ret_val -= 1;
+        }
+
+        if ret_val < 0 {
+            ret_val += MBIG;
+        }
+
+        self.seed_array[loc_inext] = ret_val;
+        self.inext = loc_inext;
+        self.inextp = loc_inextp;
+
+        ret_val
+    }
+
+    pub fn sample(&mut self) -> f64 {
+        f64::from(self.internal_sample()) * (1.0 / f64::from(MBIG))
+    }
+
+    /// Returns a non-negative integer less than `max`.
+    pub fn next_max(&mut self, max: i32) -> i32 {
+        (self.sample() * f64::from(max)) as i32
+    }
+}
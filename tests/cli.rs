@@ -0,0 +1,115 @@
+#![cfg(feature = "cli")]
+
+use std::{fs, path::PathBuf, process::Command};
+
+use mames_pp::{any::HitResult, Beatmap, Difficulty, GradualPerformance, Performance};
+use serde_json::Value;
+
+const MAP: &str = "./resources/2785319.osu";
+
+fn assert_close(actual: Option<f64>, expected: f64) {
+    let actual = actual.unwrap();
+
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "expected {expected}, got {actual}"
+    );
+}
+
+fn run(args: &[&str]) -> Value {
+    let output = Command::new(env!("CARGO_BIN_EXE_mames-pp"))
+        .arg(MAP)
+        .args(args)
+        .arg("--json")
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+fn hit_results_file(name: &str, content: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("mames-pp-{}-{name}", std::process::id()));
+    fs::write(&path, content).unwrap();
+
+    path
+}
+
+#[test]
+fn matches_performance() {
+    let json = run(&["--mods", "HDDT", "--acc", "98.5", "--misses", "2"]);
+
+    let map = Beatmap::from_path(MAP).unwrap();
+    let expected = Performance::new(&map)
+        .mods(64 + 8)
+        .accuracy(98.5)
+        .misses(2)
+        .calculate();
+
+    assert_close(json["performance"]["osu"]["pp"].as_f64(), expected.pp());
+}
+
+#[test]
+fn gradual_uses_hit_results() {
+    let path = hit_results_file("gradual", "300 100+tick+tick_miss+tail x 50");
+    let json = run(&["--gradual", "--hit-results", path.to_str().unwrap()]);
+    fs::remove_file(path).unwrap();
+
+    let map = Beatmap::from_path(MAP).unwrap();
+    let mut expected = GradualPerformance::new(Difficulty::new(), &map).with_hit_results();
+
+    let expected_pp = [
+        expected.next(HitResult::Great).unwrap().pp(),
+        expected
+            .next_with_nested(
+                HitResult::Ok,
+                [
+                    HitResult::LargeTickHit,
+                    HitResult::LargeTickMiss,
+                    HitResult::SliderTailHit,
+                ],
+            )
+            .unwrap()
+            .pp(),
+        expected.next(HitResult::Miss).unwrap().pp(),
+        expected.next(HitResult::Meh).unwrap().pp(),
+    ];
+
+    let gradual = json["gradual"].as_array().unwrap();
+    assert_eq!(gradual.len(), expected_pp.len());
+
+    for (entry, pp) in gradual.iter().zip(expected_pp) {
+        assert_close(entry["pp"].as_f64(), pp);
+    }
+}
+
+#[test]
+fn gradual_requires_hit_results() {
+    let status = Command::new(env!("CARGO_BIN_EXE_mames-pp"))
+        .args([MAP, "--gradual"])
+        .output()
+        .unwrap()
+        .status;
+
+    assert!(!status.success());
+}
+
+#[test]
+fn invalid_hit_result() {
+    let path = hit_results_file("invalid", "300 nope");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_mames-pp"))
+        .args([MAP, "--gradual", "--hit-results", path.to_str().unwrap()])
+        .output()
+        .unwrap();
+
+    fs::remove_file(path).unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown hit result `nope`"));
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "ffi")]
+
+use std::{fs, path::Path};
+
+#[test]
+fn header_matches_bindings() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let config = cbindgen::Config::from_file(root.join("cbindgen.toml")).unwrap();
+
+    let mut generated = Vec::new();
+
+    cbindgen::Builder::new()
+        .with_crate(root)
+        .with_config(config)
+        .generate()
+        .unwrap()
+        .write(&mut generated);
+
+    let committed = fs::read(root.join("include/mames_pp.h")).unwrap();
+
+    assert!(
+        generated == committed,
+        "include/mames_pp.h is outdated, regenerate it with \
+        `cbindgen --config cbindgen.toml --output include/mames_pp.h`"
+    );
+}
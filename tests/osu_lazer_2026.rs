@@ -1,8 +1,12 @@
 use mames_pp::{
+    any::{AlgorithmVersion, DifficultySection, TraceStepKind},
+    catch::Catch,
+    mania::Mania,
     osu::{
-        Osu, OsuDifficultyAttributes, OsuGradualDifficulty, OsuPerformance,
+        Osu, OsuDifficultyAttributes, OsuGradualDifficulty, OsuParameters, OsuPerformance,
         OsuPerformanceAttributes,
     },
+    taiko::Taiko,
     Beatmap, Difficulty, GameMods,
 };
 use rosu_mods::{GameModIntermode, GameModsIntermode};
@@ -391,3 +395,341 @@ fn lazer_legacy_score_miss_estimation() {
         assert_close(actual, expected, PRECISE_EPSILON, name);
     }
 }
+
+#[test]
+fn single_tap_speed() {
+    let map = map("diffcalc-test");
+
+    let nm = difficulty(&map, 0_u32.into(), true);
+    let single_tap = difficulty(&map, intermode(GameModIntermode::SingleTap), true);
+    let alternate = difficulty(&map, intermode(GameModIntermode::Alternate), true);
+
+    assert!(single_tap.speed > nm.speed);
+    assert_eq!(single_tap.aim, nm.aim);
+    assert_eq!(alternate.speed, nm.speed);
+    assert_eq!(alternate.speed_note_count, nm.speed_note_count);
+
+    let single_tap_pp = ss(&map, intermode(GameModIntermode::SingleTap));
+    assert!(single_tap_pp.pp_speed > ss(&map, 0_u32.into()).pp_speed);
+
+    let single_tap_with = |params: OsuParameters| {
+        Difficulty::new()
+            .mods(intermode(GameModIntermode::SingleTap))
+            .lazer(true)
+            .osu_parameters(params)
+            .calculate_for_mode::<Osu>(&map)
+            .unwrap()
+            .speed
+    };
+
+    // With alternating parameters, SingleTap rates exactly like no mod
+    let alternating = OsuParameters {
+        single_tap_bonus_bpm: 200.0,
+        single_tap_doubletapness: 1.0,
+        ..OsuParameters::default()
+    };
+    assert_eq!(single_tap_with(alternating.clone()), nm.speed);
+
+    let lower_bpm = OsuParameters {
+        single_tap_bonus_bpm: 150.0,
+        ..alternating
+    };
+    assert!(single_tap_with(lower_bpm) > nm.speed);
+    assert_eq!(single_tap_with(OsuParameters::default()), single_tap.speed);
+}
+
+#[test]
+fn algorithm_versions() {
+    let map = map("diffcalc-test");
+
+    let ss_with = |mods: GameMods, algorithm: AlgorithmVersion| {
+        let attrs = Difficulty::new()
+            .mods(mods.clone())
+            .algorithm(algorithm)
+            .calculate_for_mode::<Osu>(&map)
+            .unwrap();
+
+        OsuPerformance::new(attrs)
+            .mods(mods)
+            .algorithm(algorithm)
+            .calculate()
+            .unwrap()
+    };
+
+    let nm = ss_with(0_u32.into(), AlgorithmVersion::Mamestagram);
+    let nm_2026 = ss_with(0_u32.into(), AlgorithmVersion::Lazer2026);
+
+    assert_eq!(nm_2026, nm);
+
+    let rx = ss_with(128_u32.into(), AlgorithmVersion::Mamestagram);
+    let rx_2026 = ss_with(128_u32.into(), AlgorithmVersion::Lazer2026);
+    assert_eq!(rx.pp_reading, 0.0);
+    assert!((rx_2026.pp - rx.pp).abs() > 1.0);
+
+    let single_tap = intermode(GameModIntermode::SingleTap);
+    let single_tap_2026 = Difficulty::new()
+        .mods(single_tap.clone())
+        .algorithm(AlgorithmVersion::Lazer2026)
+        .calculate_for_mode::<Osu>(&map)
+        .unwrap();
+    assert_eq!(single_tap_2026.speed, nm.difficulty.speed);
+    assert!(difficulty(&map, single_tap, true).speed > single_tap_2026.speed);
+}
+
+#[test]
+fn tunable_parameters() {
+    let map = map("diffcalc-test");
+
+    let with_params = |params: OsuParameters| {
+        let difficulty = Difficulty::new().osu_parameters(params);
+        let attrs = difficulty.calculate_for_mode::<Osu>(&map).unwrap();
+
+        OsuPerformance::new(attrs)
+            .difficulty(difficulty)
+            .misses(3)
+            .calculate()
+            .unwrap()
+    };
+
+    let live = OsuPerformance::new(difficulty(&map, 0_u32.into(), true))
+        .misses(3)
+        .calculate()
+        .unwrap();
+
+    assert_eq!(with_params(OsuParameters::default()), live);
+
+    let harsh_misses = with_params(OsuParameters {
+        miss_penalty_multiplier: 0.8,
+        ..OsuParameters::default()
+    });
+    assert_eq!(harsh_misses.difficulty, live.difficulty);
+    assert!(harsh_misses.pp < live.pp);
+
+    let reading = with_params(OsuParameters {
+        reading_skill_multiplier: 3.0,
+        ..OsuParameters::default()
+    });
+    assert!(reading.difficulty.reading > live.difficulty.reading);
+    assert_eq!(reading.difficulty.aim, live.difficulty.aim);
+
+    let multiplier = with_params(OsuParameters {
+        performance_base_multiplier: 1.2,
+        ..OsuParameters::default()
+    });
+    assert!(multiplier.difficulty.stars > live.difficulty.stars);
+    assert!(multiplier.pp > live.pp);
+
+    let slow_aim_decay = with_params(OsuParameters {
+        aim_strain_decay_base: 0.4,
+        ..OsuParameters::default()
+    });
+    assert!(slow_aim_decay.difficulty.aim > live.difficulty.aim);
+    assert_eq!(slow_aim_decay.difficulty.speed, live.difficulty.speed);
+}
+
+#[test]
+fn performance_trace() {
+    let map = map("diffcalc-test");
+    let attrs = difficulty(&map, intermode(GameModIntermode::Hidden), true);
+
+    let perf = || {
+        OsuPerformance::new(attrs.clone())
+            .mods(intermode(GameModIntermode::Hidden))
+            .misses(3)
+    };
+
+    let (traced, trace) = perf().calculate_with_trace().unwrap();
+    assert_eq!(traced, perf().calculate().unwrap());
+
+    let pp = trace
+        .component("total")
+        .filter(|step| step.kind == TraceStepKind::Value && step.name == "pp")
+        .map(|step| step.value)
+        .last();
+    assert_eq!(pp, Some(traced.pp));
+
+    let miss_penalty = trace.factor("aim", "miss penalty").unwrap();
+    assert!(miss_penalty > 0.0 && miss_penalty < 1.0);
+    assert!(trace.factor("speed", "miss penalty").is_some());
+    assert!(trace.factor("aim", "length bonus").is_some());
+    assert!(trace.factor("total", "base multiplier").is_some());
+    assert!(trace.factor("total", "no fail").is_none());
+
+    let (_, ss_trace) = OsuPerformance::new(attrs)
+        .mods(intermode(GameModIntermode::Hidden))
+        .calculate_with_trace()
+        .unwrap();
+    assert!(ss_trace.factor("aim", "miss penalty").is_none());
+}
+
+#[test]
+fn section_difficulty() {
+    let map = map("2785319");
+    let n_objects = map.hit_objects.len() as u32;
+    let half = n_objects / 2;
+
+    let stars = |section: Option<DifficultySection>, clock_rate: f64| {
+        let difficulty = Difficulty::new().clock_rate(clock_rate);
+
+        let attrs = match section {
+            Some(section) => difficulty.calculate_section_for_mode::<Osu>(&map, section),
+            None => difficulty.calculate_for_mode::<Osu>(&map),
+        };
+
+        attrs.unwrap().stars
+    };
+
+    let full = stars(None, 1.0);
+    let everything = DifficultySection::Objects {
+        start: 0,
+        end: n_objects,
+    };
+    assert_eq!(stars(Some(everything), 1.0), full);
+
+    let first_half = DifficultySection::Objects {
+        start: 0,
+        end: half,
+    };
+    let second_half = DifficultySection::Objects {
+        start: half,
+        end: n_objects,
+    };
+    assert!(stars(Some(first_half), 1.0) < full);
+    assert!(stars(Some(second_half), 1.0) < full);
+
+    let by_time = DifficultySection::Time {
+        start: map.hit_objects[half as usize].start_time,
+        end: f64::INFINITY,
+    };
+
+    for clock_rate in [1.0, 1.5] {
+        assert_eq!(
+            stars(Some(by_time), clock_rate),
+            stars(Some(second_half), clock_rate)
+        );
+    }
+}
+
+/// The section from the middle of the map to its end and the map without the
+/// objects before that section.
+fn second_half(map: &Beatmap) -> (DifficultySection, Beatmap) {
+    let half = map.hit_objects.len() / 2;
+
+    let mut cold = map.clone();
+    cold.hit_objects.drain(..half);
+    cold.hit_sounds.drain(..half);
+
+    let section = DifficultySection::Objects {
+        start: half as u32,
+        end: u32::MAX,
+    };
+
+    (section, cold)
+}
+
+#[test]
+fn section_difficulty_carries_over_strains() {
+    let difficulty = Difficulty::new();
+
+    let osu = map("2785319");
+    let (section, cold) = second_half(&osu);
+    let warm = difficulty
+        .calculate_section_for_mode::<Osu>(&osu, section)
+        .unwrap();
+    let cold = difficulty.calculate_for_mode::<Osu>(&cold).unwrap();
+    assert_eq!(
+        (warm.n_circles, warm.n_sliders, warm.n_spinners),
+        (cold.n_circles, cold.n_sliders, cold.n_spinners)
+    );
+    assert_eq!(warm.n_large_ticks, cold.n_large_ticks);
+    assert_eq!(warm.max_combo, cold.max_combo);
+    assert_ne!(warm.stars, cold.stars);
+
+    let taiko = map("1028484");
+    let (section, cold) = second_half(&taiko);
+    let warm = difficulty
+        .calculate_section_for_mode::<Taiko>(&taiko, section)
+        .unwrap();
+    let cold = difficulty.calculate_for_mode::<Taiko>(&cold).unwrap();
+    assert_eq!(warm.max_combo, cold.max_combo);
+    assert_eq!(
+        (warm.n_drum_rolls, warm.n_drum_roll_ticks),
+        (cold.n_drum_rolls, cold.n_drum_roll_ticks)
+    );
+    assert_eq!(
+        (warm.n_swells, warm.n_swell_hits),
+        (cold.n_swells, cold.n_swell_hits)
+    );
+    assert_ne!(warm.stars, cold.stars);
+
+    let catch = map("2118524");
+    let (section, cold) = second_half(&catch);
+    let warm = difficulty
+        .calculate_section_for_mode::<Catch>(&catch, section)
+        .unwrap();
+    let cold = difficulty.calculate_for_mode::<Catch>(&cold).unwrap();
+    assert_eq!(
+        (warm.n_fruits, warm.n_droplets, warm.n_tiny_droplets),
+        (cold.n_fruits, cold.n_droplets, cold.n_tiny_droplets)
+    );
+    assert_ne!(warm.stars, cold.stars);
+
+    let mania = map("1638954");
+    let (section, cold) = second_half(&mania);
+    let warm = difficulty
+        .calculate_section_for_mode::<Mania>(&mania, section)
+        .unwrap();
+    let cold = difficulty.calculate_for_mode::<Mania>(&cold).unwrap();
+    assert_eq!(
+        (warm.n_objects, warm.n_hold_notes, warm.max_combo),
+        (cold.n_objects, cold.n_hold_notes, cold.max_combo)
+    );
+    assert_ne!(warm.stars, cold.stars);
+}
+
+#[test]
+fn section_splits_juice_stream() {
+    // The juice stream lasts from 1000ms to 5000ms with droplets every second
+    // and 15 tiny droplets between consecutive nested objects.
+    const MAP: &str = "osu file format v14
+
+[General]
+Mode: 2
+
+[Difficulty]
+SliderMultiplier:1
+SliderTickRate:1
+
+[TimingPoints]
+0,1000,4,2,0,100,1,0
+
+[HitObjects]
+256,192,0,1,0,0:0:0:0:
+0,192,1000,2,0,L|400:192,1,400
+256,192,6000,1,0,0:0:0:0:
+";
+
+    let map: Beatmap = MAP.parse().unwrap();
+    let difficulty = Difficulty::new();
+
+    let full = difficulty.calculate_for_mode::<Catch>(&map).unwrap();
+    assert_eq!(
+        (full.n_fruits, full.n_droplets, full.n_tiny_droplets),
+        (4, 3, 60)
+    );
+
+    let section = DifficultySection::Time {
+        start: 2510.0,
+        end: f64::INFINITY,
+    };
+
+    let attrs = difficulty
+        .calculate_section_for_mode::<Catch>(&map, section)
+        .unwrap();
+
+    // Only the nested objects from 2510ms onwards count
+    assert_eq!(
+        (attrs.n_fruits, attrs.n_droplets, attrs.n_tiny_droplets),
+        (2, 2, 37)
+    );
+}
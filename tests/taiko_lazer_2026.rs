@@ -1,10 +1,13 @@
 use mames_pp::{
+    any::AlgorithmVersion,
+    model::mods::reexports::GameModsIntermode,
     taiko::{
         Taiko, TaikoDifficultyAttributes, TaikoGradualDifficulty, TaikoPerformance,
         TaikoPerformanceAttributes,
     },
     Beatmap, Difficulty,
 };
+use rosu_mods::{generated_mods::RandomTaiko, GameMod, GameMods as GameModsLazer};
 
 const EPSILON: f64 = 1e-12;
 
@@ -25,6 +28,7 @@ fn map() -> Beatmap {
 fn difficulty(map: &Beatmap, mods: u32) -> TaikoDifficultyAttributes {
     Difficulty::new()
         .mods(mods)
+        .algorithm(AlgorithmVersion::Lazer2026)
         .calculate_for_mode::<Taiko>(map)
         .unwrap()
 }
@@ -34,6 +38,7 @@ fn ss(attrs: TaikoDifficultyAttributes, mods: u32) -> TaikoPerformanceAttributes
 
     TaikoPerformance::new(attrs)
         .mods(mods)
+        .algorithm(AlgorithmVersion::Lazer2026)
         .combo(max_combo)
         .n300(max_combo)
         .calculate()
@@ -43,6 +48,7 @@ fn ss(attrs: TaikoDifficultyAttributes, mods: u32) -> TaikoPerformanceAttributes
 fn imperfect(attrs: TaikoDifficultyAttributes, mods: u32) -> TaikoPerformanceAttributes {
     TaikoPerformance::new(attrs)
         .mods(mods)
+        .algorithm(AlgorithmVersion::Lazer2026)
         .combo(120)
         .n300(180)
         .n100(15)
@@ -178,6 +184,147 @@ fn lazer_performance_reference() {
     );
 }
 
+#[test]
+fn drum_roll_and_swell_counts() {
+    const MAP: &str = "osu file format v14
+
+[General]
+Mode: 1
+
+[Difficulty]
+OverallDifficulty:5
+SliderMultiplier:1.4
+SliderTickRate:1
+
+[TimingPoints]
+0,500,4,2,1,50,1,0
+
+[HitObjects]
+256,192,0,1,0,0:0:0:0:
+256,192,500,2,0,L|456:192,1,400
+256,192,3000,12,0,4000,0:0:0:0:
+256,192,4500,1,0,0:0:0:0:
+";
+
+    let map: Beatmap = MAP.parse().unwrap();
+    let attrs = difficulty(&map, 0);
+
+    // * 400px * 1.4 / (100 * 1.4 * 1.4) * 500ms = 1428ms drum roll with
+    // * ticks every 125ms
+    assert_eq!(attrs.n_drum_rolls, 1);
+    assert_eq!(attrs.n_drum_roll_ticks, 12);
+    // * 1s swell at OD5 requires `5 * 1.65` hits
+    assert_eq!(attrs.n_swells, 1);
+    assert_eq!(attrs.n_swell_hits, 8);
+
+    let state = TaikoPerformance::new(attrs.clone())
+        .drum_roll_tick_hits(20)
+        .swells_completed(0)
+        .generate_state()
+        .unwrap();
+
+    assert_eq!(state.drum_roll_tick_hits, 12);
+    assert_eq!(state.swells_completed, 0);
+
+    let all_bonus = TaikoPerformance::new(attrs.clone()).calculate().unwrap();
+    let no_bonus = TaikoPerformance::new(attrs)
+        .drum_roll_tick_hits(0)
+        .swells_completed(0)
+        .calculate()
+        .unwrap();
+
+    assert_eq!(all_bonus.pp, no_bonus.pp);
+}
+
+#[test]
+fn hidden_flashlight_reading() {
+    let map = map();
+
+    let fork = |mods| {
+        Difficulty::new()
+            .mods(mods)
+            .algorithm(AlgorithmVersion::Mamestagram)
+            .calculate_for_mode::<Taiko>(&map)
+            .unwrap()
+    };
+
+    let nm = fork(0);
+    let hd = fork(8);
+    let hdfl = fork(8 | 1024);
+
+    assert!(hd.reading > nm.reading);
+    assert!(hdfl.reading > hd.reading);
+
+    // Lazer does not consider the visibility of notes
+    assert_eq!(
+        difficulty(&map, 8 | 1024).reading,
+        difficulty(&map, 0).reading
+    );
+}
+
+#[test]
+fn constant_speed_only_affects_fork() {
+    let map = map();
+
+    let constant_speed = Difficulty::new()
+        .mods(GameModsIntermode::from_acronyms("CS"))
+        .algorithm(AlgorithmVersion::Lazer2026)
+        .calculate_for_mode::<Taiko>(&map)
+        .unwrap();
+
+    // Lazer does not consider Constant Speed
+    assert_eq!(constant_speed, difficulty(&map, 0));
+}
+
+#[test]
+fn swap_and_random_colours() {
+    let map = map();
+    let nm = difficulty(&map, 0);
+
+    let swap = Difficulty::new()
+        .mods(GameModsIntermode::from_acronyms("SW"))
+        .algorithm(AlgorithmVersion::Lazer2026)
+        .calculate_for_mode::<Taiko>(&map)
+        .unwrap();
+
+    // Swapping every colour keeps all colour patterns intact
+    assert_eq!(swap, nm);
+
+    let random = difficulty(&map, 2_097_152);
+
+    assert_ne!(random.color, nm.color);
+    assert_eq!(random, difficulty(&map, 2_097_152));
+
+    let seeded = |seed| {
+        let gamemod = GameMod::RandomTaiko(RandomTaiko { seed: Some(seed) });
+
+        Difficulty::new()
+            .mods(GameModsLazer::from(gamemod))
+            .algorithm(AlgorithmVersion::Lazer2026)
+            .calculate_for_mode::<Taiko>(&map)
+            .unwrap()
+    };
+
+    // Random without a seed falls back to seed 0
+    assert_eq!(seeded(0.0), random);
+    assert_ne!(seeded(1.0).color, random.color);
+}
+
+#[test]
+fn classic_keeps_conversion() {
+    let map = map();
+
+    let classic = Difficulty::new()
+        .mods(GameModsIntermode::from_acronyms("CL"))
+        .algorithm(AlgorithmVersion::Lazer2026)
+        .calculate_for_mode::<Taiko>(&map)
+        .unwrap();
+
+    // Lazer's `TaikoModClassic` does not change the converted objects
+    assert_close(classic.stars, 3.319084940658167, "CL stars");
+    assert_close(classic.color, 0.711226393181619, "CL color");
+}
+
 #[test]
 fn lazer_gradual_ends_on_regular_attributes() {
     let map = map();